@@ -0,0 +1,400 @@
+use crate::resolvers::args_resolver_positional::PlaceholderStyle;
+use crate::selections::{AggFunctionType, DbValueType};
+use crate::{quote_escaped, NullableValue, Value};
+use chrono::{NaiveDate, NaiveDateTime};
+use std::fmt;
+
+/// How an `UPDATE` statement reads rows from additional tables.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UpdateFromStyle {
+    /// `UPDATE t SET .. FROM x WHERE ..`, used by Postgres and SQLite.
+    From,
+    /// `UPDATE t SET .. FROM t, x WHERE ..`, used by MSSQL, the target is repeated in `FROM`.
+    FromWithTarget,
+    /// `UPDATE t, x SET .. WHERE ..`, used by MySQL.
+    TableList,
+}
+
+/// How a `DELETE` statement reads rows from additional tables.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DeleteUsingStyle {
+    /// `DELETE FROM t USING x WHERE ..`, used by Postgres.
+    Using,
+    /// `DELETE t FROM t, x WHERE ..`, used by MySQL and MSSQL.
+    TargetFrom,
+    /// Additional tables are not supported, used by SQLite.
+    Unsupported,
+}
+
+/// How an `INSERT` resolves a conflict with an existing row (upsert).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UpsertStyle {
+    /// `INSERT .. ON CONFLICT (..) DO UPDATE SET ..`, used by Postgres and SQLite.
+    OnConflict,
+    /// `INSERT .. ON DUPLICATE KEY UPDATE ..`, used by MySQL.
+    OnDuplicateKey,
+    /// `MERGE INTO .. USING .. WHEN MATCHED ..`, used by MSSQL.
+    Merge,
+}
+
+/// How an `ALTER TABLE` changes the definition of an existing column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AlterColumnStyle {
+    /// `ALTER COLUMN c TYPE t, ALTER COLUMN c SET NOT NULL, ..`, used by Postgres.
+    Separate,
+    /// `MODIFY COLUMN c t NOT NULL DEFAULT ..`, used by MySQL, the whole definition is replaced.
+    Modify,
+    /// `ALTER COLUMN c t NOT NULL`, used by MSSQL, defaults are named constraints and are kept.
+    Redefine,
+    /// Columns can't be altered, used by SQLite.
+    Unsupported,
+}
+
+/// Syntax differences between database backends.
+/// The dialect is provided by the `ArgsResolver`, so the same AST can be rendered for any backend.
+/// Default implementations follow the ANSI/Postgres syntax.
+pub trait Dialect: fmt::Debug {
+    /// Name of the database backend.
+    fn name(&self) -> &'static str;
+
+    /// Placeholder syntax used by the backend drivers.
+    fn placeholder_style(&self) -> PlaceholderStyle;
+
+    /// Quote an identifier (table, field or alias name), escaping any quote inside it.
+    fn quote_identifier(&self, identifier: &str) -> String {
+        quote_escaped(identifier, '"', '"')
+    }
+
+    /// Quote a string literal, escaping any character that could terminate it.
+    fn string_literal(&self, value: &str) -> String {
+        quote_escaped(value, '\'', '\'')
+    }
+
+    /// Return `true` when backslash escapes characters inside string literals.
+    fn backslash_escapes(&self) -> bool {
+        false
+    }
+
+    fn json_literal(&self, value: &serde_json::Value) -> String {
+        self.string_literal(&value.to_string())
+    }
+
+    fn boolean_literal(&self, value: bool) -> String {
+        value.to_string()
+    }
+
+    fn date_literal(&self, value: &NaiveDate) -> String {
+        format!("DATE '{}'", value.format("%Y-%m-%d"))
+    }
+
+    fn date_time_literal(&self, value: &NaiveDateTime) -> String {
+        format!("TIMESTAMP '{}'", value.format("%Y-%m-%d %H:%M:%S%.f"))
+    }
+
+    /// Literal representation of a value, used when arguments are expanded inside the SQL.
+    fn literal(&self, value: &NullableValue) -> String {
+        match value.value() {
+            None => "NULL".to_string(),
+            Some(Value::String(v)) => self.string_literal(&v),
+            Some(Value::Json(v)) => self.json_literal(&v),
+            Some(Value::Boolean(v)) => self.boolean_literal(v),
+            Some(Value::Date(v)) => self.date_literal(&v),
+            Some(Value::DateTime(v)) => self.date_time_literal(&v),
+            Some(v) => v.sql(),
+        }
+    }
+
+    /// Pagination clause placed at the end of the query.
+    fn limit_offset(&self, limit: usize, offset: usize) -> String {
+        format!("LIMIT {limit} OFFSET {offset}")
+    }
+
+    /// Pagination clause placed right after `SELECT`, used instead of `limit_offset` when returns `Some`.
+    fn top(&self, _limit: usize, _offset: usize) -> Option<String> {
+        None
+    }
+
+    /// Return `true` when the pagination clause is only valid after an `ORDER BY`.
+    fn limit_requires_order_by(&self) -> bool {
+        false
+    }
+
+    /// Return `true` when `RETURNING` clause is supported by `INSERT`, `UPDATE` and `DELETE`.
+    fn supports_returning(&self) -> bool {
+        true
+    }
+
+    fn update_from_style(&self) -> UpdateFromStyle {
+        UpdateFromStyle::From
+    }
+
+    fn delete_using_style(&self) -> DeleteUsingStyle {
+        DeleteUsingStyle::Using
+    }
+
+    fn upsert_style(&self) -> UpsertStyle {
+        UpsertStyle::OnConflict
+    }
+
+    /// Return `true` when a recursive common table expression needs `WITH RECURSIVE`.
+    fn requires_recursive_keyword(&self) -> bool {
+        true
+    }
+
+    fn substring(&self, value: &str, start: u16, length: u16) -> String {
+        format!("SUBSTRING({value} FROM {start} FOR {length})")
+    }
+
+    fn concat(&self, values: &[String]) -> String {
+        format!("CONCAT({})", values.join(","))
+    }
+
+    /// Name of the aggregate function, dialects lacking a function return an equivalent one
+    /// or `None` when there is none.
+    fn aggregate_name(&self, agg_type: &AggFunctionType) -> Option<&'static str> {
+        Some(agg_type.name())
+    }
+
+    /// Render a string aggregation, `None` when the dialect can't aggregate `DISTINCT` values
+    /// with this separator.
+    fn string_agg(&self, value: &str, separator: &str, distinct: bool) -> Option<String> {
+        let distinct = if distinct { "DISTINCT " } else { "" };
+        Some(format!("STRING_AGG({distinct}{value}, {separator})"))
+    }
+
+    /// Return `true` when aggregates accept `FILTER (WHERE ..)`, otherwise the condition is rendered as a `CASE`.
+    fn supports_aggregate_filter(&self) -> bool {
+        true
+    }
+
+    /// Return `true` when `ILIKE` is supported, otherwise both sides are compared with `LOWER`.
+    fn supports_ilike(&self) -> bool {
+        true
+    }
+
+    /// Render the null-safe comparison `IS [NOT] DISTINCT FROM`, `not_distinct` meaning equality.
+    fn is_distinct_from(&self, left: &str, right: &str, not_distinct: bool) -> String {
+        if not_distinct {
+            format!("{left} IS NOT DISTINCT FROM {right}")
+        } else {
+            format!("{left} IS DISTINCT FROM {right}")
+        }
+    }
+
+    /// Native column type used by `CREATE TABLE` and `ALTER TABLE`, a string of length `0` has no limit.
+    fn column_type(&self, db_type: &DbValueType) -> String {
+        ansi_column_type(db_type)
+    }
+
+    /// Render the `ALTER TABLE` action adding a column from its `definition`.
+    fn add_column(&self, definition: &str) -> String {
+        format!("ADD COLUMN {definition}")
+    }
+
+    fn alter_column_style(&self) -> AlterColumnStyle {
+        AlterColumnStyle::Separate
+    }
+
+    /// Return `true` when `ALTER TABLE` can add and drop constraints of an existing table.
+    fn supports_alter_constraint(&self) -> bool {
+        true
+    }
+
+    /// Render the `ALTER TABLE` action dropping the primary key of `table` (not quoted), `None`
+    /// when its constraint has no predictable name.
+    fn drop_primary_key(&self, table: &str) -> Option<String> {
+        Some(format!(
+            "DROP CONSTRAINT {}",
+            self.quote_identifier(&format!("{table}_pkey"))
+        ))
+    }
+
+    /// Render `DROP INDEX`, `index` and `table` are already quoted.
+    fn drop_index(&self, index: &str, _table: &str) -> String {
+        format!("DROP INDEX {index}")
+    }
+}
+
+/// ANSI/Postgres column type, the fallback of `Dialect::column_type` for types without a specific one.
+pub(crate) fn ansi_column_type(db_type: &DbValueType) -> String {
+    match db_type {
+        DbValueType::String(0) => "TEXT".to_string(),
+        DbValueType::String(length) => format!("VARCHAR({length})"),
+        DbValueType::Uuid => "UUID".to_string(),
+        DbValueType::Int32(_) => "INTEGER".to_string(),
+        DbValueType::Int64(_) => "BIGINT".to_string(),
+        DbValueType::Decimal(precision, scale) => format!("NUMERIC({precision},{scale})"),
+        DbValueType::Boolean => "BOOLEAN".to_string(),
+        DbValueType::Date => "DATE".to_string(),
+        DbValueType::DateTime => "TIMESTAMP".to_string(),
+        DbValueType::Json => "JSONB".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dialects::{MsSqlDialect, MySqlDialect, PostgresDialect, SqliteDialect};
+    use crate::resolvers::args_resolver_string::args_to_str_with_dialect;
+    use crate::selections::{ConditionWhereOperation, TableField};
+    use crate::IntoNullableValue;
+    use pretty_assertions::assert_eq;
+
+    const HAZARDS: &[char] = &[
+        '\'', '"', '`', '[', ']', '\\', ';', '-', '/', '*', '\0', '\n', '\r', '\t', ' ', '%', '_',
+        'a', 'Z', '0', 'é', '😀',
+    ];
+
+    /// Deterministic pseudo random strings built from characters that commonly break literals.
+    fn fuzz_inputs() -> Vec<String> {
+        let mut seed: u64 = 0x2545_F491_4F6C_DD1D;
+        let mut next = move || {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            seed as usize
+        };
+        let mut inputs: Vec<String> = HAZARDS.iter().map(|c| c.to_string()).collect();
+        inputs.push(String::new());
+        inputs.push(r"\'; DROP TABLE T; --".to_string());
+        inputs.push(r#"" OR ""=""#.to_string());
+        for _ in 0..2000 {
+            let len = next() % 24;
+            inputs.push((0..len).map(|_| HAZARDS[next() % HAZARDS.len()]).collect());
+        }
+        inputs
+    }
+
+    /// Read a quoted text the way the database does. Returns the content only when the
+    /// whole text is a single quoted token, `None` when the content breaks out of its quotes.
+    fn unquote(sql: &str, open: char, close: char, backslash_escapes: bool) -> Option<String> {
+        let mut chars = sql.chars();
+        if chars.next()? != open {
+            return None;
+        }
+        let mut content = String::new();
+        while let Some(c) = chars.next() {
+            if backslash_escapes && c == '\\' {
+                content.push(match chars.next()? {
+                    '0' => '\0',
+                    'n' => '\n',
+                    't' => '\t',
+                    other => other,
+                });
+            } else if c == close {
+                match chars.next() {
+                    Some(c) if c == close => content.push(close),
+                    Some(_) => return None,
+                    None => return Some(content),
+                }
+            } else {
+                content.push(c);
+            }
+        }
+        None
+    }
+
+    fn check_dialect(dialect: &dyn Dialect, id_quotes: (char, char), backslash_escapes: bool) {
+        for input in fuzz_inputs() {
+            let literal = dialect.literal(&input.as_str().into_nullable_value());
+            assert_eq!(
+                unquote(&literal, '\'', '\'', backslash_escapes).as_ref(),
+                Some(&input),
+                "{} string literal {literal:?}",
+                dialect.name()
+            );
+
+            let json = serde_json::json!({ "key": input });
+            let literal = dialect.literal(&json.clone().into_nullable_value());
+            assert_eq!(
+                unquote(&literal, '\'', '\'', backslash_escapes),
+                Some(json.to_string()),
+                "{} json literal {literal:?}",
+                dialect.name()
+            );
+
+            let identifier = dialect.quote_identifier(&input);
+            assert_eq!(
+                unquote(&identifier, id_quotes.0, id_quotes.1, false).as_ref(),
+                Some(&input),
+                "{} identifier {identifier:?}",
+                dialect.name()
+            );
+        }
+    }
+
+    #[test]
+    fn test_mssql_column_type() {
+        let column_type = |db_type| MsSqlDialect.column_type(&db_type);
+        assert_eq!(column_type(DbValueType::String(0)), "NVARCHAR(MAX)");
+        assert_eq!(column_type(DbValueType::String(4000)), "NVARCHAR(4000)");
+        assert_eq!(column_type(DbValueType::String(4001)), "NVARCHAR(MAX)");
+        assert_eq!(column_type(DbValueType::Json), "NVARCHAR(MAX)");
+    }
+
+    #[test]
+    fn test_mssql_date_time_literal() {
+        let date = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+        let literal = |nano| {
+            MsSqlDialect.date_time_literal(&date.and_hms_nano_opt(10, 20, 30, nano).unwrap())
+        };
+        assert_eq!(literal(0), "CAST('2024-02-29T10:20:30' AS DATETIME2)");
+        assert_eq!(
+            literal(123_456_000),
+            "CAST('2024-02-29T10:20:30.123456' AS DATETIME2)"
+        );
+        assert_eq!(
+            literal(123_456_789),
+            "CAST('2024-02-29T10:20:30.1234567' AS DATETIME2)"
+        );
+    }
+
+    #[test]
+    fn test_mysql_column_type() {
+        let column_type = |db_type| MySqlDialect.column_type(&db_type);
+        assert_eq!(column_type(DbValueType::String(0)), "TEXT");
+        assert_eq!(column_type(DbValueType::String(16383)), "VARCHAR(16383)");
+        assert_eq!(column_type(DbValueType::String(16384)), "MEDIUMTEXT");
+        assert_eq!(column_type(DbValueType::String(u16::MAX)), "MEDIUMTEXT");
+    }
+
+    #[test]
+    fn test_fuzz_postgres() {
+        check_dialect(&PostgresDialect, ('"', '"'), false);
+    }
+
+    #[test]
+    fn test_fuzz_mysql() {
+        check_dialect(&MySqlDialect, ('`', '`'), true);
+    }
+
+    #[test]
+    fn test_fuzz_sqlite() {
+        check_dialect(&SqliteDialect, ('"', '"'), false);
+    }
+
+    #[test]
+    fn test_fuzz_mssql() {
+        check_dialect(&MsSqlDialect, ('[', ']'), false);
+    }
+
+    #[test]
+    fn test_fuzz_condition() {
+        for input in fuzz_inputs() {
+            let cond = TableField::new("NAME").equal(input.as_str());
+            let sql = args_to_str_with_dialect(&cond, PostgresDialect).unwrap();
+            let literal = sql.strip_prefix(r#""NAME" = "#).unwrap();
+            assert_eq!(unquote(literal, '\'', '\'', false), Some(input));
+        }
+    }
+
+    #[test]
+    fn test_escape_literals() {
+        let value = "O'Brien \\ x".into_nullable_value();
+        assert_eq!(PostgresDialect.literal(&value), r"'O''Brien \ x'");
+        assert_eq!(MySqlDialect.literal(&value), r"'O''Brien \\ x'");
+        assert_eq!(MsSqlDialect.quote_identifier("A]B"), "[A]]B]");
+        assert_eq!(MySqlDialect.quote_identifier("A`B"), "`A``B`");
+        assert_eq!(PostgresDialect.quote_identifier(r#"A"B"#), r#""A""B""#);
+    }
+}
@@ -0,0 +1,135 @@
+use super::dialect::{
+    ansi_column_type, AlterColumnStyle, DeleteUsingStyle, Dialect, UpdateFromStyle, UpsertStyle,
+};
+use crate::quote_escaped;
+use crate::resolvers::args_resolver_positional::PlaceholderStyle;
+use crate::selections::{AggFunctionType, DbValueType};
+use chrono::{NaiveDate, NaiveDateTime, Timelike};
+
+/// Microsoft SQL Server syntax: bracket identifiers, `TOP` and `OFFSET .. FETCH` pagination.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct MsSqlDialect;
+
+impl Dialect for MsSqlDialect {
+    fn name(&self) -> &'static str {
+        "MSSQL"
+    }
+
+    fn placeholder_style(&self) -> PlaceholderStyle {
+        PlaceholderStyle::AtP
+    }
+
+    fn quote_identifier(&self, identifier: &str) -> String {
+        quote_escaped(identifier, '[', ']')
+    }
+
+    fn supports_returning(&self) -> bool {
+        false
+    }
+
+    fn update_from_style(&self) -> UpdateFromStyle {
+        UpdateFromStyle::FromWithTarget
+    }
+
+    fn delete_using_style(&self) -> DeleteUsingStyle {
+        DeleteUsingStyle::TargetFrom
+    }
+
+    fn upsert_style(&self) -> UpsertStyle {
+        UpsertStyle::Merge
+    }
+
+    fn requires_recursive_keyword(&self) -> bool {
+        false
+    }
+
+    fn boolean_literal(&self, value: bool) -> String {
+        if value { "1" } else { "0" }.to_string()
+    }
+
+    fn date_literal(&self, value: &NaiveDate) -> String {
+        format!("CAST('{}' AS DATE)", value.format("%Y-%m-%d"))
+    }
+
+    fn date_time_literal(&self, value: &NaiveDateTime) -> String {
+        // DATETIME2 accepts up to 7 fractional digits, nanoseconds are truncated
+        let nanosecond = value.nanosecond();
+        let value = if nanosecond.is_multiple_of(1000) {
+            value.format("%Y-%m-%dT%H:%M:%S%.f").to_string()
+        } else {
+            let seconds = value.format("%Y-%m-%dT%H:%M:%S");
+            format!("{seconds}.{:07}", nanosecond % 1_000_000_000 / 100)
+        };
+        format!("CAST('{value}' AS DATETIME2)")
+    }
+
+    fn limit_offset(&self, limit: usize, offset: usize) -> String {
+        format!("OFFSET {offset} ROWS FETCH NEXT {limit} ROWS ONLY")
+    }
+
+    fn top(&self, limit: usize, offset: usize) -> Option<String> {
+        (offset == 0).then(|| format!("TOP {limit}"))
+    }
+
+    fn limit_requires_order_by(&self) -> bool {
+        true
+    }
+
+    fn substring(&self, value: &str, start: u16, length: u16) -> String {
+        format!("SUBSTRING({value}, {start}, {length})")
+    }
+
+    fn aggregate_name(&self, agg_type: &AggFunctionType) -> Option<&'static str> {
+        match agg_type {
+            AggFunctionType::ArrayAgg => None,
+            AggFunctionType::BoolAnd => Some("MIN"),
+            AggFunctionType::BoolOr => Some("MAX"),
+            AggFunctionType::StdDev => Some("STDEV"),
+            AggFunctionType::Variance => Some("VAR"),
+            _ => Some(agg_type.name()),
+        }
+    }
+
+    fn string_agg(&self, value: &str, separator: &str, distinct: bool) -> Option<String> {
+        // STRING_AGG doesn't accept DISTINCT
+        (!distinct).then(|| format!("STRING_AGG({value}, {separator})"))
+    }
+
+    fn supports_aggregate_filter(&self) -> bool {
+        false
+    }
+
+    fn supports_ilike(&self) -> bool {
+        false
+    }
+
+    fn column_type(&self, db_type: &DbValueType) -> String {
+        match db_type {
+            // NVARCHAR accepts up to 4000 characters, longer ones need MAX
+            DbValueType::String(length @ 1..=4000) => format!("NVARCHAR({length})"),
+            DbValueType::String(_) | DbValueType::Json => "NVARCHAR(MAX)".to_string(),
+            DbValueType::Uuid => "UNIQUEIDENTIFIER".to_string(),
+            DbValueType::Int32(_) => "INT".to_string(),
+            DbValueType::Decimal(precision, scale) => format!("DECIMAL({precision},{scale})"),
+            DbValueType::Boolean => "BIT".to_string(),
+            DbValueType::DateTime => "DATETIME2".to_string(),
+            _ => ansi_column_type(db_type),
+        }
+    }
+
+    fn add_column(&self, definition: &str) -> String {
+        format!("ADD {definition}")
+    }
+
+    fn alter_column_style(&self) -> AlterColumnStyle {
+        AlterColumnStyle::Redefine
+    }
+
+    fn drop_primary_key(&self, _table: &str) -> Option<String> {
+        None
+    }
+
+    fn drop_index(&self, index: &str, table: &str) -> String {
+        format!("DROP INDEX {index} ON {table}")
+    }
+}
@@ -0,0 +1,111 @@
+use super::dialect::{
+    ansi_column_type, AlterColumnStyle, DeleteUsingStyle, Dialect, UpdateFromStyle, UpsertStyle,
+};
+use crate::quote_escaped;
+use crate::resolvers::args_resolver_positional::PlaceholderStyle;
+use crate::selections::{AggFunctionType, DbValueType};
+
+/// MySQL syntax: back-tick identifiers and backslash escapes inside strings.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct MySqlDialect;
+
+impl Dialect for MySqlDialect {
+    fn name(&self) -> &'static str {
+        "MySQL"
+    }
+
+    fn placeholder_style(&self) -> PlaceholderStyle {
+        PlaceholderStyle::QuestionMark
+    }
+
+    fn quote_identifier(&self, identifier: &str) -> String {
+        quote_escaped(identifier, '`', '`')
+    }
+
+    fn string_literal(&self, value: &str) -> String {
+        // Backslash is an escape character in MySQL strings, unless `NO_BACKSLASH_ESCAPES` is set.
+        let value = value.replace('\\', r"\\").replace('\0', r"\0");
+        quote_escaped(&value, '\'', '\'')
+    }
+
+    fn backslash_escapes(&self) -> bool {
+        true
+    }
+
+    fn supports_returning(&self) -> bool {
+        false
+    }
+
+    fn update_from_style(&self) -> UpdateFromStyle {
+        UpdateFromStyle::TableList
+    }
+
+    fn delete_using_style(&self) -> DeleteUsingStyle {
+        DeleteUsingStyle::TargetFrom
+    }
+
+    fn upsert_style(&self) -> UpsertStyle {
+        UpsertStyle::OnDuplicateKey
+    }
+
+    fn boolean_literal(&self, value: bool) -> String {
+        if value { "TRUE" } else { "FALSE" }.to_string()
+    }
+
+    fn aggregate_name(&self, agg_type: &AggFunctionType) -> Option<&'static str> {
+        match agg_type {
+            AggFunctionType::ArrayAgg => None,
+            AggFunctionType::BoolAnd => Some("MIN"),
+            AggFunctionType::BoolOr => Some("MAX"),
+            _ => Some(agg_type.name()),
+        }
+    }
+
+    fn string_agg(&self, value: &str, separator: &str, distinct: bool) -> Option<String> {
+        let distinct = if distinct { "DISTINCT " } else { "" };
+        Some(format!(
+            "GROUP_CONCAT({distinct}{value} SEPARATOR {separator})"
+        ))
+    }
+
+    fn supports_aggregate_filter(&self) -> bool {
+        false
+    }
+
+    fn supports_ilike(&self) -> bool {
+        false
+    }
+
+    fn is_distinct_from(&self, left: &str, right: &str, not_distinct: bool) -> String {
+        if not_distinct {
+            format!("{left} <=> {right}")
+        } else {
+            format!("NOT ({left} <=> {right})")
+        }
+    }
+
+    fn column_type(&self, db_type: &DbValueType) -> String {
+        match db_type {
+            // VARCHAR accepts up to 16383 utf8mb4 characters, longer ones need a text type
+            DbValueType::String(16384..) => "MEDIUMTEXT".to_string(),
+            DbValueType::Uuid => "CHAR(36)".to_string(),
+            DbValueType::Int32(_) => "INT".to_string(),
+            DbValueType::Decimal(precision, scale) => format!("DECIMAL({precision},{scale})"),
+            DbValueType::DateTime => "DATETIME".to_string(),
+            DbValueType::Json => "JSON".to_string(),
+            _ => ansi_column_type(db_type),
+        }
+    }
+
+    fn alter_column_style(&self) -> AlterColumnStyle {
+        AlterColumnStyle::Modify
+    }
+
+    fn drop_primary_key(&self, _table: &str) -> Option<String> {
+        Some("DROP PRIMARY KEY".to_string())
+    }
+
+    fn drop_index(&self, index: &str, table: &str) -> String {
+        format!("DROP INDEX {index} ON {table}")
+    }
+}
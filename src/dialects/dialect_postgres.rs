@@ -0,0 +1,16 @@
+use super::dialect::Dialect;
+use crate::resolvers::args_resolver_positional::PlaceholderStyle;
+
+/// Postgres syntax, the default dialect.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct PostgresDialect;
+
+impl Dialect for PostgresDialect {
+    fn name(&self) -> &'static str {
+        "Postgres"
+    }
+
+    fn placeholder_style(&self) -> PlaceholderStyle {
+        PlaceholderStyle::Dollar
+    }
+}
@@ -0,0 +1,93 @@
+use super::dialect::{AlterColumnStyle, DeleteUsingStyle, Dialect};
+use crate::resolvers::args_resolver_positional::PlaceholderStyle;
+use crate::selections::{AggFunctionType, DbValueType};
+use chrono::{NaiveDate, NaiveDateTime};
+
+/// SQLite syntax: booleans as integers, dates as text and `||` concatenation.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SqliteDialect;
+
+impl Dialect for SqliteDialect {
+    fn name(&self) -> &'static str {
+        "SQLite"
+    }
+
+    fn placeholder_style(&self) -> PlaceholderStyle {
+        PlaceholderStyle::QuestionMark
+    }
+
+    fn delete_using_style(&self) -> DeleteUsingStyle {
+        DeleteUsingStyle::Unsupported
+    }
+
+    fn boolean_literal(&self, value: bool) -> String {
+        if value { "1" } else { "0" }.to_string()
+    }
+
+    fn date_literal(&self, value: &NaiveDate) -> String {
+        format!("'{}'", value.format("%Y-%m-%d"))
+    }
+
+    fn date_time_literal(&self, value: &NaiveDateTime) -> String {
+        format!("'{}'", value.format("%Y-%m-%d %H:%M:%S%.f"))
+    }
+
+    fn substring(&self, value: &str, start: u16, length: u16) -> String {
+        format!("SUBSTR({value}, {start}, {length})")
+    }
+
+    fn concat(&self, values: &[String]) -> String {
+        format!("({})", values.join(" || "))
+    }
+
+    fn aggregate_name(&self, agg_type: &AggFunctionType) -> Option<&'static str> {
+        match agg_type {
+            AggFunctionType::BoolAnd => Some("MIN"),
+            AggFunctionType::BoolOr => Some("MAX"),
+            AggFunctionType::ArrayAgg | AggFunctionType::StdDev | AggFunctionType::Variance => None,
+            _ => Some(agg_type.name()),
+        }
+    }
+
+    fn string_agg(&self, value: &str, separator: &str, distinct: bool) -> Option<String> {
+        match distinct {
+            false => Some(format!("GROUP_CONCAT({value}, {separator})")),
+            // a DISTINCT aggregate takes a single argument, the default separator is a comma
+            true if separator == "','" => Some(format!("GROUP_CONCAT(DISTINCT {value})")),
+            true => None,
+        }
+    }
+
+    fn supports_ilike(&self) -> bool {
+        false
+    }
+
+    fn is_distinct_from(&self, left: &str, right: &str, not_distinct: bool) -> String {
+        if not_distinct {
+            format!("{left} IS {right}")
+        } else {
+            format!("{left} IS NOT {right}")
+        }
+    }
+
+    fn column_type(&self, db_type: &DbValueType) -> String {
+        match db_type {
+            DbValueType::String(_)
+            | DbValueType::Uuid
+            | DbValueType::Json
+            | DbValueType::Date
+            | DbValueType::DateTime => "TEXT",
+            DbValueType::Int32(_) | DbValueType::Int64(_) | DbValueType::Boolean => "INTEGER",
+            DbValueType::Decimal(_, _) => "NUMERIC",
+        }
+        .to_string()
+    }
+
+    fn alter_column_style(&self) -> AlterColumnStyle {
+        AlterColumnStyle::Unsupported
+    }
+
+    fn supports_alter_constraint(&self) -> bool {
+        false
+    }
+}
@@ -0,0 +1,11 @@
+pub mod dialect;
+pub mod dialect_mssql;
+pub mod dialect_mysql;
+pub mod dialect_postgres;
+pub mod dialect_sqlite;
+
+pub use dialect::{AlterColumnStyle, DeleteUsingStyle, Dialect, UpdateFromStyle, UpsertStyle};
+pub use dialect_mssql::MsSqlDialect;
+pub use dialect_mysql::MySqlDialect;
+pub use dialect_postgres::PostgresDialect;
+pub use dialect_sqlite::SqliteDialect;
@@ -0,0 +1,18 @@
+use derive_more::Display;
+use error_stack::Context;
+
+#[derive(Display, Debug, PartialEq, Eq)]
+pub enum EvalError {
+    #[display(fmt = "field not found: `{_0}`")]
+    FieldNotFound(String),
+    #[display(fmt = "expression not supported by evaluation: `{_0}`")]
+    Unsupported(String),
+    #[display(fmt = "type mismatch: `{_0}`")]
+    TypeMismatch(String),
+    #[display(fmt = "division by zero")]
+    DivisionByZero,
+    #[display(fmt = "arithmetic overflow")]
+    Overflow,
+}
+
+impl Context for EvalError {}
@@ -1,6 +1,18 @@
 pub mod core_error;
 pub use core_error::CoreError;
 
+#[cfg(feature = "sql")]
+pub mod eval_error;
+#[cfg(feature = "sql")]
+pub use eval_error::EvalError;
+#[cfg(feature = "sql")]
+pub mod parse_error;
+#[cfg(feature = "sql")]
+pub use parse_error::ParseError;
+#[cfg(feature = "sql")]
+pub mod schema_error;
+#[cfg(feature = "sql")]
+pub use schema_error::SchemaError;
 #[cfg(feature = "sql")]
 pub mod sql_error;
 #[cfg(feature = "sql")]
@@ -0,0 +1,23 @@
+use derive_more::Display;
+use error_stack::Context;
+
+/// Error found parsing a SQL text, with the position where it happens (both starting at 1).
+#[derive(Display, Debug, Clone, PartialEq, Eq)]
+#[display(fmt = "{message} at line {line}, column {column}")]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+}
+
+impl Context for ParseError {}
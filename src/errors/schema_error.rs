@@ -0,0 +1,26 @@
+use derive_more::Display;
+use error_stack::Context;
+
+#[derive(Display, Debug, PartialEq, Eq)]
+pub enum SchemaError {
+    #[display(fmt = "parse json error: `{_0}`")]
+    ParseJson(String),
+    #[display(fmt = "table not found: `{_0}`")]
+    TableNotFound(String),
+    #[display(fmt = "duplicated table: `{_0}`")]
+    DuplicatedTable(String),
+    #[display(fmt = "column not found: `{_0}.{_1}`")]
+    ColumnNotFound(String, String),
+    #[display(fmt = "duplicated column: `{_0}.{_1}`")]
+    DuplicatedColumn(String, String),
+    #[display(fmt = "invalid table `{_0}`: {_1}")]
+    InvalidTable(String, String),
+    #[display(fmt = "invalid foreign key `{_0}`: {_1}")]
+    InvalidForeignKey(String, String),
+    #[display(fmt = "no relation path from `{_0}` to `{_1}`")]
+    NoRelationPath(String, String),
+    #[display(fmt = "ambiguous relation path from `{_0}` to `{_1}`: {_2}")]
+    AmbiguousRelationPath(String, String, String),
+}
+
+impl Context for SchemaError {}
@@ -19,6 +19,8 @@ pub enum SQLError {
     FieldNameNotFound(String, String),
     #[display(fmt = "bind name not found: `{_0}`")]
     BindNameNotFound(String),
+    #[display(fmt = "type of column could not be inferred: `{_0}`")]
+    TypeNotInferred(String),
 }
 
 impl Context for SQLError {}
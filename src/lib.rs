@@ -7,6 +7,7 @@ pub use values::into_value::IntoValue;
 pub use values::into_value::TryValueFromString;
 pub use values::null_value::NullValue;
 pub use values::nullable_value::NullableValue;
+pub use values::quote_escaped;
 pub use values::validate_double_quotes;
 pub use values::value::Value;
 pub use values::value::ValueToSQL;
@@ -24,11 +25,21 @@ pub use objects::json_to_value;
 #[cfg(feature = "objects")]
 pub use objects::v_to_json;
 
+#[cfg(feature = "sql")]
+pub mod dialects;
 #[cfg(feature = "sql")]
 pub mod resolvers;
 #[cfg(feature = "sql")]
+pub mod schema;
+#[cfg(feature = "sql")]
 pub mod selections;
 #[cfg(feature = "sql")]
+pub use errors::eval_error::EvalError;
+#[cfg(feature = "sql")]
+pub use errors::parse_error::ParseError;
+#[cfg(feature = "sql")]
+pub use errors::schema_error::SchemaError;
+#[cfg(feature = "sql")]
 pub use errors::sql_error::SQLError;
 
 #[cfg(feature = "objects")]
@@ -1,3 +1,5 @@
+use super::sql_format::FormatState;
+use crate::dialects::{Dialect, PostgresDialect};
 use crate::selections::bind_name::BindName;
 use crate::NullableValue;
 
@@ -8,6 +10,16 @@ pub trait ArgsResolver {
     fn add_bind(&mut self, _bind_name: BindName) -> Option<NullableValue> {
         None
     }
+
+    /// SQL dialect used to render identifiers, literals and syntax specific for the backend.
+    fn dialect(&self) -> &dyn Dialect {
+        &PostgresDialect
+    }
+
+    /// State of the SQL rendered in multiple lines, `None` renders it in a single line.
+    fn format_state(&mut self) -> Option<&mut FormatState> {
+        None
+    }
 }
 
 #[cfg(test)]
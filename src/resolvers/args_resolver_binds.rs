@@ -1,4 +1,6 @@
 use super::args_resolver::ArgsResolver;
+use super::sql_format::FormatState;
+use crate::dialects::Dialect;
 use crate::selections::bind_name::BindName;
 use crate::NullableValue;
 use std::collections::HashMap;
@@ -29,4 +31,12 @@ impl ArgsResolver for ArgsResolverBindsDecorator<'_> {
     fn add_bind(&mut self, bind_name: BindName) -> Option<NullableValue> {
         self.binds_values.get(&bind_name).cloned()
     }
+
+    fn dialect(&self) -> &dyn Dialect {
+        self.super_args_resolver.dialect()
+    }
+
+    fn format_state(&mut self) -> Option<&mut FormatState> {
+        self.super_args_resolver.format_state()
+    }
 }
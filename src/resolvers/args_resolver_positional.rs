@@ -0,0 +1,193 @@
+use super::args_resolver::ArgsResolver;
+use crate::dialects::{Dialect, PostgresDialect};
+use crate::NullableValue;
+use crate::{selections::to_sql::ToSQL, SQLError};
+use serde::{Deserialize, Serialize};
+
+/// Placeholder syntax used to reference a positional argument (bind) in the generated SQL.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PlaceholderStyle {
+    /// Numbered dollar placeholders `$1, $2…`, used by Postgres.
+    Dollar,
+    /// Anonymous `?` placeholders, used by MySQL and SQLite.
+    QuestionMark,
+    /// Named `@p1, @p2…` placeholders, used by MSSQL.
+    AtP,
+}
+
+impl PlaceholderStyle {
+    /// Placeholder text for the argument at `position` (1-based).
+    pub fn placeholder(&self, position: usize) -> String {
+        match self {
+            PlaceholderStyle::Dollar => format!("${position}"),
+            PlaceholderStyle::QuestionMark => "?".to_string(),
+            PlaceholderStyle::AtP => format!("@p{position}"),
+        }
+    }
+}
+
+/// SQL arguments (binds) resolver that emits placeholders and collects the values in order,
+/// so the SQL can be handed to a database driver without inlining any value.
+pub struct ArgsResolverPositional {
+    style: PlaceholderStyle,
+    dialect: Box<dyn Dialect>,
+    args: Vec<NullableValue>,
+}
+
+impl ArgsResolverPositional {
+    pub fn new(style: PlaceholderStyle) -> Self {
+        Self {
+            style,
+            dialect: Box::new(PostgresDialect),
+            args: Vec::new(),
+        }
+    }
+
+    /// Create a resolver using the syntax and the placeholder style of `dialect`.
+    pub fn with_dialect(dialect: impl Dialect + 'static) -> Self {
+        Self {
+            style: dialect.placeholder_style(),
+            dialect: Box::new(dialect),
+            args: Vec::new(),
+        }
+    }
+
+    /// Placeholder syntax used by this resolver.
+    pub fn style(&self) -> PlaceholderStyle {
+        self.style
+    }
+
+    /// Arguments collected so far, in the same order of the placeholders.
+    pub fn args(&self) -> &[NullableValue] {
+        self.args.as_ref()
+    }
+
+    /// Get owned arguments collected, in the same order of the placeholders.
+    pub fn into_args(self) -> Vec<NullableValue> {
+        self.args
+    }
+}
+
+impl ArgsResolver for ArgsResolverPositional {
+    fn add_arg(&mut self, value: NullableValue) -> String {
+        self.args.push(value);
+        self.style.placeholder(self.args.len())
+    }
+
+    fn dialect(&self) -> &dyn Dialect {
+        self.dialect.as_ref()
+    }
+}
+
+/// Generate SQL with positional placeholders and returns it with the ordered argument values.
+/// # Example
+/// ```
+/// # use voxi_core::selections::ConditionWhere;
+/// # use voxi_core::resolvers::args_resolver_positional::{args_to_positional, PlaceholderStyle};
+/// # use voxi_core::IntoNullableValue;
+/// let c1 = ConditionWhere::eq("TEXT_1", "TEXT_2");
+/// let (sql, args) = args_to_positional(&c1, PlaceholderStyle::Dollar).unwrap();
+/// assert_eq!(sql, "$1 = $2");
+/// assert_eq!(args, vec!["TEXT_1".into_nullable_value(), "TEXT_2".into_nullable_value()]);
+/// ```
+pub fn args_to_positional(
+    to_sql: &impl ToSQL,
+    style: PlaceholderStyle,
+) -> error_stack::Result<(String, Vec<NullableValue>), SQLError> {
+    let mut args_resolver = ArgsResolverPositional::new(style);
+    let sql = to_sql.to_sql(&mut args_resolver)?;
+    Ok((sql, args_resolver.into_args()))
+}
+
+/// Generate SQL for `dialect`, with its placeholders, and returns it with the ordered argument values.
+pub fn args_to_positional_with_dialect(
+    to_sql: &impl ToSQL,
+    dialect: impl Dialect + 'static,
+) -> error_stack::Result<(String, Vec<NullableValue>), SQLError> {
+    let mut args_resolver = ArgsResolverPositional::with_dialect(dialect);
+    let sql = to_sql.to_sql(&mut args_resolver)?;
+    Ok((sql, args_resolver.into_args()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dialects::{MsSqlDialect, MySqlDialect};
+    use crate::selections::{
+        ConditionWhereOperation, LogicalExprWhereOps, QueryBuilder, TableField, ValueWhere,
+    };
+    use crate::{IntoNullableValue, NullableValue, ValueType};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_dollar_placeholders() {
+        let query = QueryBuilder::new()
+            .field("ID")
+            .from("TABLE")
+            .where_c(
+                TableField::new("NAME")
+                    .equal("O'Brien")
+                    .and(TableField::new("AGE").greater(18)),
+            )
+            .build()
+            .unwrap();
+        let (sql, args) = args_to_positional(&query, PlaceholderStyle::Dollar).unwrap();
+        assert_eq!(
+            sql,
+            r#"SELECT "ID" FROM "TABLE" WHERE "NAME" = $1 AND "AGE" > $2"#
+        );
+        assert_eq!(
+            args,
+            vec!["O'Brien".into_nullable_value(), 18.into_nullable_value()]
+        );
+    }
+
+    #[test]
+    fn test_question_mark_placeholders() {
+        let cond = TableField::new("ID").include(vec![1, 2, 3]);
+        let (sql, args) = args_to_positional(&cond, PlaceholderStyle::QuestionMark).unwrap();
+        assert_eq!(sql, r#""ID" IN (?,?,?)"#);
+        assert_eq!(args.len(), 3);
+    }
+
+    #[test]
+    fn test_at_p_placeholders() {
+        let cond = TableField::new("ID")
+            .equal(1)
+            .or(TableField::new("ID").equal(2));
+        let (sql, args) = args_to_positional(&cond, PlaceholderStyle::AtP).unwrap();
+        assert_eq!(sql, r#""ID" = @p1 OR "ID" = @p2"#);
+        assert_eq!(args, vec![1.into_nullable_value(), 2.into_nullable_value()]);
+    }
+
+    #[test]
+    fn test_null_value_is_bound() {
+        let cond = TableField::new("ID").equal(NullableValue::null(ValueType::Int64));
+        let (sql, args) = args_to_positional(&cond, PlaceholderStyle::Dollar).unwrap();
+        assert_eq!(sql, r#""ID" = $1"#);
+        assert_eq!(args, vec![NullableValue::Int64(None)]);
+    }
+
+    #[test]
+    fn test_binds_values_are_positional() {
+        let query = QueryBuilder::new()
+            .field("ID")
+            .from("TABLE")
+            .where_c(TableField::new("ID").equal(ValueWhere::bind("id")))
+            .add_bind("id", 10)
+            .build()
+            .unwrap();
+        let (sql, args) = args_to_positional(&query, PlaceholderStyle::Dollar).unwrap();
+        assert_eq!(sql, r#"SELECT "ID" FROM "TABLE" WHERE "ID" = $1"#);
+        assert_eq!(args, vec![10.into_nullable_value()]);
+    }
+
+    #[test]
+    fn test_dialect_placeholders() {
+        let cond = TableField::new("ID").equal(1);
+        let (sql, _) = args_to_positional_with_dialect(&cond, MsSqlDialect).unwrap();
+        assert_eq!(sql, "[ID] = @p1");
+        let (sql, _) = args_to_positional_with_dialect(&cond, MySqlDialect).unwrap();
+        assert_eq!(sql, "`ID` = ?");
+    }
+}
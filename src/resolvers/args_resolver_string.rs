@@ -1,13 +1,26 @@
 use super::args_resolver::ArgsResolver;
+use super::sql_format::SqlFormat;
+use crate::dialects::{Dialect, PostgresDialect};
 use crate::NullableValue;
 use crate::{selections::to_sql::ToSQL, SQLError};
 
 /// SQL arguments (binds) expander to String (simple replace, without using database bindings), useful for debug.
-pub struct ArgsResolverString {}
+pub struct ArgsResolverString {
+    dialect: Box<dyn Dialect>,
+}
 
 impl ArgsResolverString {
     pub fn new() -> Self {
-        Self {}
+        Self {
+            dialect: Box::new(PostgresDialect),
+        }
+    }
+
+    /// Create a resolver that renders literal values using the syntax of `dialect`.
+    pub fn with_dialect(dialect: impl Dialect + 'static) -> Self {
+        Self {
+            dialect: Box::new(dialect),
+        }
     }
 }
 
@@ -19,7 +32,7 @@ impl Default for ArgsResolverString {
 
 impl ArgsResolver for ArgsResolverString {
     fn add_arg(&mut self, value: NullableValue) -> String {
-        value.sql()
+        self.dialect.literal(&value)
     }
 
     fn add_bind(
@@ -28,6 +41,10 @@ impl ArgsResolver for ArgsResolverString {
     ) -> Option<NullableValue> {
         None
     }
+
+    fn dialect(&self) -> &dyn Dialect {
+        self.dialect.as_ref()
+    }
 }
 
 /// Expand value arguments (binds) in a plain String
@@ -42,6 +59,50 @@ pub fn args_to_str(to_sql: &impl ToSQL) -> error_stack::Result<String, SQLError>
     to_sql.to_sql(&mut ArgsResolverString::new())
 }
 
+/// Expand value arguments (binds) in a plain String, using the syntax of `dialect`.
+/// # Example
+/// ```
+/// # use voxi_core::selections::TableField;
+/// # use voxi_core::selections::ConditionWhereOperation;
+/// # use voxi_core::dialects::MySqlDialect;
+/// # use voxi_core::resolvers::args_resolver_string::args_to_str_with_dialect;
+/// let c1 = TableField::new("ACTIVE").equal(true);
+/// assert_eq!(args_to_str_with_dialect(&c1, MySqlDialect).unwrap(), "`ACTIVE` = TRUE");
+/// ```
+pub fn args_to_str_with_dialect(
+    to_sql: &impl ToSQL,
+    dialect: impl Dialect + 'static,
+) -> error_stack::Result<String, SQLError> {
+    to_sql.to_sql(&mut ArgsResolverString::with_dialect(dialect))
+}
+
+/// Expand value arguments (binds) in a String with multiple lines laid out by `format`, using the
+/// syntax of `dialect`.
+/// # Example
+/// ```
+/// # use voxi_core::selections::{QueryBuilder, TableField, ConditionWhereOperation};
+/// # use voxi_core::dialects::PostgresDialect;
+/// # use voxi_core::resolvers::args_resolver_string::args_to_str_formatted;
+/// # use voxi_core::resolvers::sql_format::SqlFormat;
+/// let query = QueryBuilder::new()
+///     .field("ID")
+///     .from("TABLE")
+///     .where_c(TableField::new("ID").equal(1))
+///     .build()
+///     .unwrap();
+/// assert_eq!(
+///     args_to_str_formatted(&query, PostgresDialect, &SqlFormat::new()).unwrap(),
+///     "SELECT \"ID\"\nFROM \"TABLE\"\nWHERE \"ID\" = 1"
+/// );
+/// ```
+pub fn args_to_str_formatted(
+    to_sql: &impl ToSQL,
+    dialect: impl Dialect + 'static,
+    format: &SqlFormat,
+) -> error_stack::Result<String, SQLError> {
+    to_sql.to_sql_formatted(&mut ArgsResolverString::with_dialect(dialect), format)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,3 +1,5 @@
 pub mod args_resolver;
-pub mod args_resolver_string;
 pub mod args_resolver_binds;
+pub mod args_resolver_positional;
+pub mod args_resolver_string;
+pub mod sql_format;
@@ -0,0 +1,346 @@
+use super::args_resolver::ArgsResolver;
+use crate::dialects::Dialect;
+use crate::selections::bind_name::BindName;
+use crate::{NullableValue, SQLError};
+
+/// Case used to write SQL keywords and function names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum KeywordCase {
+    #[default]
+    Upper,
+    Lower,
+}
+
+/// Layout to render SQL in multiple lines: one clause per line, sub-queries and `CASE` blocks
+/// indented and columns aligned under the first one.
+/// # Example
+/// ```
+/// # use voxi_core::selections::{QueryBuilder, ToSQL};
+/// # use voxi_core::resolvers::args_resolver_string::ArgsResolverString;
+/// # use voxi_core::resolvers::sql_format::{KeywordCase, SqlFormat};
+/// let query = QueryBuilder::new()
+///     .field("ID")
+///     .field("NAME")
+///     .from("TABLE")
+///     .build()
+///     .unwrap();
+/// let format = SqlFormat::new().keyword_case(KeywordCase::Lower);
+/// let sql = query
+///     .to_sql_formatted(&mut ArgsResolverString::new(), &format)
+///     .unwrap();
+/// assert_eq!(sql, "select \"ID\",\n       \"NAME\"\nfrom \"TABLE\"");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SqlFormat {
+    indent: usize,
+    keyword_case: KeywordCase,
+}
+
+impl SqlFormat {
+    /// Create a format indenting by 4 spaces, with keywords in upper case.
+    pub fn new() -> Self {
+        Self {
+            indent: 4,
+            keyword_case: KeywordCase::Upper,
+        }
+    }
+
+    /// Define the number of spaces for each indentation level.
+    pub fn indent(mut self, indent: usize) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    pub fn keyword_case(mut self, keyword_case: KeywordCase) -> Self {
+        self.keyword_case = keyword_case;
+        self
+    }
+
+    /// Rewrite keywords of `sql` with the case of this format.
+    /// Quoted identifiers, string literals and parameters are kept untouched.
+    pub(crate) fn apply_keyword_case(&self, sql: &str, dialect: &dyn Dialect) -> String {
+        let brackets = dialect.quote_identifier("").starts_with('[');
+        let mut result = String::with_capacity(sql.len());
+        let mut chars = sql.chars().peekable();
+        let mut previous = ' ';
+        while let Some(c) = chars.next() {
+            result.push(c);
+            match c {
+                '\'' | '"' | '`' | '[' if c != '[' || brackets => {
+                    let close = if c == '[' { ']' } else { c };
+                    let backslash = c == '\'' && dialect.backslash_escapes();
+                    while let Some(d) = chars.next() {
+                        result.push(d);
+                        if d == '\\' && backslash {
+                            result.extend(chars.next());
+                        } else if d == close {
+                            match chars.peek() == Some(&close) {
+                                true => result.extend(chars.next()),
+                                false => break,
+                            }
+                        }
+                    }
+                }
+                c if c.is_ascii_alphanumeric() || c == '_' => {
+                    result.pop();
+                    let mut word = String::from(c);
+                    while let Some(&d) = chars.peek() {
+                        if !d.is_ascii_alphanumeric() && d != '_' {
+                            break;
+                        }
+                        word.push(d);
+                        chars.next();
+                    }
+                    // Numbers and parameters like `:name`, `$1` or `@p1` aren't keywords.
+                    let keyword = !c.is_ascii_digit() && !matches!(previous, ':' | '$' | '@');
+                    match (keyword, self.keyword_case) {
+                        (true, KeywordCase::Upper) => result.push_str(&word.to_uppercase()),
+                        (true, KeywordCase::Lower) => result.push_str(&word.to_lowercase()),
+                        (false, _) => result.push_str(&word),
+                    }
+                }
+                _ => {}
+            }
+            previous = c;
+        }
+        result
+    }
+}
+
+impl Default for SqlFormat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Position of the SQL being rendered with a `SqlFormat`.
+#[derive(Debug, Clone)]
+pub struct FormatState {
+    format: SqlFormat,
+    /// Indentation of the current line, in spaces.
+    indent: usize,
+}
+
+/// Decorator that makes `ToSQL` render the SQL of `super_args_resolver` in multiple lines.
+pub struct ArgsResolverFormatDecorator<'a> {
+    state: FormatState,
+    super_args_resolver: &'a mut dyn ArgsResolver,
+}
+
+impl<'a> ArgsResolverFormatDecorator<'a> {
+    pub fn new(super_args_resolver: &'a mut dyn ArgsResolver, format: SqlFormat) -> Self {
+        Self {
+            state: FormatState { format, indent: 0 },
+            super_args_resolver,
+        }
+    }
+}
+
+impl ArgsResolver for ArgsResolverFormatDecorator<'_> {
+    fn add_arg(&mut self, value: NullableValue) -> String {
+        self.super_args_resolver.add_arg(value)
+    }
+
+    fn add_bind(&mut self, bind_name: BindName) -> Option<NullableValue> {
+        self.super_args_resolver.add_bind(bind_name)
+    }
+
+    fn dialect(&self) -> &dyn Dialect {
+        self.super_args_resolver.dialect()
+    }
+
+    fn format_state(&mut self) -> Option<&mut FormatState> {
+        Some(&mut self.state)
+    }
+}
+
+/// Return `compact` for SQL in a single line, otherwise a new line at the current indentation.
+pub(crate) fn line_break(args_resolver: &mut dyn ArgsResolver, compact: &str) -> String {
+    match args_resolver.format_state() {
+        Some(state) => format!("\n{}", " ".repeat(state.indent)),
+        None => compact.to_string(),
+    }
+}
+
+/// Render with the indentation of new lines increased by `width` spaces.
+pub(crate) fn indented<T>(
+    args_resolver: &mut dyn ArgsResolver,
+    width: usize,
+    to_sql: impl FnOnce(&mut dyn ArgsResolver) -> error_stack::Result<T, SQLError>,
+) -> error_stack::Result<T, SQLError> {
+    if let Some(state) = args_resolver.format_state() {
+        state.indent += width;
+    }
+    let result = to_sql(args_resolver);
+    if let Some(state) = args_resolver.format_state() {
+        state.indent -= width;
+    }
+    result
+}
+
+/// Render with the indentation of new lines increased by one level.
+pub(crate) fn indented_level<T>(
+    args_resolver: &mut dyn ArgsResolver,
+    to_sql: impl FnOnce(&mut dyn ArgsResolver) -> error_stack::Result<T, SQLError>,
+) -> error_stack::Result<T, SQLError> {
+    let width = args_resolver
+        .format_state()
+        .map_or(0, |state| state.format.indent);
+    indented(args_resolver, width, to_sql)
+}
+
+/// Render the content of parentheses, like a sub-query, in its own lines indented by one level.
+pub(crate) fn nested(
+    args_resolver: &mut dyn ArgsResolver,
+    to_sql: impl FnOnce(&mut dyn ArgsResolver) -> error_stack::Result<String, SQLError>,
+) -> error_stack::Result<String, SQLError> {
+    let content = indented_level(args_resolver, |args_resolver| {
+        Ok(format!(
+            "{}{}",
+            line_break(args_resolver, ""),
+            to_sql(args_resolver)?
+        ))
+    })?;
+    Ok(format!("{content}{}", line_break(args_resolver, "")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        dialects::{MsSqlDialect, MySqlDialect, PostgresDialect},
+        resolvers::args_resolver_string::{args_to_str_formatted, args_to_str_with_dialect},
+        selections::{
+            AggFunction, CaseConditionBuilder, ConditionWhere, ConditionWhereOperation, Join,
+            LogicalExprWhereOps, OrderBy, QueryAlias, QueryBuilder, Select, SingleSelectBuilder,
+            TableField, ValueWhere, WhenCondition,
+        },
+    };
+    use pretty_assertions::assert_eq;
+
+    fn report_query() -> Select {
+        let size = CaseConditionBuilder::new()
+            .when_value(WhenCondition::new(
+                TableField::new("QTY").greater(10),
+                "BIG",
+            ))
+            .else_case("SMALL")
+            .build();
+        let max_price = SingleSelectBuilder::max("PRICE")
+            .from("ITEM")
+            .where_c(TableField::new("ITEM.ORDER_ID").equal(TableField::new("O.ID")))
+            .build();
+        let totals = QueryBuilder::new()
+            .field("CUSTOMER_ID")
+            .select((AggFunction::sum("TOTAL"), "TOTAL"))
+            .from("ORDERS")
+            .group("CUSTOMER_ID")
+            .build()
+            .unwrap();
+        let blocked = QueryBuilder::new()
+            .field("ID")
+            .from("BLOCKED")
+            .build()
+            .unwrap();
+        QueryBuilder::new()
+            .with("BLOCKED_IDS", blocked.clone())
+            .distinct()
+            .field("O.ID")
+            .select((ValueWhere::CaseCondition(Box::new(size)), "SIZE"))
+            .select((max_price, "MAX_PRICE"))
+            .from(QueryAlias::new(totals, "T"))
+            .join(Join::inner(
+                "ORDERS",
+                TableField::new("ORDERS.CUSTOMER_ID").equal(TableField::new("T.CUSTOMER_ID")),
+            ))
+            .where_c(
+                ConditionWhere::exists(blocked)
+                    .not()
+                    .and(TableField::new("O.NOTE").equal("Line 1\nLine 2")),
+            )
+            .order(OrderBy::desc("O.ID"))
+            .limit_offset(10, 0)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_format_select() {
+        let sql =
+            args_to_str_formatted(&report_query(), PostgresDialect, &SqlFormat::new()).unwrap();
+        let expected = r#"WITH "BLOCKED_IDS" AS (
+    SELECT "ID"
+    FROM "BLOCKED"
+)
+SELECT DISTINCT "O"."ID",
+                CASE
+                    WHEN "QTY" > 10 THEN 'BIG'
+                    ELSE 'SMALL'
+                END AS "SIZE",
+                (
+                    SELECT MAX("PRICE")
+                    FROM "ITEM"
+                    WHERE "ITEM"."ORDER_ID" = "O"."ID"
+                ) AS "MAX_PRICE"
+FROM (
+         SELECT "CUSTOMER_ID",
+                SUM("TOTAL") AS "TOTAL"
+         FROM "ORDERS"
+         GROUP BY "CUSTOMER_ID"
+     ) "T"
+INNER JOIN "ORDERS" ON "ORDERS"."CUSTOMER_ID" = "T"."CUSTOMER_ID"
+WHERE NOT EXISTS (
+    SELECT "ID"
+    FROM "BLOCKED"
+) AND "O"."NOTE" = 'Line 1
+Line 2'
+ORDER BY "O"."ID" DESC
+LIMIT 10 OFFSET 0"#;
+        assert_eq!(sql, expected);
+    }
+
+    /// The formatted SQL must be read back as the same query of the compact SQL.
+    fn assert_equivalent(query: &Select, dialect: impl Dialect + Copy + 'static) {
+        let format = SqlFormat::new().indent(2).keyword_case(KeywordCase::Lower);
+        let compact = args_to_str_with_dialect(query, dialect).unwrap();
+        let formatted = args_to_str_formatted(query, dialect, &format).unwrap();
+        assert_ne!(formatted, compact);
+        assert_eq!(
+            Select::parse(&formatted, &dialect).unwrap(),
+            Select::parse(&compact, &dialect).unwrap(),
+            "{formatted}"
+        );
+    }
+
+    #[test]
+    fn test_format_is_equivalent() {
+        let query = report_query();
+        assert_equivalent(&query, PostgresDialect);
+        assert_equivalent(&query, MySqlDialect);
+        assert_equivalent(&query, MsSqlDialect);
+    }
+
+    #[test]
+    fn test_apply_keyword_case() {
+        let lower = SqlFormat::new().keyword_case(KeywordCase::Lower);
+        assert_eq!(
+            lower.apply_keyword_case(
+                r#"SELECT "ID" AS "Id" FROM "T" WHERE "A" = 'It''s A' AND "B" = :Bind_A AND 1E5 > 2"#,
+                &PostgresDialect
+            ),
+            r#"select "ID" as "Id" from "T" where "A" = 'It''s A' and "B" = :Bind_A and 1E5 > 2"#
+        );
+        assert_eq!(
+            lower.apply_keyword_case(r"SELECT `A` FROM `T` WHERE `A` = 'X\' OR A'", &MySqlDialect),
+            r"select `A` from `T` where `A` = 'X\' OR A'"
+        );
+        assert_eq!(
+            lower.apply_keyword_case("SELECT TOP 1 [A] FROM [T] WHERE [A] = @P1", &MsSqlDialect),
+            "select top 1 [A] from [T] where [A] = @P1"
+        );
+        assert_eq!(
+            SqlFormat::new().apply_keyword_case("select count(*) from \"t\"", &PostgresDialect),
+            "SELECT COUNT(*) FROM \"t\""
+        );
+    }
+}
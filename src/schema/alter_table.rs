@@ -0,0 +1,336 @@
+use super::{
+    create_table::{column_definition, default_to_sql, fields_list, foreign_key_definition},
+    Column, ColumnDefault, ForeignKey, UniqueConstraint,
+};
+use crate::{
+    dialects::AlterColumnStyle,
+    resolvers::args_resolver::ArgsResolver,
+    selections::{IntoTableName, TableName, ToSQL},
+    FieldName, IntoFieldName, SQLError,
+};
+use serde::{Deserialize, Serialize};
+
+/// Change applied to a table by `ALTER TABLE`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum AlterTableAction {
+    AddColumn(Column),
+    DropColumn(FieldName),
+    /// Replace the type, nullability and default of the column with the same name.
+    AlterColumn(Column),
+    /// Set the default of a column, or drop it when `None`.
+    AlterDefault(FieldName, Option<ColumnDefault>),
+    AddPrimaryKey(Vec<FieldName>),
+    DropPrimaryKey,
+    AddUnique(UniqueConstraint),
+    AddForeignKey(ForeignKey),
+    /// Drop a unique or foreign key constraint by its name.
+    DropConstraint(String),
+}
+
+/// SQL `ALTER TABLE` statement.
+/// # Example
+/// ```
+/// # use voxi_core::schema::{AlterTable, Column};
+/// # use voxi_core::selections::DbValueType;
+/// # use voxi_core::dialects::{MySqlDialect, PostgresDialect};
+/// # use voxi_core::resolvers::args_resolver_string::args_to_str_with_dialect;
+/// let alter = AlterTable::alter_column("CUSTOMER", Column::new("NAME", DbValueType::String(200)));
+/// assert_eq!(
+///     args_to_str_with_dialect(&alter, PostgresDialect).unwrap(),
+///     r#"ALTER TABLE "CUSTOMER" ALTER COLUMN "NAME" TYPE VARCHAR(200), ALTER COLUMN "NAME" SET NOT NULL, ALTER COLUMN "NAME" DROP DEFAULT"#
+/// );
+/// assert_eq!(
+///     args_to_str_with_dialect(&alter, MySqlDialect).unwrap(),
+///     "ALTER TABLE `CUSTOMER` MODIFY COLUMN `NAME` VARCHAR(200) NOT NULL"
+/// );
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct AlterTable {
+    pub table: TableName,
+    pub action: AlterTableAction,
+}
+
+impl AlterTable {
+    pub fn new(table: impl IntoTableName, action: AlterTableAction) -> Self {
+        Self {
+            table: table.into_table_name(),
+            action,
+        }
+    }
+
+    pub fn add_column(table: impl IntoTableName, column: Column) -> Self {
+        Self::new(table, AlterTableAction::AddColumn(column))
+    }
+
+    pub fn drop_column(table: impl IntoTableName, column: impl IntoFieldName) -> Self {
+        Self::new(
+            table,
+            AlterTableAction::DropColumn(column.into_field_name()),
+        )
+    }
+
+    pub fn alter_column(table: impl IntoTableName, column: Column) -> Self {
+        Self::new(table, AlterTableAction::AlterColumn(column))
+    }
+}
+
+impl ToSQL for AlterTable {
+    fn to_sql(
+        &self,
+        args_resolver: &mut dyn ArgsResolver,
+    ) -> error_stack::Result<String, SQLError> {
+        let constraint = matches!(
+            self.action,
+            AlterTableAction::AddPrimaryKey(_)
+                | AlterTableAction::DropPrimaryKey
+                | AlterTableAction::AddUnique(_)
+                | AlterTableAction::AddForeignKey(_)
+                | AlterTableAction::DropConstraint(_)
+        );
+        if constraint && !args_resolver.dialect().supports_alter_constraint() {
+            return Err(SQLError::SQLResolver(format!(
+                "changing constraints of an existing table is not supported by {}",
+                args_resolver.dialect().name()
+            ))
+            .into());
+        }
+        let sql = match &self.action {
+            AlterTableAction::AddColumn(column) => {
+                let definition = column_definition(column, args_resolver)?;
+                args_resolver.dialect().add_column(&definition)
+            }
+            AlterTableAction::DropColumn(name) => {
+                format!("DROP COLUMN {}", name.to_sql(args_resolver)?)
+            }
+            AlterTableAction::AlterColumn(column) => alter_column_to_sql(column, args_resolver)?,
+            AlterTableAction::AlterDefault(name, default) => {
+                alter_default_to_sql(name, default.as_ref(), args_resolver)?
+            }
+            AlterTableAction::AddPrimaryKey(fields) => {
+                format!("ADD PRIMARY KEY {}", fields_list(fields, args_resolver)?)
+            }
+            AlterTableAction::DropPrimaryKey => {
+                let dialect = args_resolver.dialect();
+                dialect.drop_primary_key(self.table.name()).ok_or_else(|| {
+                    SQLError::SQLResolver(format!(
+                        "dropping an unnamed primary key is not supported by {}",
+                        dialect.name()
+                    ))
+                })?
+            }
+            AlterTableAction::AddUnique(unique) => format!(
+                "ADD CONSTRAINT {} UNIQUE {}",
+                args_resolver.dialect().quote_identifier(&unique.name),
+                fields_list(&unique.columns, args_resolver)?
+            ),
+            AlterTableAction::AddForeignKey(foreign_key) => format!(
+                "ADD {}",
+                foreign_key_definition(foreign_key, args_resolver)?
+            ),
+            AlterTableAction::DropConstraint(name) => format!(
+                "DROP CONSTRAINT {}",
+                args_resolver.dialect().quote_identifier(name)
+            ),
+        };
+        Ok(format!(
+            "ALTER TABLE {} {sql}",
+            self.table.to_sql(args_resolver)?
+        ))
+    }
+}
+
+fn alter_column_to_sql(
+    column: &Column,
+    args_resolver: &mut dyn ArgsResolver,
+) -> error_stack::Result<String, SQLError> {
+    let name = column.name.to_sql(args_resolver)?;
+    let dialect = args_resolver.dialect();
+    let column_type = dialect.column_type(&column.db_type);
+    let sql = match dialect.alter_column_style() {
+        AlterColumnStyle::Separate => {
+            let nullable = if column.nullable { "DROP" } else { "SET" };
+            let default = match &column.default {
+                Some(default) => format!("SET DEFAULT {}", default_to_sql(default, dialect)),
+                None => "DROP DEFAULT".to_string(),
+            };
+            format!(
+                "ALTER COLUMN {name} TYPE {column_type}, ALTER COLUMN {name} {nullable} NOT NULL, ALTER COLUMN {name} {default}"
+            )
+        }
+        AlterColumnStyle::Modify => {
+            format!(
+                "MODIFY COLUMN {}",
+                column_definition(column, args_resolver)?
+            )
+        }
+        AlterColumnStyle::Redefine => {
+            let nullable = if column.nullable { "NULL" } else { "NOT NULL" };
+            format!("ALTER COLUMN {name} {column_type} {nullable}")
+        }
+        AlterColumnStyle::Unsupported => {
+            return Err(SQLError::SQLResolver(format!(
+                "ALTER COLUMN is not supported by {}",
+                dialect.name()
+            ))
+            .into());
+        }
+    };
+    Ok(sql)
+}
+
+fn alter_default_to_sql(
+    name: &FieldName,
+    default: Option<&ColumnDefault>,
+    args_resolver: &mut dyn ArgsResolver,
+) -> error_stack::Result<String, SQLError> {
+    let name = name.to_sql(args_resolver)?;
+    let dialect = args_resolver.dialect();
+    match dialect.alter_column_style() {
+        AlterColumnStyle::Separate | AlterColumnStyle::Modify => Ok(match default {
+            Some(default) => format!(
+                "ALTER COLUMN {name} SET DEFAULT {}",
+                default_to_sql(default, dialect)
+            ),
+            None => format!("ALTER COLUMN {name} DROP DEFAULT"),
+        }),
+        // defaults are constraints named by the database, they can't be dropped by column
+        AlterColumnStyle::Redefine | AlterColumnStyle::Unsupported => {
+            Err(SQLError::SQLResolver(format!(
+                "changing the default of an existing column is not supported by {}",
+                dialect.name()
+            ))
+            .into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        dialects::{MsSqlDialect, MySqlDialect, PostgresDialect, SqliteDialect},
+        resolvers::args_resolver_string::args_to_str_with_dialect,
+        selections::DbValueType,
+        IntoValue,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_add_drop_column() {
+        let add = AlterTable::add_column(
+            "ORDERS",
+            Column::new("STATUS", DbValueType::String(1)).default_value("O"),
+        );
+        assert_eq!(
+            args_to_str_with_dialect(&add, PostgresDialect).unwrap(),
+            r#"ALTER TABLE "ORDERS" ADD COLUMN "STATUS" VARCHAR(1) NOT NULL DEFAULT 'O'"#
+        );
+        assert_eq!(
+            args_to_str_with_dialect(&add, SqliteDialect).unwrap(),
+            r#"ALTER TABLE "ORDERS" ADD COLUMN "STATUS" TEXT NOT NULL DEFAULT 'O'"#
+        );
+        assert_eq!(
+            args_to_str_with_dialect(&add, MsSqlDialect).unwrap(),
+            "ALTER TABLE [ORDERS] ADD [STATUS] NVARCHAR(1) NOT NULL DEFAULT 'O'"
+        );
+        let drop = AlterTable::drop_column("ORDERS", "STATUS");
+        assert_eq!(
+            args_to_str_with_dialect(&drop, MySqlDialect).unwrap(),
+            "ALTER TABLE `ORDERS` DROP COLUMN `STATUS`"
+        );
+    }
+
+    #[test]
+    fn test_alter_column() {
+        let alter = AlterTable::alter_column(
+            "ORDERS",
+            Column::new("TOTAL", DbValueType::Decimal(12, 2))
+                .nullable()
+                .default_value(0),
+        );
+        assert_eq!(
+            args_to_str_with_dialect(&alter, PostgresDialect).unwrap(),
+            r#"ALTER TABLE "ORDERS" ALTER COLUMN "TOTAL" TYPE NUMERIC(12,2), ALTER COLUMN "TOTAL" DROP NOT NULL, ALTER COLUMN "TOTAL" SET DEFAULT 0"#
+        );
+        assert_eq!(
+            args_to_str_with_dialect(&alter, MySqlDialect).unwrap(),
+            "ALTER TABLE `ORDERS` MODIFY COLUMN `TOTAL` DECIMAL(12,2) DEFAULT 0"
+        );
+        assert_eq!(
+            args_to_str_with_dialect(&alter, MsSqlDialect).unwrap(),
+            "ALTER TABLE [ORDERS] ALTER COLUMN [TOTAL] DECIMAL(12,2) NULL"
+        );
+        assert!(args_to_str_with_dialect(&alter, SqliteDialect).is_err());
+    }
+
+    #[test]
+    fn test_alter_default() {
+        let set = AlterTable::new(
+            "ORDERS",
+            AlterTableAction::AlterDefault(
+                "STATUS".into_field_name(),
+                Some(ColumnDefault::Value("O".into_value())),
+            ),
+        );
+        assert_eq!(
+            args_to_str_with_dialect(&set, PostgresDialect).unwrap(),
+            r#"ALTER TABLE "ORDERS" ALTER COLUMN "STATUS" SET DEFAULT 'O'"#
+        );
+        assert_eq!(
+            args_to_str_with_dialect(&set, MySqlDialect).unwrap(),
+            "ALTER TABLE `ORDERS` ALTER COLUMN `STATUS` SET DEFAULT 'O'"
+        );
+        assert!(args_to_str_with_dialect(&set, MsSqlDialect).is_err());
+        let drop = AlterTable::new(
+            "ORDERS",
+            AlterTableAction::AlterDefault("STATUS".into_field_name(), None),
+        );
+        assert_eq!(
+            args_to_str_with_dialect(&drop, PostgresDialect).unwrap(),
+            r#"ALTER TABLE "ORDERS" ALTER COLUMN "STATUS" DROP DEFAULT"#
+        );
+        assert!(args_to_str_with_dialect(&drop, MsSqlDialect).is_err());
+    }
+
+    #[test]
+    fn test_constraints() {
+        let drop_key = AlterTable::new("ORDERS", AlterTableAction::DropPrimaryKey);
+        assert_eq!(
+            args_to_str_with_dialect(&drop_key, PostgresDialect).unwrap(),
+            r#"ALTER TABLE "ORDERS" DROP CONSTRAINT "ORDERS_pkey""#
+        );
+        assert_eq!(
+            args_to_str_with_dialect(&drop_key, MySqlDialect).unwrap(),
+            "ALTER TABLE `ORDERS` DROP PRIMARY KEY"
+        );
+        assert!(args_to_str_with_dialect(&drop_key, MsSqlDialect).is_err());
+        let add_key = AlterTable::new(
+            "ORDERS",
+            AlterTableAction::AddPrimaryKey(vec!["ID".into_field_name()]),
+        );
+        assert_eq!(
+            args_to_str_with_dialect(&add_key, MsSqlDialect).unwrap(),
+            "ALTER TABLE [ORDERS] ADD PRIMARY KEY ([ID])"
+        );
+        let unique = AlterTable::new(
+            "ORDERS",
+            AlterTableAction::AddUnique(UniqueConstraint {
+                name: "UQ_ORDERS_NUMBER".to_string(),
+                columns: vec!["NUMBER".into_field_name()],
+            }),
+        );
+        assert_eq!(
+            args_to_str_with_dialect(&unique, PostgresDialect).unwrap(),
+            r#"ALTER TABLE "ORDERS" ADD CONSTRAINT "UQ_ORDERS_NUMBER" UNIQUE ("NUMBER")"#
+        );
+        assert!(args_to_str_with_dialect(&unique, SqliteDialect).is_err());
+        let drop = AlterTable::new(
+            "ORDERS",
+            AlterTableAction::DropConstraint("FK_ORDERS_CUSTOMER".to_string()),
+        );
+        assert_eq!(
+            args_to_str_with_dialect(&drop, MySqlDialect).unwrap(),
+            "ALTER TABLE `ORDERS` DROP CONSTRAINT `FK_ORDERS_CUSTOMER`"
+        );
+    }
+}
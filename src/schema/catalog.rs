@@ -0,0 +1,275 @@
+use super::{
+    validator::QueryValidator, Column, Diagnostic, RelationGraph, SchemaDiff, TableSchema,
+};
+use crate::{
+    selections::{InferredType, Select, TableName, TableTypes},
+    FieldName, IntoValueType, SchemaError,
+};
+use error_stack::Report;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Tables of a database schema, shared by validation, DDL generation and join inference.
+/// # Example
+/// ```
+/// # use voxi_core::schema::{Catalog, Column, TableSchemaBuilder};
+/// # use voxi_core::selections::DbValueType;
+/// let customer = TableSchemaBuilder::new("CUSTOMER")
+///     .column(Column::new("ID", DbValueType::Int64(0)).primary_key())
+///     .column(Column::new("NAME", DbValueType::String(100)))
+///     .build()
+///     .unwrap();
+/// let catalog = Catalog::new().with_table(customer);
+/// catalog.validate().unwrap();
+///
+/// let json = serde_json::to_string(&catalog).unwrap();
+/// assert_eq!(Catalog::from_json(&json).unwrap(), catalog);
+/// assert!(catalog.column("CUSTOMER", "NAME").is_some());
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Catalog {
+    pub tables: Vec<TableSchema>,
+}
+
+impl Catalog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn with_table(mut self, table: TableSchema) -> Self {
+        self.tables.push(table);
+        self
+    }
+
+    /// Read and validate a catalog serialized as JSON.
+    pub fn from_json(json: &str) -> error_stack::Result<Self, SchemaError> {
+        let catalog: Catalog = serde_json::from_str(json)
+            .map_err(|e| Report::new(SchemaError::ParseJson(e.to_string())))?;
+        catalog.validate()?;
+        Ok(catalog)
+    }
+
+    pub fn table(&self, name: &str) -> Option<&TableSchema> {
+        self.tables.iter().find(|t| t.name.name() == name)
+    }
+
+    pub fn column(&self, table: &str, column: &str) -> Option<&Column> {
+        self.table(table).and_then(|t| t.column(column))
+    }
+
+    /// Check the tables, fields and operand types used by the query, including its sub-queries.
+    /// Returns every problem found, an empty list when the query is valid.
+    /// # Example
+    /// ```
+    /// # use voxi_core::schema::{Catalog, Column, DiagnosticKind, TableSchemaBuilder};
+    /// # use voxi_core::selections::{DbValueType, Select};
+    /// # use voxi_core::dialects::PostgresDialect;
+    /// # use voxi_core::ValueType;
+    /// let orders = TableSchemaBuilder::new("ORDERS")
+    ///     .column(Column::new("ID", DbValueType::Uuid).primary_key())
+    ///     .build()
+    ///     .unwrap();
+    /// let catalog = Catalog::new().with_table(orders);
+    /// let query = Select::parse(r#"SELECT "ID" FROM "ORDERS" WHERE "ID" = 1"#, &PostgresDialect).unwrap();
+    /// let diagnostics = catalog.validate_select(&query);
+    /// assert_eq!(
+    ///     diagnostics[0].kind,
+    ///     DiagnosticKind::TypeMismatch(ValueType::Uuid, ValueType::Int32)
+    /// );
+    /// ```
+    pub fn validate_select(&self, select: &Select) -> Vec<Diagnostic> {
+        QueryValidator::new(self).validate(select)
+    }
+
+    /// Structural changes turning this catalog into `target`, see `SchemaDiff::migration` to apply them.
+    pub fn diff(&self, target: &Catalog) -> SchemaDiff {
+        SchemaDiff::between(self, target)
+    }
+
+    /// Relations of the foreign keys, used to infer joins between tables.
+    pub fn relations(&self) -> RelationGraph {
+        RelationGraph::from_catalog(self)
+    }
+
+    /// Check every table and that foreign keys reference existing columns of the same type.
+    pub fn validate(&self) -> Result<(), SchemaError> {
+        let mut names = HashSet::new();
+        if let Some(table) = self.tables.iter().find(|t| !names.insert(&t.name)) {
+            return Err(SchemaError::DuplicatedTable(table.name.name().to_string()));
+        }
+        for table in self.tables.iter() {
+            table.validate()?;
+            for foreign_key in table.foreign_keys.iter() {
+                let invalid = |reason: String| {
+                    Err(SchemaError::InvalidForeignKey(
+                        foreign_key.name.clone(),
+                        reason,
+                    ))
+                };
+                let foreign_name = foreign_key.foreign_table.name();
+                let Some(foreign_table) = self.table(foreign_name) else {
+                    return invalid(format!("table `{foreign_name}` not found"));
+                };
+                for field in foreign_key.fields.iter() {
+                    let Some(column) = foreign_table.column(&field.foreign.0) else {
+                        return invalid(format!(
+                            "column `{foreign_name}.{}` not found",
+                            field.foreign
+                        ));
+                    };
+                    if column.db_type.value_type() != field.local.v_type {
+                        return invalid(format!(
+                            "field `{}` is `{}` but `{foreign_name}.{}` is `{}`",
+                            field.local.name,
+                            field.local.v_type,
+                            field.foreign,
+                            column.db_type.value_type()
+                        ));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl TableTypes for Catalog {
+    fn table_columns(&self, table: &TableName) -> Option<Vec<(FieldName, InferredType)>> {
+        let table = self.table(table.name())?;
+        let columns = table
+            .columns
+            .iter()
+            .map(|c| {
+                let inferred = InferredType::new(c.db_type.value_type(), c.nullable);
+                (c.name.clone(), inferred)
+            })
+            .collect();
+        Some(columns)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        schema::{ColumnDefault, TableSchemaBuilder},
+        selections::DbValueType,
+        Value,
+    };
+    use pretty_assertions::assert_eq;
+
+    fn customer() -> TableSchema {
+        TableSchemaBuilder::new("CUSTOMER")
+            .column(Column::new("ID", DbValueType::Int64(0)).primary_key())
+            .column(Column::new("NAME", DbValueType::String(100)))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_from_json() {
+        let json = r#"{
+            "tables": [
+                {
+                    "name": "CUSTOMER",
+                    "columns": [
+                        { "name": "ID", "type": { "Int64": 0 }, "primary_key": true },
+                        { "name": "NAME", "type": { "String": 100 } }
+                    ]
+                },
+                {
+                    "name": "ORDERS",
+                    "columns": [
+                        { "name": "ID", "type": "Uuid", "primary_key": true },
+                        { "name": "CUSTOMER_ID", "type": { "Int64": 0 } },
+                        { "name": "TOTAL", "type": { "Decimal": [10, 2] }, "nullable": true },
+                        { "name": "STATUS", "type": { "String": 1 }, "default": { "Value": { "s": "O" } } }
+                    ],
+                    "foreign_keys": [
+                        {
+                            "name": "FK_ORDERS_CUSTOMER",
+                            "foreign_table": "CUSTOMER",
+                            "fields": [
+                                { "local": { "name": "CUSTOMER_ID", "type": "i8" }, "foreign": "ID" }
+                            ]
+                        }
+                    ],
+                    "indexes": [{ "name": "IX_ORDERS_STATUS", "columns": ["STATUS"] }]
+                }
+            ]
+        }"#;
+        let catalog = Catalog::from_json(json).unwrap();
+        let orders = TableSchemaBuilder::new("ORDERS")
+            .column(Column::new("ID", DbValueType::Uuid).primary_key())
+            .column(Column::new("CUSTOMER_ID", DbValueType::Int64(0)))
+            .column(Column::new("TOTAL", DbValueType::Decimal(10, 2)).nullable())
+            .column(Column::new("STATUS", DbValueType::String(1)).default_value("O"))
+            .foreign_key(
+                "FK_ORDERS_CUSTOMER",
+                "CUSTOMER",
+                vec![("CUSTOMER_ID", "ID")],
+            )
+            .index("IX_ORDERS_STATUS", vec!["STATUS"])
+            .build()
+            .unwrap();
+        assert_eq!(
+            catalog,
+            Catalog::new().with_table(customer()).with_table(orders)
+        );
+        assert_eq!(
+            catalog.column("ORDERS", "STATUS").unwrap().default,
+            Some(ColumnDefault::Value(Value::from("O")))
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        let orders = |foreign: &str| {
+            TableSchemaBuilder::new("ORDERS")
+                .column(Column::new("CUSTOMER_ID", DbValueType::Int32(0)))
+                .foreign_key("FK_CUSTOMER", "CUSTOMER", vec![("CUSTOMER_ID", foreign)])
+                .build()
+                .unwrap()
+        };
+        let catalog = Catalog::new()
+            .with_table(customer())
+            .with_table(orders("ID"));
+        assert_eq!(
+            catalog.validate().unwrap_err(),
+            SchemaError::InvalidForeignKey(
+                "FK_CUSTOMER".to_string(),
+                "field `CUSTOMER_ID` is `Int32` but `CUSTOMER.ID` is `Int64`".to_string()
+            )
+        );
+        let catalog = Catalog::new()
+            .with_table(customer())
+            .with_table(orders("CODE"));
+        assert_eq!(
+            catalog.validate().unwrap_err(),
+            SchemaError::InvalidForeignKey(
+                "FK_CUSTOMER".to_string(),
+                "column `CUSTOMER.CODE` not found".to_string()
+            )
+        );
+        let catalog = Catalog::new().with_table(orders("ID"));
+        assert_eq!(
+            catalog.validate().unwrap_err(),
+            SchemaError::InvalidForeignKey(
+                "FK_CUSTOMER".to_string(),
+                "table `CUSTOMER` not found".to_string()
+            )
+        );
+        let catalog = Catalog::new().with_table(customer()).with_table(customer());
+        assert_eq!(
+            catalog.validate().unwrap_err(),
+            SchemaError::DuplicatedTable("CUSTOMER".to_string())
+        );
+        assert_eq!(
+            Catalog::from_json(r#"{ "tables": [{ "name": "T", "columns": [] }] }"#)
+                .unwrap_err()
+                .current_context(),
+            &SchemaError::InvalidTable("T".to_string(), "no column has been defined".to_string())
+        );
+    }
+}
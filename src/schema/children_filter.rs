@@ -0,0 +1,216 @@
+use super::Relation;
+use crate::selections::{
+    ConditionWhere, ConditionWhereOperation, FromSelect, IntoAlias, IntoLogicalExprWhere,
+    LogicalExprWhere, LogicalExprWhereOps, SingleQuery, SingleSelectBuilder, Table,
+};
+
+/// Filter of parent rows by their children, the rows of the relation `local_table` referencing
+/// a row of its `foreign_table`, as a sub-query correlated by the relation fields.
+///
+/// The parent is qualified by its table name, or `parent_alias` when the outer query aliases it.
+/// The children are qualified by their table name, or `<TABLE>_2` for a self relation, unless
+/// `alias` is defined, and `where_c` conditions must qualify the child fields the same way.
+/// # Example
+/// ```
+/// # use voxi_core::schema::Relation;
+/// # use voxi_core::selections::{ConditionWhereOperation, QueryBuilder, TableField};
+/// # use voxi_core::resolvers::args_resolver_string::args_to_str;
+/// # use voxi_core::values::LocalForeignField;
+/// # use voxi_core::{FieldNameType, ValueType};
+/// let relation = Relation::new(
+///     "FK_ITEM_ORDERS",
+///     "ITEM",
+///     "ORDERS",
+///     vec![LocalForeignField {
+///         local: FieldNameType::new("ORDER_ID", ValueType::Int32),
+///         foreign: "ID".into(),
+///     }],
+/// );
+/// let items = relation
+///     .children()
+///     .where_c(TableField::new("ITEM.PRICE").greater(100));
+/// let query = QueryBuilder::new()
+///     .field("ORDERS.ID")
+///     .from("ORDERS")
+///     .where_c(items.exists())
+///     .build()
+///     .unwrap();
+/// assert_eq!(
+///     args_to_str(&query).unwrap(),
+///     r#"SELECT "ORDERS"."ID" FROM "ORDERS" WHERE EXISTS (SELECT 1 FROM "ITEM" WHERE "ITEM"."ORDER_ID" = "ORDERS"."ID" AND "ITEM"."PRICE" > 100)"#
+/// );
+/// assert_eq!(
+///     args_to_str(&items.at_least(3)).unwrap(),
+///     r#"(SELECT COUNT(*) FROM "ITEM" WHERE "ITEM"."ORDER_ID" = "ORDERS"."ID" AND "ITEM"."PRICE" > 100) >= 3"#
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChildrenFilter {
+    relation: Relation,
+    parent: Table,
+    child: Table,
+    condition: Option<LogicalExprWhere>,
+}
+
+impl ChildrenFilter {
+    pub fn new(relation: &Relation) -> Self {
+        let mut child = Table::new(relation.local_table.clone());
+        if relation.local_table == relation.foreign_table {
+            child = child.with_alias(format!("{}_2", relation.local_table.name()));
+        }
+        Self {
+            relation: relation.clone(),
+            parent: Table::new(relation.foreign_table.clone()),
+            child,
+            condition: None,
+        }
+    }
+
+    /// Alias of the parent table in the outer query.
+    #[must_use]
+    pub fn parent_alias(mut self, alias: impl IntoAlias) -> Self {
+        self.parent = self.parent.with_alias(alias);
+        self
+    }
+
+    /// Alias of the child table in the sub-query.
+    #[must_use]
+    pub fn alias(mut self, alias: impl IntoAlias) -> Self {
+        self.child = self.child.with_alias(alias);
+        self
+    }
+
+    /// Condition on the children. If already specified then the new condition will added with `AND` operator.
+    #[must_use]
+    pub fn where_c(mut self, expression: impl IntoLogicalExprWhere) -> Self {
+        let expression = expression.into_logical_expr_where();
+        self.condition = Some(match self.condition {
+            Some(condition) => condition.and(expression),
+            None => expression,
+        });
+        self
+    }
+
+    /// Parents with at least one child, `EXISTS (SELECT 1 FROM <child> WHERE ..)`.
+    pub fn exists(&self) -> ConditionWhere {
+        ConditionWhere::exists(self.sub_query(SingleSelectBuilder::literal(1)))
+    }
+
+    /// Parents without children, `NOT EXISTS (SELECT 1 FROM <child> WHERE ..)`.
+    pub fn not_exists(&self) -> ConditionWhere {
+        ConditionWhere::not_exists(self.sub_query(SingleSelectBuilder::literal(1)))
+    }
+
+    /// Number of children, `(SELECT COUNT(*) FROM <child> WHERE ..)`.
+    pub fn count(&self) -> SingleQuery {
+        self.sub_query(SingleSelectBuilder::count("*"))
+    }
+
+    /// Parents with at least `count` children, `EXISTS` when `count` is 1.
+    pub fn at_least(&self, count: usize) -> ConditionWhere {
+        match count {
+            1 => self.exists(),
+            _ => self.count().greater_or_equal(count as i64),
+        }
+    }
+
+    /// Parents with at most `count` children, `NOT EXISTS` when `count` is 0.
+    pub fn at_most(&self, count: usize) -> ConditionWhere {
+        match count {
+            0 => self.not_exists(),
+            _ => self.count().less_or_equal(count as i64),
+        }
+    }
+
+    fn sub_query(&self, builder: SingleSelectBuilder) -> SingleQuery {
+        let mut from = FromSelect::from_table(self.child.name.clone());
+        if let Some(alias) = &self.child.alias {
+            from = from.with_alias(alias.clone());
+        }
+        let mut condition = self
+            .relation
+            .fields
+            .iter()
+            .map(|field| {
+                self.child
+                    .field(field.local.name.clone())
+                    .equal(self.parent.field(field.foreign.clone()))
+            })
+            .collect::<Vec<_>>()
+            .into_logical_expr_where();
+        if let Some(children) = &self.condition {
+            condition = condition.and(children.clone());
+        }
+        builder.from(from).where_c(condition).build()
+    }
+}
+
+impl Relation {
+    /// Filter of the parent rows by their children through this relation.
+    pub fn children(&self) -> ChildrenFilter {
+        ChildrenFilter::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        resolvers::args_resolver_string::args_to_str, selections::TableField,
+        values::LocalForeignField, FieldNameType, ValueType,
+    };
+    use pretty_assertions::assert_eq;
+
+    fn fields(pairs: &[(&str, &str)]) -> Vec<LocalForeignField> {
+        pairs
+            .iter()
+            .map(|(local, foreign)| LocalForeignField {
+                local: FieldNameType::new(*local, ValueType::Int32),
+                foreign: (*foreign).into(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_exists() {
+        let relation = Relation::new(
+            "FK_ITEM_ORDERS",
+            "ITEM",
+            "ORDERS",
+            fields(&[("ORDER_ID", "ID"), ("ORDER_YEAR", "YEAR")]),
+        );
+        let children = relation.children().parent_alias("O").alias("I");
+        assert_eq!(
+            args_to_str(&children.exists()).unwrap(),
+            r#"EXISTS (SELECT 1 FROM "ITEM" "I" WHERE "I"."ORDER_ID" = "O"."ID" AND "I"."ORDER_YEAR" = "O"."YEAR")"#
+        );
+        let children = children
+            .where_c(TableField::new("I.PRICE").greater(10))
+            .where_c(TableField::new("I.QUANTITY").less(5));
+        assert_eq!(
+            args_to_str(&children.not_exists()).unwrap(),
+            r#"NOT EXISTS (SELECT 1 FROM "ITEM" "I" WHERE "I"."ORDER_ID" = "O"."ID" AND "I"."ORDER_YEAR" = "O"."YEAR" AND ("I"."PRICE" > 10 AND "I"."QUANTITY" < 5))"#
+        );
+    }
+
+    #[test]
+    fn test_count() {
+        let relation = Relation::new(
+            "FK_CUSTOMER_PARENT",
+            "CUSTOMER",
+            "CUSTOMER",
+            fields(&[("PARENT_ID", "ID")]),
+        );
+        let children = relation.children();
+        assert_eq!(
+            args_to_str(&children.at_least(2)).unwrap(),
+            r#"(SELECT COUNT(*) FROM "CUSTOMER" "CUSTOMER_2" WHERE "CUSTOMER_2"."PARENT_ID" = "CUSTOMER"."ID") >= 2"#
+        );
+        assert_eq!(
+            args_to_str(&children.at_most(3)).unwrap(),
+            r#"(SELECT COUNT(*) FROM "CUSTOMER" "CUSTOMER_2" WHERE "CUSTOMER_2"."PARENT_ID" = "CUSTOMER"."ID") <= 3"#
+        );
+        assert_eq!(children.at_least(1), children.exists());
+        assert_eq!(children.at_most(0), children.not_exists());
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{selections::DbValueType, FieldName, IntoFieldName, IntoValue, Value};
+use serde::{Deserialize, Serialize};
+
+/// Value assigned to a column when the row is inserted without it.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ColumnDefault {
+    Value(Value),
+    /// SQL expression written as is, like `CURRENT_TIMESTAMP`.
+    Expression(String),
+}
+
+/// Column of a table in the catalog.
+/// # Example
+/// ```
+/// # use voxi_core::schema::Column;
+/// # use voxi_core::selections::DbValueType;
+/// let column = Column::new("NAME", DbValueType::String(100))
+///     .nullable()
+///     .default_value("unnamed");
+/// assert!(column.nullable);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Column {
+    pub name: FieldName,
+    #[serde(rename = "type")]
+    pub db_type: DbValueType,
+    #[serde(default)]
+    pub nullable: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<ColumnDefault>,
+    #[serde(default)]
+    pub primary_key: bool,
+}
+
+impl Column {
+    /// Create a column not null, without default value.
+    pub fn new(name: impl IntoFieldName, db_type: DbValueType) -> Self {
+        Self {
+            name: name.into_field_name(),
+            db_type,
+            nullable: false,
+            default: None,
+            primary_key: false,
+        }
+    }
+
+    #[must_use]
+    pub fn nullable(mut self) -> Self {
+        self.nullable = true;
+        self
+    }
+
+    /// Make the column part of the primary key, in the order of the table columns.
+    #[must_use]
+    pub fn primary_key(mut self) -> Self {
+        self.primary_key = true;
+        self
+    }
+
+    #[must_use]
+    pub fn default_value(mut self, value: impl IntoValue) -> Self {
+        self.default = Some(ColumnDefault::Value(value.into_value()));
+        self
+    }
+
+    /// Default defined by a SQL expression, like `CURRENT_TIMESTAMP`.
+    #[must_use]
+    pub fn default_expression(mut self, expression: impl Into<String>) -> Self {
+        self.default = Some(ColumnDefault::Expression(expression.into()));
+        self
+    }
+}
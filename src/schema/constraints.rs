@@ -0,0 +1,35 @@
+use crate::{selections::TableName, values::LocalForeignField, FieldName};
+use serde::{Deserialize, Serialize};
+
+/// Columns whose values combination can't repeat in the table.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct UniqueConstraint {
+    pub name: String,
+    pub columns: Vec<FieldName>,
+}
+
+/// Reference from local columns to the columns of `foreign_table`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ForeignKey {
+    pub name: String,
+    pub foreign_table: TableName,
+    pub fields: Vec<LocalForeignField>,
+}
+
+impl ForeignKey {
+    pub fn local_fields(&self) -> impl Iterator<Item = &FieldName> {
+        self.fields.iter().map(|f| &f.local.name)
+    }
+
+    pub fn foreign_fields(&self) -> impl Iterator<Item = &FieldName> {
+        self.fields.iter().map(|f| &f.foreign)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Index {
+    pub name: String,
+    pub columns: Vec<FieldName>,
+    #[serde(default)]
+    pub unique: bool,
+}
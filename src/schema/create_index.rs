@@ -0,0 +1,77 @@
+use super::{create_table::fields_list, Index};
+use crate::{
+    resolvers::args_resolver::ArgsResolver,
+    selections::{IntoTableName, TableName, ToSQL},
+    FieldName, IntoFieldName, SQLError,
+};
+use serde::{Deserialize, Serialize};
+
+/// SQL `CREATE [UNIQUE] INDEX` statement.
+/// # Example
+/// ```
+/// # use voxi_core::schema::CreateIndex;
+/// # use voxi_core::dialects::MsSqlDialect;
+/// # use voxi_core::resolvers::args_resolver_string::{args_to_str, args_to_str_with_dialect};
+/// let create = CreateIndex::new("IX_CUSTOMER_NAME", "CUSTOMER", vec!["NAME", "CITY"]);
+/// assert_eq!(
+///     args_to_str(&create).unwrap(),
+///     r#"CREATE INDEX "IX_CUSTOMER_NAME" ON "CUSTOMER" ("NAME", "CITY")"#
+/// );
+/// assert_eq!(
+///     args_to_str_with_dialect(&create.unique(), MsSqlDialect).unwrap(),
+///     "CREATE UNIQUE INDEX [IX_CUSTOMER_NAME] ON [CUSTOMER] ([NAME], [CITY])"
+/// );
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CreateIndex {
+    pub name: String,
+    pub table: TableName,
+    pub columns: Vec<FieldName>,
+    pub unique: bool,
+}
+
+impl CreateIndex {
+    pub fn new<F: IntoFieldName>(
+        name: impl Into<String>,
+        table: impl IntoTableName,
+        columns: Vec<F>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            table: table.into_table_name(),
+            columns: columns.into_iter().map(|c| c.into_field_name()).collect(),
+            unique: false,
+        }
+    }
+
+    /// Create the statement of an index defined in the catalog for `table`.
+    pub fn from_index(table: TableName, index: &Index) -> Self {
+        Self {
+            name: index.name.clone(),
+            table,
+            columns: index.columns.clone(),
+            unique: index.unique,
+        }
+    }
+
+    #[must_use]
+    pub fn unique(mut self) -> Self {
+        self.unique = true;
+        self
+    }
+}
+
+impl ToSQL for CreateIndex {
+    fn to_sql(
+        &self,
+        args_resolver: &mut dyn ArgsResolver,
+    ) -> error_stack::Result<String, SQLError> {
+        let unique = if self.unique { "UNIQUE " } else { "" };
+        Ok(format!(
+            "CREATE {unique}INDEX {} ON {} {}",
+            args_resolver.dialect().quote_identifier(&self.name),
+            self.table.to_sql(args_resolver)?,
+            fields_list(&self.columns, args_resolver)?
+        ))
+    }
+}
@@ -0,0 +1,234 @@
+use super::{Column, ColumnDefault, CreateIndex, ForeignKey, TableSchema};
+use crate::{
+    dialects::Dialect,
+    resolvers::{
+        args_resolver::ArgsResolver,
+        sql_format::{indented_level, line_break},
+    },
+    selections::ToSQL,
+    FieldName, IntoNullableValue, SQLError,
+};
+use serde::{Deserialize, Serialize};
+
+/// SQL `CREATE TABLE` statement with the columns, primary key, unique and foreign keys of a table.
+/// Indexes are created apart, see `CreateTable::indexes`.
+/// # Example
+/// ```
+/// # use voxi_core::schema::{Column, CreateTable, TableSchemaBuilder};
+/// # use voxi_core::selections::DbValueType;
+/// # use voxi_core::dialects::{MySqlDialect, PostgresDialect};
+/// # use voxi_core::resolvers::args_resolver_string::args_to_str_with_dialect;
+/// let table = TableSchemaBuilder::new("CUSTOMER")
+///     .column(Column::new("ID", DbValueType::Uuid).primary_key())
+///     .column(Column::new("NAME", DbValueType::String(100)).nullable())
+///     .build()
+///     .unwrap();
+/// let create = CreateTable::new(table);
+/// assert_eq!(
+///     args_to_str_with_dialect(&create, PostgresDialect).unwrap(),
+///     r#"CREATE TABLE "CUSTOMER" ("ID" UUID NOT NULL, "NAME" VARCHAR(100), PRIMARY KEY ("ID"))"#
+/// );
+/// assert_eq!(
+///     args_to_str_with_dialect(&create, MySqlDialect).unwrap(),
+///     "CREATE TABLE `CUSTOMER` (`ID` CHAR(36) NOT NULL, `NAME` VARCHAR(100), PRIMARY KEY (`ID`))"
+/// );
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct CreateTable {
+    pub table: TableSchema,
+}
+
+impl CreateTable {
+    pub fn new(table: TableSchema) -> Self {
+        Self { table }
+    }
+
+    /// `CREATE INDEX` statements of the table indexes, to run after the table is created.
+    pub fn indexes(&self) -> Vec<CreateIndex> {
+        self.table
+            .indexes
+            .iter()
+            .map(|index| CreateIndex::from_index(self.table.name.clone(), index))
+            .collect()
+    }
+}
+
+impl ToSQL for CreateTable {
+    fn to_sql(
+        &self,
+        args_resolver: &mut dyn ArgsResolver,
+    ) -> error_stack::Result<String, SQLError> {
+        let table = &self.table;
+        let elements = indented_level(args_resolver, |args_resolver| {
+            let mut elements = table
+                .columns
+                .iter()
+                .map(|c| column_definition(c, args_resolver))
+                .collect::<error_stack::Result<Vec<_>, SQLError>>()?;
+            let primary_key = table.primary_key();
+            if !primary_key.is_empty() {
+                let fields = primary_key.into_iter().map(|c| &c.name);
+                elements.push(format!(
+                    "PRIMARY KEY {}",
+                    fields_list(fields, args_resolver)?
+                ));
+            }
+            for unique in table.uniques.iter() {
+                elements.push(format!(
+                    "CONSTRAINT {} UNIQUE {}",
+                    args_resolver.dialect().quote_identifier(&unique.name),
+                    fields_list(&unique.columns, args_resolver)?
+                ));
+            }
+            for foreign_key in table.foreign_keys.iter() {
+                elements.push(foreign_key_definition(foreign_key, args_resolver)?);
+            }
+            let separator = format!(",{}", line_break(args_resolver, " "));
+            Ok(format!(
+                "{}{}",
+                line_break(args_resolver, ""),
+                elements.join(&separator)
+            ))
+        })?;
+        Ok(format!(
+            "CREATE TABLE {} ({elements}{})",
+            table.name.to_sql(args_resolver)?,
+            line_break(args_resolver, "")
+        ))
+    }
+}
+
+/// Render the name, native type, nullability and default of a column.
+pub(crate) fn column_definition(
+    column: &Column,
+    args_resolver: &mut dyn ArgsResolver,
+) -> error_stack::Result<String, SQLError> {
+    let dialect = args_resolver.dialect();
+    let mut sql = format!(
+        "{} {}",
+        dialect.quote_identifier(column.name.as_ref()),
+        dialect.column_type(&column.db_type)
+    );
+    if !column.nullable {
+        sql.push_str(" NOT NULL");
+    }
+    if let Some(default) = &column.default {
+        sql.push_str(&format!(" DEFAULT {}", default_to_sql(default, dialect)));
+    }
+    Ok(sql)
+}
+
+/// Literal of a default value, expressions are written as is.
+pub(crate) fn default_to_sql(default: &ColumnDefault, dialect: &dyn Dialect) -> String {
+    match default {
+        ColumnDefault::Value(value) => dialect.literal(&value.clone().into_nullable_value()),
+        ColumnDefault::Expression(expression) => expression.clone(),
+    }
+}
+
+/// Render `CONSTRAINT name FOREIGN KEY (..) REFERENCES table (..)`.
+pub(crate) fn foreign_key_definition(
+    foreign_key: &ForeignKey,
+    args_resolver: &mut dyn ArgsResolver,
+) -> error_stack::Result<String, SQLError> {
+    Ok(format!(
+        "CONSTRAINT {} FOREIGN KEY {} REFERENCES {} {}",
+        args_resolver.dialect().quote_identifier(&foreign_key.name),
+        fields_list(foreign_key.local_fields(), args_resolver)?,
+        foreign_key.foreign_table.to_sql(args_resolver)?,
+        fields_list(foreign_key.foreign_fields(), args_resolver)?
+    ))
+}
+
+/// Render quoted field names between parentheses.
+pub(crate) fn fields_list<'a>(
+    fields: impl IntoIterator<Item = &'a FieldName>,
+    args_resolver: &mut dyn ArgsResolver,
+) -> error_stack::Result<String, SQLError> {
+    let fields = fields
+        .into_iter()
+        .map(|f| f.to_sql(args_resolver))
+        .collect::<error_stack::Result<Vec<_>, SQLError>>()?;
+    Ok(format!("({})", fields.join(", ")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        dialects::{MsSqlDialect, MySqlDialect, PostgresDialect, SqliteDialect},
+        resolvers::{
+            args_resolver_string::{args_to_str_formatted, args_to_str_with_dialect},
+            sql_format::SqlFormat,
+        },
+        schema::TableSchemaBuilder,
+        selections::DbValueType,
+    };
+    use pretty_assertions::assert_eq;
+
+    fn orders() -> CreateTable {
+        let table = TableSchemaBuilder::new("ORDERS")
+            .column(Column::new("ID", DbValueType::Int64(0)).primary_key())
+            .column(Column::new("CUSTOMER_ID", DbValueType::Uuid))
+            .column(Column::new("TOTAL", DbValueType::Decimal(10, 2)).default_value(0))
+            .column(Column::new("PAID", DbValueType::Boolean).default_value(false))
+            .column(
+                Column::new("CREATED", DbValueType::DateTime)
+                    .default_expression("CURRENT_TIMESTAMP"),
+            )
+            .column(Column::new("NOTES", DbValueType::Json).nullable())
+            .unique("UQ_ORDERS_CREATED", vec!["CUSTOMER_ID", "CREATED"])
+            .foreign_key(
+                "FK_ORDERS_CUSTOMER",
+                "CUSTOMER",
+                vec![("CUSTOMER_ID", "ID")],
+            )
+            .index("IX_ORDERS_TOTAL", vec!["TOTAL"])
+            .build()
+            .unwrap();
+        CreateTable::new(table)
+    }
+
+    #[test]
+    fn test_create_table() {
+        let create = orders();
+        assert_eq!(
+            args_to_str_with_dialect(&create, PostgresDialect).unwrap(),
+            r#"CREATE TABLE "ORDERS" ("ID" BIGINT NOT NULL, "CUSTOMER_ID" UUID NOT NULL, "TOTAL" NUMERIC(10,2) NOT NULL DEFAULT 0, "PAID" BOOLEAN NOT NULL DEFAULT false, "CREATED" TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP, "NOTES" JSONB, PRIMARY KEY ("ID"), CONSTRAINT "UQ_ORDERS_CREATED" UNIQUE ("CUSTOMER_ID", "CREATED"), CONSTRAINT "FK_ORDERS_CUSTOMER" FOREIGN KEY ("CUSTOMER_ID") REFERENCES "CUSTOMER" ("ID"))"#
+        );
+        assert_eq!(
+            args_to_str_with_dialect(&create, MySqlDialect).unwrap(),
+            "CREATE TABLE `ORDERS` (`ID` BIGINT NOT NULL, `CUSTOMER_ID` CHAR(36) NOT NULL, `TOTAL` DECIMAL(10,2) NOT NULL DEFAULT 0, `PAID` BOOLEAN NOT NULL DEFAULT FALSE, `CREATED` DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP, `NOTES` JSON, PRIMARY KEY (`ID`), CONSTRAINT `UQ_ORDERS_CREATED` UNIQUE (`CUSTOMER_ID`, `CREATED`), CONSTRAINT `FK_ORDERS_CUSTOMER` FOREIGN KEY (`CUSTOMER_ID`) REFERENCES `CUSTOMER` (`ID`))"
+        );
+        assert_eq!(
+            args_to_str_with_dialect(&create, SqliteDialect).unwrap(),
+            r#"CREATE TABLE "ORDERS" ("ID" INTEGER NOT NULL, "CUSTOMER_ID" TEXT NOT NULL, "TOTAL" NUMERIC NOT NULL DEFAULT 0, "PAID" INTEGER NOT NULL DEFAULT 0, "CREATED" TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP, "NOTES" TEXT, PRIMARY KEY ("ID"), CONSTRAINT "UQ_ORDERS_CREATED" UNIQUE ("CUSTOMER_ID", "CREATED"), CONSTRAINT "FK_ORDERS_CUSTOMER" FOREIGN KEY ("CUSTOMER_ID") REFERENCES "CUSTOMER" ("ID"))"#
+        );
+        assert_eq!(
+            args_to_str_with_dialect(&create, MsSqlDialect).unwrap(),
+            "CREATE TABLE [ORDERS] ([ID] BIGINT NOT NULL, [CUSTOMER_ID] UNIQUEIDENTIFIER NOT NULL, [TOTAL] DECIMAL(10,2) NOT NULL DEFAULT 0, [PAID] BIT NOT NULL DEFAULT 0, [CREATED] DATETIME2 NOT NULL DEFAULT CURRENT_TIMESTAMP, [NOTES] NVARCHAR(MAX), PRIMARY KEY ([ID]), CONSTRAINT [UQ_ORDERS_CREATED] UNIQUE ([CUSTOMER_ID], [CREATED]), CONSTRAINT [FK_ORDERS_CUSTOMER] FOREIGN KEY ([CUSTOMER_ID]) REFERENCES [CUSTOMER] ([ID]))"
+        );
+        assert_eq!(
+            args_to_str_with_dialect(&create.indexes()[0], PostgresDialect).unwrap(),
+            r#"CREATE INDEX "IX_ORDERS_TOTAL" ON "ORDERS" ("TOTAL")"#
+        );
+    }
+
+    #[test]
+    fn test_create_table_formatted() {
+        let table = TableSchemaBuilder::new("CUSTOMER")
+            .column(Column::new("ID", DbValueType::Int32(0)).primary_key())
+            .column(Column::new("NAME", DbValueType::String(0)).default_value("unnamed"))
+            .build()
+            .unwrap();
+        assert_eq!(
+            args_to_str_formatted(&CreateTable::new(table), PostgresDialect, &SqlFormat::new())
+                .unwrap(),
+            r#"CREATE TABLE "CUSTOMER" (
+    "ID" INTEGER NOT NULL,
+    "NAME" TEXT NOT NULL DEFAULT 'unnamed',
+    PRIMARY KEY ("ID")
+)"#
+        );
+    }
+}
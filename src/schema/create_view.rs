@@ -0,0 +1,71 @@
+use super::create_table::fields_list;
+use crate::{
+    resolvers::{args_resolver::ArgsResolver, sql_format::line_break},
+    selections::{IntoTableName, Select, TableName, ToSQL},
+    FieldName, IntoFieldName, SQLError,
+};
+use serde::{Deserialize, Serialize};
+
+/// SQL `CREATE VIEW .. AS <Select>` statement.
+/// Views can't have parameters, render it with a resolver expanding the arguments, like
+/// `args_to_str_with_dialect`.
+/// # Example
+/// ```
+/// # use voxi_core::schema::CreateView;
+/// # use voxi_core::selections::Select;
+/// # use voxi_core::dialects::PostgresDialect;
+/// # use voxi_core::resolvers::args_resolver_string::args_to_str_with_dialect;
+/// let query = Select::parse(
+///     r#"SELECT "ID", "NAME" FROM "CUSTOMER" WHERE "ACTIVE" = true"#,
+///     &PostgresDialect,
+/// )
+/// .unwrap();
+/// let create = CreateView::new("ACTIVE_CUSTOMER", query).columns(vec!["CUSTOMER_ID", "NAME"]);
+/// assert_eq!(
+///     args_to_str_with_dialect(&create, PostgresDialect).unwrap(),
+///     r#"CREATE VIEW "ACTIVE_CUSTOMER" ("CUSTOMER_ID", "NAME") AS SELECT "ID","NAME" FROM "CUSTOMER" WHERE "ACTIVE" = true"#
+/// );
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CreateView {
+    pub name: TableName,
+    /// Names of the view columns, when empty they are the names of the query columns.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub columns: Vec<FieldName>,
+    pub query: Select,
+}
+
+impl CreateView {
+    pub fn new(name: impl IntoTableName, query: Select) -> Self {
+        Self {
+            name: name.into_table_name(),
+            columns: Vec::new(),
+            query,
+        }
+    }
+
+    #[must_use]
+    pub fn columns<F: IntoFieldName>(mut self, columns: Vec<F>) -> Self {
+        self.columns = columns.into_iter().map(|c| c.into_field_name()).collect();
+        self
+    }
+}
+
+impl ToSQL for CreateView {
+    fn to_sql(
+        &self,
+        args_resolver: &mut dyn ArgsResolver,
+    ) -> error_stack::Result<String, SQLError> {
+        let mut sql = format!("CREATE VIEW {}", self.name.to_sql(args_resolver)?);
+        if !self.columns.is_empty() {
+            sql.push_str(&format!(" {}", fields_list(&self.columns, args_resolver)?));
+        }
+        sql.push_str(&format!(
+            " AS{}{}",
+            line_break(args_resolver, " "),
+            self.query.to_sql(args_resolver)?
+        ));
+        Ok(sql)
+    }
+}
@@ -0,0 +1,430 @@
+use super::{
+    migration::migration_steps, Catalog, Column, ForeignKey, Index, Migration, TableSchema,
+    UniqueConstraint,
+};
+use crate::{
+    selections::{DbValueType, TableName},
+    FieldName,
+};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Structural change between two versions of a catalog.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum SchemaChange {
+    CreateTable(TableSchema),
+    /// Table of the old catalog removed from the new one.
+    DropTable(TableSchema),
+    AddColumn(TableName, Column),
+    DropColumn(TableName, FieldName),
+    /// Column with a different type, nullability or default, from the old to the new definition.
+    AlterColumn(TableName, Column, Column),
+    /// Columns of the primary key, from the old to the new ones, empty without primary key.
+    ChangePrimaryKey(TableName, Vec<FieldName>, Vec<FieldName>),
+    AddUnique(TableName, UniqueConstraint),
+    DropUnique(TableName, String),
+    AddForeignKey(TableName, ForeignKey),
+    DropForeignKey(TableName, String),
+    AddIndex(TableName, Index),
+    DropIndex(TableName, String),
+}
+
+impl SchemaChange {
+    /// Return `true` when the change can lose data: dropping tables or columns and narrowing
+    /// column types.
+    pub fn is_destructive(&self) -> bool {
+        match self {
+            SchemaChange::DropTable(_) | SchemaChange::DropColumn(_, _) => true,
+            SchemaChange::AlterColumn(_, from, to) => is_narrowing(&from.db_type, &to.db_type),
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for SchemaChange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SchemaChange::CreateTable(table) => write!(f, "create table `{}`", table.name),
+            SchemaChange::DropTable(table) => write!(f, "drop table `{}`", table.name),
+            SchemaChange::AddColumn(table, column) => {
+                write!(f, "add column `{table}.{}`", column.name)
+            }
+            SchemaChange::DropColumn(table, name) => write!(f, "drop column `{table}.{name}`"),
+            SchemaChange::AlterColumn(table, from, to) => write!(
+                f,
+                "alter column `{table}.{}` from {:?} to {:?}",
+                to.name, from.db_type, to.db_type
+            ),
+            SchemaChange::ChangePrimaryKey(table, _, _) => {
+                write!(f, "change primary key of `{table}`")
+            }
+            SchemaChange::AddUnique(table, unique) => {
+                write!(f, "add unique `{}` to `{table}`", unique.name)
+            }
+            SchemaChange::DropUnique(table, name) => {
+                write!(f, "drop unique `{name}` from `{table}`")
+            }
+            SchemaChange::AddForeignKey(table, foreign_key) => {
+                write!(f, "add foreign key `{}` to `{table}`", foreign_key.name)
+            }
+            SchemaChange::DropForeignKey(table, name) => {
+                write!(f, "drop foreign key `{name}` from `{table}`")
+            }
+            SchemaChange::AddIndex(table, index) => {
+                write!(f, "add index `{}` to `{table}`", index.name)
+            }
+            SchemaChange::DropIndex(table, name) => write!(f, "drop index `{name}` from `{table}`"),
+        }
+    }
+}
+
+/// Changes turning a catalog into another one, see `Catalog::diff`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SchemaDiff {
+    pub changes: Vec<SchemaChange>,
+}
+
+impl SchemaDiff {
+    /// Compare tables by name, and their columns and constraints by name.
+    pub fn between(from: &Catalog, to: &Catalog) -> Self {
+        let mut changes = Vec::new();
+        for table in to.tables.iter() {
+            match from.table(table.name.name()) {
+                Some(old) => diff_table(old, table, &mut changes),
+                None => changes.push(SchemaChange::CreateTable(table.clone())),
+            }
+        }
+        for table in from.tables.iter() {
+            if to.table(table.name.name()).is_none() {
+                changes.push(SchemaChange::DropTable(table.clone()));
+            }
+        }
+        Self { changes }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    pub fn destructive(&self) -> impl Iterator<Item = &SchemaChange> {
+        self.changes.iter().filter(|c| c.is_destructive())
+    }
+
+    /// Statements applying the changes, ordered so that constraints are dropped before the
+    /// columns and tables they use, and tables are created before the foreign keys referencing them.
+    pub fn migration(&self) -> Migration {
+        Migration {
+            steps: migration_steps(&self.changes),
+        }
+    }
+}
+
+fn diff_table(from: &TableSchema, to: &TableSchema, changes: &mut Vec<SchemaChange>) {
+    let table = &to.name;
+    for column in to.columns.iter() {
+        match from.column(column.name.as_ref()) {
+            Some(old) => {
+                let changed = old.db_type != column.db_type
+                    || old.nullable != column.nullable
+                    || old.default != column.default;
+                if changed {
+                    changes.push(SchemaChange::AlterColumn(
+                        table.clone(),
+                        old.clone(),
+                        column.clone(),
+                    ));
+                }
+            }
+            None => changes.push(SchemaChange::AddColumn(table.clone(), column.clone())),
+        }
+    }
+    for column in from.columns.iter() {
+        if to.column(column.name.as_ref()).is_none() {
+            changes.push(SchemaChange::DropColumn(table.clone(), column.name.clone()));
+        }
+    }
+    let primary_key = |t: &TableSchema| {
+        t.primary_key()
+            .into_iter()
+            .map(|c| c.name.clone())
+            .collect::<Vec<_>>()
+    };
+    let (old_key, new_key) = (primary_key(from), primary_key(to));
+    if old_key != new_key {
+        changes.push(SchemaChange::ChangePrimaryKey(
+            table.clone(),
+            old_key,
+            new_key,
+        ));
+    }
+    diff_named(
+        &from.uniques,
+        &to.uniques,
+        |u| &u.name,
+        |removed, added| {
+            if let Some(unique) = removed {
+                changes.push(SchemaChange::DropUnique(table.clone(), unique.name.clone()));
+            }
+            if let Some(unique) = added {
+                changes.push(SchemaChange::AddUnique(table.clone(), unique.clone()));
+            }
+        },
+    );
+    diff_named(
+        &from.foreign_keys,
+        &to.foreign_keys,
+        |k| &k.name,
+        |removed, added| {
+            if let Some(foreign_key) = removed {
+                changes.push(SchemaChange::DropForeignKey(
+                    table.clone(),
+                    foreign_key.name.clone(),
+                ));
+            }
+            if let Some(foreign_key) = added {
+                changes.push(SchemaChange::AddForeignKey(
+                    table.clone(),
+                    foreign_key.clone(),
+                ));
+            }
+        },
+    );
+    diff_named(
+        &from.indexes,
+        &to.indexes,
+        |i| &i.name,
+        |removed, added| {
+            if let Some(index) = removed {
+                changes.push(SchemaChange::DropIndex(table.clone(), index.name.clone()));
+            }
+            if let Some(index) = added {
+                changes.push(SchemaChange::AddIndex(table.clone(), index.clone()));
+            }
+        },
+    );
+}
+
+/// Call `change` with the removed and added definitions of constraints matched by name, a
+/// changed constraint is both removed and added.
+fn diff_named<T: PartialEq>(
+    from: &[T],
+    to: &[T],
+    name: impl Fn(&T) -> &String,
+    mut change: impl FnMut(Option<&T>, Option<&T>),
+) {
+    for old in from.iter() {
+        match to.iter().find(|new| name(new) == name(old)) {
+            Some(new) if new == old => {}
+            Some(new) => change(Some(old), Some(new)),
+            None => change(Some(old), None),
+        }
+    }
+    for new in to.iter() {
+        if !from.iter().any(|old| name(old) == name(new)) {
+            change(None, Some(new));
+        }
+    }
+}
+
+/// Return `true` when values of type `from` may not fit in `to`, a string of length `0` has no
+/// limit.
+pub(crate) fn is_narrowing(from: &DbValueType, to: &DbValueType) -> bool {
+    match (from, to) {
+        (_, DbValueType::String(0)) => false,
+        (DbValueType::String(0), DbValueType::String(_)) => true,
+        (DbValueType::String(from), DbValueType::String(to)) => to < from,
+        (DbValueType::Uuid, DbValueType::String(length)) => *length < 36,
+        (DbValueType::Int32(_), DbValueType::Int32(_) | DbValueType::Int64(_)) => false,
+        (DbValueType::Int64(_), DbValueType::Int64(_)) => false,
+        (DbValueType::Int32(_), DbValueType::Decimal(precision, scale)) => {
+            precision.saturating_sub(*scale) < 10
+        }
+        (DbValueType::Int64(_), DbValueType::Decimal(precision, scale)) => {
+            precision.saturating_sub(*scale) < 19
+        }
+        (
+            DbValueType::Decimal(from_precision, from_scale),
+            DbValueType::Decimal(precision, scale),
+        ) => {
+            scale < from_scale
+                || precision.saturating_sub(*scale) < from_precision.saturating_sub(*from_scale)
+        }
+        (DbValueType::Date, DbValueType::DateTime) => false,
+        (from, to) => from != to,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::TableSchemaBuilder;
+    use pretty_assertions::assert_eq;
+
+    fn customer(name: DbValueType) -> TableSchema {
+        TableSchemaBuilder::new("CUSTOMER")
+            .column(Column::new("ID", DbValueType::Int64(0)).primary_key())
+            .column(Column::new("NAME", name))
+            .index("IX_CUSTOMER_NAME", vec!["NAME"])
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_is_narrowing() {
+        assert!(!is_narrowing(
+            &DbValueType::String(10),
+            &DbValueType::String(20)
+        ));
+        assert!(!is_narrowing(
+            &DbValueType::String(10),
+            &DbValueType::String(0)
+        ));
+        assert!(is_narrowing(
+            &DbValueType::String(20),
+            &DbValueType::String(10)
+        ));
+        assert!(is_narrowing(
+            &DbValueType::String(0),
+            &DbValueType::String(10)
+        ));
+        assert!(!is_narrowing(
+            &DbValueType::Decimal(10, 2),
+            &DbValueType::Decimal(12, 4)
+        ));
+        assert!(is_narrowing(
+            &DbValueType::Decimal(10, 2),
+            &DbValueType::Decimal(10, 4)
+        ));
+        assert!(is_narrowing(
+            &DbValueType::Decimal(10, 2),
+            &DbValueType::Decimal(10, 1)
+        ));
+        assert!(!is_narrowing(
+            &DbValueType::Int32(0),
+            &DbValueType::Int64(0)
+        ));
+        assert!(is_narrowing(&DbValueType::Int64(0), &DbValueType::Int32(0)));
+        assert!(!is_narrowing(
+            &DbValueType::Int32(0),
+            &DbValueType::Decimal(12, 2)
+        ));
+        assert!(is_narrowing(
+            &DbValueType::Int64(0),
+            &DbValueType::Decimal(12, 2)
+        ));
+        assert!(!is_narrowing(&DbValueType::Uuid, &DbValueType::String(36)));
+        assert!(is_narrowing(&DbValueType::String(36), &DbValueType::Uuid));
+        assert!(!is_narrowing(&DbValueType::Date, &DbValueType::DateTime));
+        assert!(is_narrowing(&DbValueType::DateTime, &DbValueType::Date));
+    }
+
+    #[test]
+    fn test_diff() {
+        let from = Catalog::new().with_table(customer(DbValueType::String(100)));
+        assert!(from.diff(&from).is_empty());
+
+        let orders = TableSchemaBuilder::new("ORDERS")
+            .column(Column::new("ID", DbValueType::Uuid).primary_key())
+            .build()
+            .unwrap();
+        let mut new_customer = customer(DbValueType::String(50));
+        new_customer
+            .columns
+            .push(Column::new("ACTIVE", DbValueType::Boolean).default_value(true));
+        new_customer.indexes[0].unique = true;
+        let to = Catalog::new()
+            .with_table(new_customer)
+            .with_table(orders.clone());
+        let diff = from.diff(&to);
+        assert_eq!(
+            diff.changes,
+            vec![
+                SchemaChange::AlterColumn(
+                    TableName::from("CUSTOMER"),
+                    Column::new("NAME", DbValueType::String(100)),
+                    Column::new("NAME", DbValueType::String(50))
+                ),
+                SchemaChange::AddColumn(
+                    TableName::from("CUSTOMER"),
+                    Column::new("ACTIVE", DbValueType::Boolean).default_value(true)
+                ),
+                SchemaChange::DropIndex(
+                    TableName::from("CUSTOMER"),
+                    "IX_CUSTOMER_NAME".to_string()
+                ),
+                SchemaChange::AddIndex(
+                    TableName::from("CUSTOMER"),
+                    to.tables[0].indexes[0].clone()
+                ),
+                SchemaChange::CreateTable(orders.clone()),
+            ]
+        );
+        assert_eq!(
+            diff.destructive()
+                .map(|c| c.to_string())
+                .collect::<Vec<_>>(),
+            vec!["alter column `CUSTOMER.NAME` from String(100) to String(50)"]
+        );
+
+        let diff = to.diff(&from);
+        assert_eq!(
+            diff.changes,
+            vec![
+                SchemaChange::AlterColumn(
+                    TableName::from("CUSTOMER"),
+                    Column::new("NAME", DbValueType::String(50)),
+                    Column::new("NAME", DbValueType::String(100))
+                ),
+                SchemaChange::DropColumn(TableName::from("CUSTOMER"), "ACTIVE".into()),
+                SchemaChange::DropIndex(
+                    TableName::from("CUSTOMER"),
+                    "IX_CUSTOMER_NAME".to_string()
+                ),
+                SchemaChange::AddIndex(
+                    TableName::from("CUSTOMER"),
+                    from.tables[0].indexes[0].clone()
+                ),
+                SchemaChange::DropTable(orders),
+            ]
+        );
+        assert_eq!(diff.destructive().count(), 2);
+    }
+
+    #[test]
+    fn test_diff_keys() {
+        let table = |key: &str, foreign: &str| {
+            TableSchemaBuilder::new("ITEM")
+                .column(Column::new("ORDER_ID", DbValueType::Int64(0)).primary_key())
+                .column(Column::new("LINE", DbValueType::Int32(0)))
+                .column(Column::new("PRODUCT_ID", DbValueType::Int64(0)))
+                .unique("UQ_ITEM", vec![key])
+                .foreign_key("FK_ITEM_PRODUCT", foreign, vec![("PRODUCT_ID", "ID")])
+                .build()
+                .unwrap()
+        };
+        let from = Catalog::new().with_table(table("LINE", "PRODUCT"));
+        let mut new_table = table("PRODUCT_ID", "ARTICLE");
+        new_table.columns[1].primary_key = true;
+        let to = Catalog::new().with_table(new_table.clone());
+        assert_eq!(
+            from.diff(&to).changes,
+            vec![
+                SchemaChange::ChangePrimaryKey(
+                    TableName::from("ITEM"),
+                    vec!["ORDER_ID".into()],
+                    vec!["ORDER_ID".into(), "LINE".into()]
+                ),
+                SchemaChange::DropUnique(TableName::from("ITEM"), "UQ_ITEM".to_string()),
+                SchemaChange::AddUnique(TableName::from("ITEM"), new_table.uniques[0].clone()),
+                SchemaChange::DropForeignKey(
+                    TableName::from("ITEM"),
+                    "FK_ITEM_PRODUCT".to_string()
+                ),
+                SchemaChange::AddForeignKey(
+                    TableName::from("ITEM"),
+                    new_table.foreign_keys[0].clone()
+                ),
+            ]
+        );
+    }
+}
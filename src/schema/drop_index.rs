@@ -0,0 +1,48 @@
+use crate::{
+    resolvers::args_resolver::ArgsResolver,
+    selections::{IntoTableName, TableName, ToSQL},
+    SQLError,
+};
+use serde::{Deserialize, Serialize};
+
+/// SQL `DROP INDEX` statement, the table is required by MySQL and MSSQL.
+/// # Example
+/// ```
+/// # use voxi_core::schema::DropIndex;
+/// # use voxi_core::dialects::{MySqlDialect, PostgresDialect};
+/// # use voxi_core::resolvers::args_resolver_string::args_to_str_with_dialect;
+/// let drop = DropIndex::new("IX_CUSTOMER_NAME", "CUSTOMER");
+/// assert_eq!(
+///     args_to_str_with_dialect(&drop, PostgresDialect).unwrap(),
+///     r#"DROP INDEX "IX_CUSTOMER_NAME""#
+/// );
+/// assert_eq!(
+///     args_to_str_with_dialect(&drop, MySqlDialect).unwrap(),
+///     "DROP INDEX `IX_CUSTOMER_NAME` ON `CUSTOMER`"
+/// );
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DropIndex {
+    pub name: String,
+    pub table: TableName,
+}
+
+impl DropIndex {
+    pub fn new(name: impl Into<String>, table: impl IntoTableName) -> Self {
+        Self {
+            name: name.into(),
+            table: table.into_table_name(),
+        }
+    }
+}
+
+impl ToSQL for DropIndex {
+    fn to_sql(
+        &self,
+        args_resolver: &mut dyn ArgsResolver,
+    ) -> error_stack::Result<String, SQLError> {
+        let table = self.table.to_sql(args_resolver)?;
+        let dialect = args_resolver.dialect();
+        Ok(dialect.drop_index(&dialect.quote_identifier(&self.name), &table))
+    }
+}
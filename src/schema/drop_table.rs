@@ -0,0 +1,38 @@
+use crate::{
+    resolvers::args_resolver::ArgsResolver,
+    selections::{IntoTableName, TableName, ToSQL},
+    SQLError,
+};
+use serde::{Deserialize, Serialize};
+
+/// SQL `DROP TABLE` statement.
+/// # Example
+/// ```
+/// # use voxi_core::schema::DropTable;
+/// # use voxi_core::resolvers::args_resolver_string::args_to_str;
+/// assert_eq!(
+///     args_to_str(&DropTable::new("CUSTOMER")).unwrap(),
+///     r#"DROP TABLE "CUSTOMER""#
+/// );
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DropTable {
+    pub table: TableName,
+}
+
+impl DropTable {
+    pub fn new(table: impl IntoTableName) -> Self {
+        Self {
+            table: table.into_table_name(),
+        }
+    }
+}
+
+impl ToSQL for DropTable {
+    fn to_sql(
+        &self,
+        args_resolver: &mut dyn ArgsResolver,
+    ) -> error_stack::Result<String, SQLError> {
+        Ok(format!("DROP TABLE {}", self.table.to_sql(args_resolver)?))
+    }
+}
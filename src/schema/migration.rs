@@ -0,0 +1,408 @@
+use super::{
+    AlterTable, AlterTableAction, CreateIndex, CreateTable, CreateView, DropIndex, DropTable,
+    ForeignKey, SchemaChange, TableSchema,
+};
+use crate::{
+    resolvers::args_resolver::ArgsResolver,
+    selections::{TableName, ToSQL},
+    SQLError,
+};
+use serde::{Deserialize, Serialize};
+
+/// Any DDL statement, to run a list of them in order.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum DdlStatement {
+    CreateTable(CreateTable),
+    DropTable(DropTable),
+    AlterTable(AlterTable),
+    CreateIndex(CreateIndex),
+    DropIndex(DropIndex),
+    CreateView(CreateView),
+}
+
+impl ToSQL for DdlStatement {
+    fn to_sql(
+        &self,
+        args_resolver: &mut dyn ArgsResolver,
+    ) -> error_stack::Result<String, SQLError> {
+        match self {
+            DdlStatement::CreateTable(statement) => statement.to_sql(args_resolver),
+            DdlStatement::DropTable(statement) => statement.to_sql(args_resolver),
+            DdlStatement::AlterTable(statement) => statement.to_sql(args_resolver),
+            DdlStatement::CreateIndex(statement) => statement.to_sql(args_resolver),
+            DdlStatement::DropIndex(statement) => statement.to_sql(args_resolver),
+            DdlStatement::CreateView(statement) => statement.to_sql(args_resolver),
+        }
+    }
+}
+
+/// Statement of a migration, `destructive` when it can lose data.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct MigrationStep {
+    pub statement: DdlStatement,
+    pub destructive: bool,
+}
+
+/// Ordered statements applying a `SchemaDiff`.
+/// Rendered as a script with a statement per line, destructive ones preceded by a warning comment.
+/// # Example
+/// ```
+/// # use voxi_core::schema::{Catalog, Column, TableSchemaBuilder};
+/// # use voxi_core::selections::DbValueType;
+/// # use voxi_core::dialects::PostgresDialect;
+/// # use voxi_core::resolvers::args_resolver_string::args_to_str_with_dialect;
+/// let customer = |name: DbValueType| {
+///     TableSchemaBuilder::new("CUSTOMER")
+///         .column(Column::new("ID", DbValueType::Int64(0)).primary_key())
+///         .column(Column::new("NAME", name).nullable())
+///         .build()
+///         .unwrap()
+/// };
+/// let from = Catalog::new().with_table(customer(DbValueType::String(100)));
+/// let to = Catalog::new().with_table(customer(DbValueType::String(50)));
+/// let migration = from.diff(&to).migration();
+/// assert!(migration.is_destructive());
+/// assert_eq!(
+///     args_to_str_with_dialect(&migration, PostgresDialect).unwrap(),
+///     r#"-- WARNING: destructive, data may be lost
+/// ALTER TABLE "CUSTOMER" ALTER COLUMN "NAME" TYPE VARCHAR(50), ALTER COLUMN "NAME" DROP NOT NULL, ALTER COLUMN "NAME" DROP DEFAULT;"#
+/// );
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct Migration {
+    pub steps: Vec<MigrationStep>,
+}
+
+impl Migration {
+    pub fn is_destructive(&self) -> bool {
+        self.steps.iter().any(|s| s.destructive)
+    }
+}
+
+impl ToSQL for Migration {
+    fn to_sql(
+        &self,
+        args_resolver: &mut dyn ArgsResolver,
+    ) -> error_stack::Result<String, SQLError> {
+        let statements = self
+            .steps
+            .iter()
+            .map(|step| {
+                let sql = step.statement.to_sql(args_resolver)?;
+                if step.destructive {
+                    Ok(format!("-- WARNING: destructive, data may be lost\n{sql};"))
+                } else {
+                    Ok(format!("{sql};"))
+                }
+            })
+            .collect::<error_stack::Result<Vec<_>, SQLError>>()?;
+        Ok(statements.join("\n"))
+    }
+}
+
+/// Order in which the statements run, dropping dependent objects first and creating them last.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Phase {
+    DropForeignKey,
+    DropConstraint,
+    DropColumn,
+    DropTable,
+    CreateTable,
+    AddColumn,
+    AlterColumn,
+    AddConstraint,
+    CreateIndex,
+    AddForeignKey,
+}
+
+pub(crate) fn migration_steps(changes: &[SchemaChange]) -> Vec<MigrationStep> {
+    let (created, cyclic) = dependency_order(changes.iter().filter_map(|c| match c {
+        SchemaChange::CreateTable(table) => Some(table),
+        _ => None,
+    }));
+    let (dropped, dropped_cyclic) = dependency_order(changes.iter().filter_map(|c| match c {
+        SchemaChange::DropTable(table) => Some(table),
+        _ => None,
+    }));
+    let mut steps = Vec::new();
+    for change in changes.iter() {
+        let destructive = change.is_destructive();
+        let mut push = |phase: Phase, order: usize, statement: DdlStatement| {
+            steps.push((phase, order, statement, destructive))
+        };
+        let alter = |table: &TableName, action| {
+            DdlStatement::AlterTable(AlterTable::new(table.clone(), action))
+        };
+        match change {
+            SchemaChange::CreateTable(table) => {
+                // keys of a reference cycle are added once all the tables exist
+                let (deferred, foreign_keys) = table
+                    .foreign_keys
+                    .iter()
+                    .cloned()
+                    .partition::<Vec<_>, _>(|key| {
+                        cyclic
+                            .iter()
+                            .any(|(name, k)| **name == table.name && k.name == key.name)
+                    });
+                let order = position(&created, table);
+                for foreign_key in deferred {
+                    let action = AlterTableAction::AddForeignKey(foreign_key);
+                    push(Phase::AddForeignKey, order, alter(&table.name, action));
+                }
+                let create = CreateTable::new(TableSchema {
+                    foreign_keys,
+                    ..table.clone()
+                });
+                for index in create.indexes() {
+                    push(Phase::CreateIndex, order, DdlStatement::CreateIndex(index));
+                }
+                push(Phase::CreateTable, order, DdlStatement::CreateTable(create));
+            }
+            SchemaChange::DropTable(table) => {
+                // tables referencing others are dropped first
+                let order = dropped.len() - position(&dropped, table);
+                // keys of a reference cycle are dropped before any of its tables
+                for (_, foreign_key) in dropped_cyclic.iter().filter(|(n, _)| **n == table.name) {
+                    let action = AlterTableAction::DropConstraint(foreign_key.name.clone());
+                    push(Phase::DropForeignKey, order, alter(&table.name, action));
+                }
+                let statement = DdlStatement::DropTable(DropTable::new(table.name.clone()));
+                push(Phase::DropTable, order, statement);
+            }
+            SchemaChange::AddColumn(table, column) => {
+                let action = AlterTableAction::AddColumn(column.clone());
+                push(Phase::AddColumn, 0, alter(table, action));
+            }
+            SchemaChange::DropColumn(table, name) => {
+                let action = AlterTableAction::DropColumn(name.clone());
+                push(Phase::DropColumn, 0, alter(table, action));
+            }
+            SchemaChange::AlterColumn(table, from, to) => {
+                if from.db_type != to.db_type || from.nullable != to.nullable {
+                    let action = AlterTableAction::AlterColumn(to.clone());
+                    push(Phase::AlterColumn, 0, alter(table, action));
+                }
+                // not every dialect changes the default when redefining the column
+                if from.default != to.default {
+                    let action =
+                        AlterTableAction::AlterDefault(to.name.clone(), to.default.clone());
+                    push(Phase::AlterColumn, 0, alter(table, action));
+                }
+            }
+            SchemaChange::ChangePrimaryKey(table, from, to) => {
+                if !from.is_empty() {
+                    let action = AlterTableAction::DropPrimaryKey;
+                    push(Phase::DropConstraint, 0, alter(table, action));
+                }
+                if !to.is_empty() {
+                    let action = AlterTableAction::AddPrimaryKey(to.clone());
+                    push(Phase::AddConstraint, 0, alter(table, action));
+                }
+            }
+            SchemaChange::AddUnique(table, unique) => {
+                let action = AlterTableAction::AddUnique(unique.clone());
+                push(Phase::AddConstraint, 0, alter(table, action));
+            }
+            SchemaChange::DropUnique(table, name) => {
+                let action = AlterTableAction::DropConstraint(name.clone());
+                push(Phase::DropConstraint, 0, alter(table, action));
+            }
+            SchemaChange::AddForeignKey(table, foreign_key) => {
+                let action = AlterTableAction::AddForeignKey(foreign_key.clone());
+                push(Phase::AddForeignKey, 0, alter(table, action));
+            }
+            SchemaChange::DropForeignKey(table, name) => {
+                let action = AlterTableAction::DropConstraint(name.clone());
+                push(Phase::DropForeignKey, 0, alter(table, action));
+            }
+            SchemaChange::AddIndex(table, index) => {
+                let statement = CreateIndex::from_index(table.clone(), index);
+                push(Phase::CreateIndex, 0, DdlStatement::CreateIndex(statement));
+            }
+            SchemaChange::DropIndex(table, name) => {
+                let statement = DropIndex::new(name.clone(), table.clone());
+                push(Phase::DropConstraint, 0, DdlStatement::DropIndex(statement));
+            }
+        }
+    }
+    steps.sort_by_key(|(phase, order, _, _)| (*phase, *order));
+    steps
+        .into_iter()
+        .map(|(_, _, statement, destructive)| MigrationStep {
+            statement,
+            destructive,
+        })
+        .collect()
+}
+
+/// Sort tables so that a table comes after the tables its foreign keys reference.
+/// A reference cycle is broken by taking its first table, also returning its foreign keys to
+/// tables coming after it.
+fn dependency_order<'a>(
+    tables: impl Iterator<Item = &'a TableSchema>,
+) -> (Vec<&'a TableSchema>, Vec<(&'a TableName, &'a ForeignKey)>) {
+    let mut pending = tables.collect::<Vec<_>>();
+    let mut ordered: Vec<&TableSchema> = Vec::with_capacity(pending.len());
+    let mut cyclic = Vec::new();
+    while !pending.is_empty() {
+        let ready = pending.iter().position(|table| {
+            table.foreign_keys.iter().all(|k| {
+                k.foreign_table == table.name || !pending.iter().any(|t| t.name == k.foreign_table)
+            })
+        });
+        let table = pending.remove(ready.unwrap_or(0));
+        if ready.is_none() {
+            cyclic.extend(
+                table
+                    .foreign_keys
+                    .iter()
+                    .filter(|k| pending.iter().any(|t| t.name == k.foreign_table))
+                    .map(|k| (&table.name, k)),
+            );
+        }
+        ordered.push(table);
+    }
+    (ordered, cyclic)
+}
+
+fn position(tables: &[&TableSchema], table: &TableSchema) -> usize {
+    tables
+        .iter()
+        .position(|t| t.name == table.name)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        dialects::{MsSqlDialect, MySqlDialect, PostgresDialect, SqliteDialect},
+        resolvers::args_resolver_string::args_to_str_with_dialect,
+        schema::{Catalog, Column, TableSchemaBuilder},
+        selections::DbValueType,
+    };
+    use pretty_assertions::assert_eq;
+
+    fn table(name: &str, foreign: Option<&str>) -> TableSchema {
+        let builder = TableSchemaBuilder::new(name)
+            .column(Column::new("ID", DbValueType::Int64(0)).primary_key());
+        match foreign {
+            Some(foreign) => builder
+                .column(Column::new("PARENT_ID", DbValueType::Int64(0)))
+                .foreign_key(format!("FK_{name}"), foreign, vec![("PARENT_ID", "ID")])
+                .index(format!("IX_{name}"), vec!["PARENT_ID"]),
+            None => builder,
+        }
+        .build()
+        .unwrap()
+    }
+
+    #[test]
+    fn test_create_drop_order() {
+        let from = Catalog::new();
+        let to = Catalog::new()
+            .with_table(table("ITEM", Some("ORDERS")))
+            .with_table(table("ORDERS", Some("CUSTOMER")))
+            .with_table(table("CUSTOMER", None));
+        let migration = from.diff(&to).migration();
+        assert!(!migration.is_destructive());
+        assert_eq!(
+            args_to_str_with_dialect(&migration, MySqlDialect).unwrap(),
+            "CREATE TABLE `CUSTOMER` (`ID` BIGINT NOT NULL, PRIMARY KEY (`ID`));
+CREATE TABLE `ORDERS` (`ID` BIGINT NOT NULL, `PARENT_ID` BIGINT NOT NULL, PRIMARY KEY (`ID`), CONSTRAINT `FK_ORDERS` FOREIGN KEY (`PARENT_ID`) REFERENCES `CUSTOMER` (`ID`));
+CREATE TABLE `ITEM` (`ID` BIGINT NOT NULL, `PARENT_ID` BIGINT NOT NULL, PRIMARY KEY (`ID`), CONSTRAINT `FK_ITEM` FOREIGN KEY (`PARENT_ID`) REFERENCES `ORDERS` (`ID`));
+CREATE INDEX `IX_ORDERS` ON `ORDERS` (`PARENT_ID`);
+CREATE INDEX `IX_ITEM` ON `ITEM` (`PARENT_ID`);"
+        );
+        let migration = to.diff(&from).migration();
+        assert!(migration.steps.iter().all(|s| s.destructive));
+        let tables = migration
+            .steps
+            .iter()
+            .map(|s| match &s.statement {
+                DdlStatement::DropTable(drop) => drop.table.name(),
+                _ => unreachable!(),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(tables, vec!["ITEM", "ORDERS", "CUSTOMER"]);
+    }
+
+    #[test]
+    fn test_alter_order() {
+        let from = Catalog::new()
+            .with_table(table("CUSTOMER", None))
+            .with_table(table("ORDERS", Some("CUSTOMER")));
+        let mut orders = table("ORDERS", None);
+        orders
+            .columns
+            .push(Column::new("TOTAL", DbValueType::Decimal(10, 2)).nullable());
+        let to = Catalog::new().with_table(orders);
+        let migration = from.diff(&to).migration();
+        assert_eq!(
+            args_to_str_with_dialect(&migration, MySqlDialect).unwrap(),
+            "ALTER TABLE `ORDERS` DROP CONSTRAINT `FK_ORDERS`;
+DROP INDEX `IX_ORDERS` ON `ORDERS`;
+-- WARNING: destructive, data may be lost
+ALTER TABLE `ORDERS` DROP COLUMN `PARENT_ID`;
+-- WARNING: destructive, data may be lost
+DROP TABLE `CUSTOMER`;
+ALTER TABLE `ORDERS` ADD COLUMN `TOTAL` DECIMAL(10,2);"
+        );
+        assert!(args_to_str_with_dialect(&migration, SqliteDialect).is_err());
+    }
+
+    #[test]
+    fn test_create_cycle() {
+        let to = Catalog::new()
+            .with_table(table("ORDERS", Some("INVOICE")))
+            .with_table(table("INVOICE", Some("ORDERS")));
+        let migration = Catalog::new().diff(&to).migration();
+        assert_eq!(
+            args_to_str_with_dialect(&migration, MySqlDialect).unwrap(),
+            "CREATE TABLE `ORDERS` (`ID` BIGINT NOT NULL, `PARENT_ID` BIGINT NOT NULL, PRIMARY KEY (`ID`));
+CREATE TABLE `INVOICE` (`ID` BIGINT NOT NULL, `PARENT_ID` BIGINT NOT NULL, PRIMARY KEY (`ID`), CONSTRAINT `FK_INVOICE` FOREIGN KEY (`PARENT_ID`) REFERENCES `ORDERS` (`ID`));
+CREATE INDEX `IX_ORDERS` ON `ORDERS` (`PARENT_ID`);
+CREATE INDEX `IX_INVOICE` ON `INVOICE` (`PARENT_ID`);
+ALTER TABLE `ORDERS` ADD CONSTRAINT `FK_ORDERS` FOREIGN KEY (`PARENT_ID`) REFERENCES `INVOICE` (`ID`);"
+        );
+    }
+
+    #[test]
+    fn test_drop_cycle() {
+        let from = Catalog::new()
+            .with_table(table("ORDERS", Some("INVOICE")))
+            .with_table(table("INVOICE", Some("ORDERS")));
+        let migration = from.diff(&Catalog::new()).migration();
+        assert_eq!(
+            args_to_str_with_dialect(&migration, PostgresDialect).unwrap(),
+            r#"-- WARNING: destructive, data may be lost
+ALTER TABLE "ORDERS" DROP CONSTRAINT "FK_ORDERS";
+-- WARNING: destructive, data may be lost
+DROP TABLE "INVOICE";
+-- WARNING: destructive, data may be lost
+DROP TABLE "ORDERS";"#
+        );
+    }
+
+    #[test]
+    fn test_alter_default() {
+        let from = Catalog::new().with_table(table("ORDERS", None));
+        let mut orders = table("ORDERS", None);
+        orders.columns[0] = Column::new("ID", DbValueType::Int64(0))
+            .primary_key()
+            .default_value(1);
+        let to = Catalog::new().with_table(orders);
+        let migration = from.diff(&to).migration();
+        assert_eq!(
+            args_to_str_with_dialect(&migration, PostgresDialect).unwrap(),
+            r#"ALTER TABLE "ORDERS" ALTER COLUMN "ID" SET DEFAULT 1;"#
+        );
+        assert!(args_to_str_with_dialect(&migration, MsSqlDialect).is_err());
+        let migration = to.diff(&from).migration();
+        assert_eq!(
+            args_to_str_with_dialect(&migration, MySqlDialect).unwrap(),
+            "ALTER TABLE `ORDERS` ALTER COLUMN `ID` DROP DEFAULT;"
+        );
+    }
+}
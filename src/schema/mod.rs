@@ -0,0 +1,31 @@
+pub(crate) mod alter_table;
+pub(crate) mod catalog;
+pub(crate) mod children_filter;
+pub(crate) mod column;
+pub(crate) mod constraints;
+pub(crate) mod create_index;
+pub(crate) mod create_table;
+pub(crate) mod create_view;
+pub(crate) mod diff;
+pub(crate) mod drop_index;
+pub(crate) mod drop_table;
+pub(crate) mod migration;
+pub(crate) mod relations;
+pub(crate) mod table_schema;
+pub(crate) mod validator;
+
+pub use alter_table::{AlterTable, AlterTableAction};
+pub use catalog::Catalog;
+pub use children_filter::ChildrenFilter;
+pub use column::{Column, ColumnDefault};
+pub use constraints::{ForeignKey, Index, UniqueConstraint};
+pub use create_index::CreateIndex;
+pub use create_table::CreateTable;
+pub use create_view::CreateView;
+pub use diff::{SchemaChange, SchemaDiff};
+pub use drop_index::DropIndex;
+pub use drop_table::DropTable;
+pub use migration::{DdlStatement, Migration, MigrationStep};
+pub use relations::{Relation, RelationGraph};
+pub use table_schema::{TableSchema, TableSchemaBuilder};
+pub use validator::{Clause, Diagnostic, DiagnosticKind, DiagnosticNode};
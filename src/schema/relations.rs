@@ -0,0 +1,489 @@
+use super::Catalog;
+use crate::{
+    selections::{
+        ConditionWhere, ConditionWhereOperation, FromSelect, IntoTableName, Join, QueryBuilder,
+        Table, TableName,
+    },
+    values::LocalForeignField,
+    SchemaError,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+
+/// Foreign key from `local_table` to `foreign_table`, traversed in both directions by
+/// `RelationGraph`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Relation {
+    pub name: String,
+    pub local_table: TableName,
+    pub foreign_table: TableName,
+    pub fields: Vec<LocalForeignField>,
+    /// `true` when a local field accepts null, so a local row may have no foreign row.
+    #[serde(default)]
+    pub nullable: bool,
+}
+
+impl Relation {
+    pub fn new(
+        name: impl Into<String>,
+        local_table: impl IntoTableName,
+        foreign_table: impl IntoTableName,
+        fields: Vec<LocalForeignField>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            local_table: local_table.into_table_name(),
+            foreign_table: foreign_table.into_table_name(),
+            fields,
+            nullable: false,
+        }
+    }
+
+    #[must_use]
+    pub fn nullable(mut self) -> Self {
+        self.nullable = true;
+        self
+    }
+}
+
+/// Relations between tables, used to infer the joins going through a list of tables.
+/// # Example
+/// ```
+/// # use voxi_core::schema::{Catalog, Column, TableSchemaBuilder};
+/// # use voxi_core::selections::{DbValueType, QueryBuilder};
+/// # use voxi_core::resolvers::args_resolver_string::args_to_str;
+/// let region = TableSchemaBuilder::new("REGION")
+///     .column(Column::new("ID", DbValueType::Int32(0)).primary_key())
+///     .build()
+///     .unwrap();
+/// let customer = TableSchemaBuilder::new("CUSTOMER")
+///     .column(Column::new("ID", DbValueType::Int32(0)).primary_key())
+///     .column(Column::new("REGION_ID", DbValueType::Int32(0)).nullable())
+///     .foreign_key("FK_CUSTOMER_REGION", "REGION", vec![("REGION_ID", "ID")])
+///     .build()
+///     .unwrap();
+/// let orders = TableSchemaBuilder::new("ORDERS")
+///     .column(Column::new("ID", DbValueType::Int32(0)).primary_key())
+///     .column(Column::new("CUSTOMER_ID", DbValueType::Int32(0)))
+///     .foreign_key("FK_ORDERS_CUSTOMER", "CUSTOMER", vec![("CUSTOMER_ID", "ID")])
+///     .build()
+///     .unwrap();
+/// let graph = Catalog::new()
+///     .with_table(region)
+///     .with_table(customer)
+///     .with_table(orders)
+///     .relations();
+/// let query = QueryBuilder::new()
+///     .field("ORDERS.ID")
+///     .join_path(&graph, ["ORDERS", "CUSTOMER", "REGION"])
+///     .unwrap()
+///     .build()
+///     .unwrap();
+/// assert_eq!(
+///     args_to_str(&query).unwrap(),
+///     r#"SELECT "ORDERS"."ID" FROM "ORDERS" INNER JOIN "CUSTOMER" ON "CUSTOMER"."ID" = "ORDERS"."CUSTOMER_ID" LEFT JOIN "REGION" ON "REGION"."ID" = "CUSTOMER"."REGION_ID""#
+/// );
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RelationGraph {
+    relations: Vec<Relation>,
+}
+
+impl RelationGraph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create the relations of the catalog foreign keys, nullable when a local column is nullable.
+    pub fn from_catalog(catalog: &Catalog) -> Self {
+        let relations = catalog
+            .tables
+            .iter()
+            .flat_map(|table| {
+                table.foreign_keys.iter().map(|foreign_key| {
+                    let nullable = foreign_key
+                        .local_fields()
+                        .any(|f| table.column(f.as_ref()).is_some_and(|c| c.nullable));
+                    Relation {
+                        name: foreign_key.name.clone(),
+                        local_table: table.name.clone(),
+                        foreign_table: foreign_key.foreign_table.clone(),
+                        fields: foreign_key.fields.clone(),
+                        nullable,
+                    }
+                })
+            })
+            .collect();
+        Self { relations }
+    }
+
+    #[must_use]
+    pub fn relation(mut self, relation: Relation) -> Self {
+        self.relations.push(relation);
+        self
+    }
+
+    pub fn relations(&self) -> &[Relation] {
+        &self.relations
+    }
+
+    /// Joins going through `tables` in order, the first table being the source of the query.
+    /// Consecutive tables are linked by the shortest path of relations, intermediate tables are
+    /// joined too, and a table can be followed by itself for a self relation.
+    ///
+    /// Tables are qualified by their names, a table appearing again gets the alias `<TABLE>_<n>`.
+    /// A join is `LEFT` when it follows a nullable relation to its foreign table, or comes after
+    /// a `LEFT` join, otherwise it's `INNER`.
+    ///
+    /// Fails when two tables have no path between them, or more than one shortest path.
+    pub fn join_path<T: IntoTableName>(
+        &self,
+        tables: impl IntoIterator<Item = T>,
+    ) -> Result<Vec<Join>, SchemaError> {
+        let tables = tables
+            .into_iter()
+            .map(|t| t.into_table_name())
+            .collect::<Vec<_>>();
+        let mut aliases = HashMap::new();
+        let Some(first) = tables.first() else {
+            return Ok(Vec::new());
+        };
+        let mut current = path_table(first, &mut aliases);
+        let mut left = false;
+        let mut joins = Vec::new();
+        for pair in tables.windows(2) {
+            for step in self.path(&pair[0], &pair[1])? {
+                let next = path_table(step.target(), &mut aliases);
+                left |= step.forward && step.relation.nullable;
+                let condition = step
+                    .relation
+                    .fields
+                    .iter()
+                    .map(|field| step.condition(field, &current, &next))
+                    .collect::<Vec<_>>();
+                let mut from = FromSelect::from_table(next.name.clone());
+                if let Some(alias) = &next.alias {
+                    from = from.with_alias(alias.clone());
+                }
+                joins.push(if left {
+                    Join::left(from, condition)
+                } else {
+                    Join::inner(from, condition)
+                });
+                current = next;
+            }
+        }
+        Ok(joins)
+    }
+
+    /// The only shortest path from `from` to `to`.
+    fn path<'a>(
+        &'a self,
+        from: &'a TableName,
+        to: &TableName,
+    ) -> Result<Vec<Step<'a>>, SchemaError> {
+        let mut paths = self.shortest_paths(from, to);
+        match paths.len() {
+            0 => Err(SchemaError::NoRelationPath(
+                from.name().to_string(),
+                to.name().to_string(),
+            )),
+            1 => Ok(paths.remove(0)),
+            _ => {
+                let paths = paths
+                    .iter()
+                    .map(|path| {
+                        path.iter()
+                            .map(|step| format!("`{}`", step.relation.name))
+                            .collect::<Vec<_>>()
+                            .join(" > ")
+                    })
+                    .collect::<Vec<_>>();
+                Err(SchemaError::AmbiguousRelationPath(
+                    from.name().to_string(),
+                    to.name().to_string(),
+                    paths.join(" or "),
+                ))
+            }
+        }
+    }
+
+    /// Shortest paths from `from` to `to`, stopping at the second one found.
+    fn shortest_paths<'a>(&'a self, from: &'a TableName, to: &TableName) -> Vec<Vec<Step<'a>>> {
+        if from == to {
+            return self
+                .relations
+                .iter()
+                .filter(|r| r.local_table == *from && r.foreign_table == *from)
+                .map(|relation| {
+                    vec![Step {
+                        relation,
+                        forward: true,
+                    }]
+                })
+                .collect();
+        }
+        // breadth-first search, keeping every step reaching a table by its shortest distance
+        let mut distances = HashMap::from([(from, 0)]);
+        let mut predecessors: HashMap<&TableName, Vec<(&TableName, Step)>> = HashMap::new();
+        let mut queue = VecDeque::from([from]);
+        while let Some(table) = queue.pop_front() {
+            let distance = distances[table] + 1;
+            if distances.get(to).is_some_and(|d| distance > *d) {
+                break;
+            }
+            for step in self.steps(table) {
+                let next = step.target();
+                match distances.get(next) {
+                    Some(d) if *d < distance => continue,
+                    Some(_) => {}
+                    None => {
+                        distances.insert(next, distance);
+                        queue.push_back(next);
+                    }
+                }
+                predecessors.entry(next).or_default().push((table, step));
+            }
+        }
+        let mut paths = Vec::new();
+        collect_paths(&predecessors, from, to, Vec::new(), &mut paths);
+        paths
+    }
+
+    /// Steps leaving `table` through relations with other tables.
+    fn steps<'a>(&'a self, table: &'a TableName) -> impl Iterator<Item = Step<'a>> {
+        self.relations
+            .iter()
+            .filter(|r| r.local_table != r.foreign_table)
+            .filter_map(move |relation| {
+                if relation.local_table == *table {
+                    Some(Step {
+                        relation,
+                        forward: true,
+                    })
+                } else if relation.foreign_table == *table {
+                    Some(Step {
+                        relation,
+                        forward: false,
+                    })
+                } else {
+                    None
+                }
+            })
+    }
+}
+
+impl QueryBuilder {
+    /// Add the joins going through `path`, inferred from the relations of `graph`, see
+    /// `RelationGraph::join_path`. The first table of the path is added to `FROM` when it's empty.
+    pub fn join_path<T: IntoTableName>(
+        self,
+        graph: &RelationGraph,
+        path: impl IntoIterator<Item = T>,
+    ) -> Result<Self, SchemaError> {
+        let path = path
+            .into_iter()
+            .map(|t| t.into_table_name())
+            .collect::<Vec<_>>();
+        let joins = graph.join_path(path.iter().cloned())?;
+        Ok(match path.into_iter().next() {
+            Some(first) => self.join_from(first, joins),
+            None => self,
+        })
+    }
+}
+
+/// Relation traversed from the local to the foreign table when `forward`, or the opposite.
+#[derive(Debug, Clone, Copy)]
+struct Step<'a> {
+    relation: &'a Relation,
+    forward: bool,
+}
+
+impl<'a> Step<'a> {
+    fn target(&self) -> &'a TableName {
+        if self.forward {
+            &self.relation.foreign_table
+        } else {
+            &self.relation.local_table
+        }
+    }
+
+    /// Equality of a key field, the field of the joined table `next` comes first.
+    fn condition(
+        &self,
+        field: &LocalForeignField,
+        current: &Table,
+        next: &Table,
+    ) -> ConditionWhere {
+        if self.forward {
+            next.field(field.foreign.clone())
+                .equal(current.field(field.local.name.clone()))
+        } else {
+            next.field(field.local.name.clone())
+                .equal(current.field(field.foreign.clone()))
+        }
+    }
+}
+
+fn collect_paths<'a>(
+    predecessors: &HashMap<&TableName, Vec<(&TableName, Step<'a>)>>,
+    from: &TableName,
+    table: &TableName,
+    suffix: Vec<Step<'a>>,
+    paths: &mut Vec<Vec<Step<'a>>>,
+) {
+    if paths.len() > 1 {
+        return;
+    }
+    if table == from {
+        paths.push(suffix.into_iter().rev().collect());
+        return;
+    }
+    for (previous, step) in predecessors.get(table).into_iter().flatten() {
+        let mut path = suffix.clone();
+        path.push(*step);
+        collect_paths(predecessors, from, previous, path, paths);
+    }
+}
+
+/// Table of the path, aliased as `<TABLE>_<n>` when it already appeared.
+fn path_table(name: &TableName, aliases: &mut HashMap<TableName, usize>) -> Table {
+    let count = aliases.entry(name.clone()).or_default();
+    *count += 1;
+    let table = Table::new(name.clone());
+    if *count > 1 {
+        table.with_alias(format!("{}_{count}", name.name()))
+    } else {
+        table
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{resolvers::args_resolver_string::args_to_str, FieldNameType, ValueType};
+    use pretty_assertions::assert_eq;
+
+    fn fields(pairs: &[(&str, &str)]) -> Vec<LocalForeignField> {
+        pairs
+            .iter()
+            .map(|(local, foreign)| LocalForeignField {
+                local: FieldNameType::new(*local, ValueType::Int32),
+                foreign: (*foreign).into(),
+            })
+            .collect()
+    }
+
+    fn joins_sql(joins: Vec<Join>) -> Vec<String> {
+        joins.iter().map(|j| args_to_str(j).unwrap()).collect()
+    }
+
+    fn graph() -> RelationGraph {
+        RelationGraph::new()
+            .relation(Relation::new(
+                "FK_ITEM_ORDERS",
+                "ITEM",
+                "ORDERS",
+                fields(&[("ORDER_ID", "ID"), ("ORDER_YEAR", "YEAR")]),
+            ))
+            .relation(Relation::new(
+                "FK_ORDERS_CUSTOMER",
+                "ORDERS",
+                "CUSTOMER",
+                fields(&[("CUSTOMER_ID", "ID")]),
+            ))
+            .relation(
+                Relation::new(
+                    "FK_CUSTOMER_REGION",
+                    "CUSTOMER",
+                    "REGION",
+                    fields(&[("REGION_ID", "ID")]),
+                )
+                .nullable(),
+            )
+            .relation(Relation::new(
+                "FK_CUSTOMER_PARENT",
+                "CUSTOMER",
+                "CUSTOMER",
+                fields(&[("PARENT_ID", "ID")]),
+            ))
+    }
+
+    #[test]
+    fn test_join_path() {
+        let graph = graph();
+        assert_eq!(
+            joins_sql(graph.join_path(["ITEM", "CUSTOMER"]).unwrap()),
+            vec![
+                r#"INNER JOIN "ORDERS" ON "ORDERS"."ID" = "ITEM"."ORDER_ID" AND "ORDERS"."YEAR" = "ITEM"."ORDER_YEAR""#,
+                r#"INNER JOIN "CUSTOMER" ON "CUSTOMER"."ID" = "ORDERS"."CUSTOMER_ID""#,
+            ]
+        );
+        assert_eq!(
+            joins_sql(graph.join_path(["REGION", "CUSTOMER", "ORDERS"]).unwrap()),
+            vec![
+                r#"INNER JOIN "CUSTOMER" ON "CUSTOMER"."REGION_ID" = "REGION"."ID""#,
+                r#"INNER JOIN "ORDERS" ON "ORDERS"."CUSTOMER_ID" = "CUSTOMER"."ID""#,
+            ]
+        );
+        assert_eq!(
+            joins_sql(graph.join_path(["CUSTOMER", "REGION", "CUSTOMER"]).unwrap()),
+            vec![
+                r#"LEFT JOIN "REGION" ON "REGION"."ID" = "CUSTOMER"."REGION_ID""#,
+                r#"LEFT JOIN "CUSTOMER" "CUSTOMER_2" ON "CUSTOMER_2"."REGION_ID" = "REGION"."ID""#,
+            ]
+        );
+        assert_eq!(
+            joins_sql(graph.join_path(["ORDERS", "CUSTOMER", "CUSTOMER"]).unwrap()),
+            vec![
+                r#"INNER JOIN "CUSTOMER" ON "CUSTOMER"."ID" = "ORDERS"."CUSTOMER_ID""#,
+                r#"INNER JOIN "CUSTOMER" "CUSTOMER_2" ON "CUSTOMER_2"."ID" = "CUSTOMER"."PARENT_ID""#,
+            ]
+        );
+        assert!(graph.join_path(["ITEM"]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_join_path_errors() {
+        let graph = graph().relation(Relation::new(
+            "FK_ORDERS_REGION",
+            "ORDERS",
+            "REGION",
+            fields(&[("REGION_ID", "ID")]),
+        ));
+        assert_eq!(
+            graph.join_path(["ORDERS", "PRODUCT"]).unwrap_err(),
+            SchemaError::NoRelationPath("ORDERS".to_string(), "PRODUCT".to_string())
+        );
+        assert_eq!(
+            graph.join_path(["REGION", "REGION"]).unwrap_err(),
+            SchemaError::NoRelationPath("REGION".to_string(), "REGION".to_string())
+        );
+        // directly related, the longer path through CUSTOMER is ignored
+        assert_eq!(graph.join_path(["ORDERS", "REGION"]).unwrap().len(), 1);
+        let graph = graph.relation(Relation::new(
+            "FK_ITEM_REGION",
+            "ITEM",
+            "REGION",
+            fields(&[("REGION_ID", "ID")]),
+        ));
+        assert_eq!(
+            graph.join_path(["ITEM", "CUSTOMER"]).unwrap_err(),
+            SchemaError::AmbiguousRelationPath(
+                "ITEM".to_string(),
+                "CUSTOMER".to_string(),
+                "`FK_ITEM_ORDERS` > `FK_ORDERS_CUSTOMER` or `FK_ITEM_REGION` > `FK_CUSTOMER_REGION`"
+                    .to_string()
+            )
+        );
+        // an intermediate table chooses the path
+        assert_eq!(
+            graph
+                .join_path(["ITEM", "ORDERS", "CUSTOMER"])
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+}
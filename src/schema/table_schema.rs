@@ -0,0 +1,289 @@
+use super::{Column, ForeignKey, Index, UniqueConstraint};
+use crate::{
+    selections::{IntoTableName, TableName},
+    values::LocalForeignField,
+    FieldName, FieldNameType, IntoFieldName, IntoValueType, SchemaError,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Table of the catalog, with its columns and constraints.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TableSchema {
+    pub name: TableName,
+    pub columns: Vec<Column>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub uniques: Vec<UniqueConstraint>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub foreign_keys: Vec<ForeignKey>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub indexes: Vec<Index>,
+}
+
+impl TableSchema {
+    pub fn column(&self, name: &str) -> Option<&Column> {
+        self.columns.iter().find(|c| c.name == name)
+    }
+
+    /// Columns of the primary key, empty when the table has no primary key.
+    pub fn primary_key(&self) -> Vec<&Column> {
+        self.columns.iter().filter(|c| c.primary_key).collect()
+    }
+
+    /// Check that column names are unique and constraints reference existing columns.
+    pub fn validate(&self) -> Result<(), SchemaError> {
+        let table = self.name.name();
+        if self.columns.is_empty() {
+            return Err(SchemaError::InvalidTable(
+                table.to_string(),
+                "no column has been defined".to_string(),
+            ));
+        }
+        let mut names = HashSet::new();
+        if let Some(column) = self.columns.iter().find(|c| !names.insert(&c.name)) {
+            return Err(SchemaError::DuplicatedColumn(
+                table.to_string(),
+                column.name.to_string(),
+            ));
+        }
+        let constraints = self
+            .uniques
+            .iter()
+            .map(|u| (&u.name, &u.columns))
+            .chain(self.indexes.iter().map(|i| (&i.name, &i.columns)));
+        for (name, columns) in constraints {
+            if columns.is_empty() {
+                return Err(SchemaError::InvalidTable(
+                    table.to_string(),
+                    format!("constraint `{name}` has no column"),
+                ));
+            }
+            self.check_columns(columns.iter())?;
+        }
+        for foreign_key in self.foreign_keys.iter() {
+            if foreign_key.fields.is_empty() {
+                return Err(SchemaError::InvalidForeignKey(
+                    foreign_key.name.clone(),
+                    "no field has been defined".to_string(),
+                ));
+            }
+            self.check_columns(foreign_key.local_fields())?;
+            for field in foreign_key.fields.iter() {
+                let column = self.column(&field.local.name.0).unwrap();
+                if column.db_type.value_type() != field.local.v_type {
+                    return Err(SchemaError::InvalidForeignKey(
+                        foreign_key.name.clone(),
+                        format!(
+                            "field `{}` is `{}` but the column is `{}`",
+                            field.local.name,
+                            field.local.v_type,
+                            column.db_type.value_type()
+                        ),
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn check_columns<'a>(
+        &self,
+        mut columns: impl Iterator<Item = &'a FieldName>,
+    ) -> Result<(), SchemaError> {
+        match columns.find(|c| self.column(&c.0).is_none()) {
+            Some(column) => Err(SchemaError::ColumnNotFound(
+                self.name.name().to_string(),
+                column.to_string(),
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Foreign key waiting for `build` to read the types of its local columns.
+type PendingForeignKey = (String, TableName, Vec<(FieldName, FieldName)>);
+
+/// Build a `TableSchema` in code.
+/// # Example
+/// ```
+/// # use voxi_core::schema::{Column, TableSchemaBuilder};
+/// # use voxi_core::selections::DbValueType;
+/// let orders = TableSchemaBuilder::new("ORDERS")
+///     .column(Column::new("ID", DbValueType::Int64(0)).primary_key())
+///     .column(Column::new("CUSTOMER_ID", DbValueType::Int64(0)))
+///     .column(Column::new("NUMBER", DbValueType::String(20)))
+///     .unique("UQ_ORDERS_NUMBER", vec!["NUMBER"])
+///     .foreign_key("FK_ORDERS_CUSTOMER", "CUSTOMER", vec![("CUSTOMER_ID", "ID")])
+///     .index("IX_ORDERS_CUSTOMER", vec!["CUSTOMER_ID"])
+///     .build()
+///     .unwrap();
+/// assert_eq!(orders.primary_key().len(), 1);
+/// ```
+pub struct TableSchemaBuilder {
+    name: TableName,
+    columns: Vec<Column>,
+    uniques: Vec<UniqueConstraint>,
+    foreign_keys: Vec<PendingForeignKey>,
+    indexes: Vec<Index>,
+}
+
+impl TableSchemaBuilder {
+    pub fn new(name: impl IntoTableName) -> Self {
+        Self {
+            name: name.into_table_name(),
+            columns: Vec::new(),
+            uniques: Vec::new(),
+            foreign_keys: Vec::new(),
+            indexes: Vec::new(),
+        }
+    }
+
+    pub fn column(mut self, column: Column) -> Self {
+        self.columns.push(column);
+        self
+    }
+
+    pub fn unique<F: IntoFieldName>(mut self, name: impl Into<String>, columns: Vec<F>) -> Self {
+        self.uniques.push(UniqueConstraint {
+            name: name.into(),
+            columns: columns.into_iter().map(|c| c.into_field_name()).collect(),
+        });
+        self
+    }
+
+    /// Reference `foreign_table`, each pair maps a local column to a column of `foreign_table`.
+    pub fn foreign_key<L: IntoFieldName, F: IntoFieldName>(
+        mut self,
+        name: impl Into<String>,
+        foreign_table: impl IntoTableName,
+        fields: Vec<(L, F)>,
+    ) -> Self {
+        let fields = fields
+            .into_iter()
+            .map(|(l, f)| (l.into_field_name(), f.into_field_name()))
+            .collect();
+        self.foreign_keys
+            .push((name.into(), foreign_table.into_table_name(), fields));
+        self
+    }
+
+    pub fn index<F: IntoFieldName>(self, name: impl Into<String>, columns: Vec<F>) -> Self {
+        self.add_index(name.into(), columns, false)
+    }
+
+    pub fn unique_index<F: IntoFieldName>(self, name: impl Into<String>, columns: Vec<F>) -> Self {
+        self.add_index(name.into(), columns, true)
+    }
+
+    fn add_index<F: IntoFieldName>(mut self, name: String, columns: Vec<F>, unique: bool) -> Self {
+        self.indexes.push(Index {
+            name,
+            columns: columns.into_iter().map(|c| c.into_field_name()).collect(),
+            unique,
+        });
+        self
+    }
+
+    pub fn build(self) -> Result<TableSchema, SchemaError> {
+        let mut foreign_keys = Vec::new();
+        for (name, foreign_table, fields) in self.foreign_keys {
+            let fields = fields
+                .into_iter()
+                .map(|(local, foreign)| {
+                    let column =
+                        self.columns
+                            .iter()
+                            .find(|c| c.name == local)
+                            .ok_or_else(|| {
+                                SchemaError::ColumnNotFound(
+                                    self.name.name().to_string(),
+                                    local.to_string(),
+                                )
+                            })?;
+                    Ok(LocalForeignField {
+                        local: FieldNameType::new(local, column.db_type),
+                        foreign,
+                    })
+                })
+                .collect::<Result<Vec<_>, SchemaError>>()?;
+            foreign_keys.push(ForeignKey {
+                name,
+                foreign_table,
+                fields,
+            });
+        }
+        let table = TableSchema {
+            name: self.name,
+            columns: self.columns,
+            uniques: self.uniques,
+            foreign_keys,
+            indexes: self.indexes,
+        };
+        table.validate()?;
+        Ok(table)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::selections::DbValueType;
+    use pretty_assertions::assert_eq;
+
+    fn builder() -> TableSchemaBuilder {
+        TableSchemaBuilder::new("ORDERS")
+            .column(Column::new("ID", DbValueType::Int64(0)).primary_key())
+            .column(Column::new("CUSTOMER_ID", DbValueType::Int32(0)))
+    }
+
+    #[test]
+    fn test_build() {
+        let table = builder()
+            .foreign_key("FK_CUSTOMER", "CUSTOMER", vec![("CUSTOMER_ID", "ID")])
+            .build()
+            .unwrap();
+        assert_eq!(
+            table.foreign_keys[0].fields,
+            vec![LocalForeignField {
+                local: FieldNameType::new("CUSTOMER_ID", DbValueType::Int32(0)),
+                foreign: FieldName::from("ID"),
+            }]
+        );
+        assert_eq!(table.primary_key(), vec![&table.columns[0]]);
+    }
+
+    #[test]
+    fn test_build_invalid() {
+        assert_eq!(
+            builder()
+                .column(Column::new("ID", DbValueType::Uuid))
+                .build()
+                .unwrap_err(),
+            SchemaError::DuplicatedColumn("ORDERS".to_string(), "ID".to_string())
+        );
+        assert_eq!(
+            builder()
+                .foreign_key("FK_CUSTOMER", "CUSTOMER", vec![("CLIENT_ID", "ID")])
+                .build()
+                .unwrap_err(),
+            SchemaError::ColumnNotFound("ORDERS".to_string(), "CLIENT_ID".to_string())
+        );
+        assert_eq!(
+            builder()
+                .index("IX_DATE", Vec::<&str>::new())
+                .build()
+                .unwrap_err(),
+            SchemaError::InvalidTable(
+                "ORDERS".to_string(),
+                "constraint `IX_DATE` has no column".to_string()
+            )
+        );
+        assert_eq!(
+            TableSchemaBuilder::new("EMPTY").build().unwrap_err(),
+            SchemaError::InvalidTable(
+                "EMPTY".to_string(),
+                "no column has been defined".to_string()
+            )
+        );
+    }
+}
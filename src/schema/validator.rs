@@ -0,0 +1,737 @@
+//! Validation of queries against the tables of a `Catalog`.
+use super::Catalog;
+use crate::{
+    selections::{
+        visitor::{walk_agg_function, walk_condition, walk_string_function, Visitor},
+        AggFunction, AggFunctionType, ConditionWhere, FieldTypes, FromSelect, FromType, InferType,
+        InferredType, Select, StringFunction, TableField, TableName, TableTypes, ValueWhere,
+        ValuesListWhere, WindowFunction,
+    },
+    FieldName, ValueType,
+};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Clause of a `SELECT` holding the node of a diagnostic.
+#[derive(Display, Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum Clause {
+    #[display(fmt = "WITH")]
+    With,
+    #[display(fmt = "SELECT")]
+    Columns,
+    #[display(fmt = "FROM")]
+    From,
+    #[display(fmt = "JOIN")]
+    Join,
+    #[display(fmt = "WHERE")]
+    Where,
+    #[display(fmt = "GROUP BY")]
+    GroupBy,
+    #[display(fmt = "HAVING")]
+    Having,
+    #[display(fmt = "WINDOW")]
+    Window,
+    #[display(fmt = "ORDER BY")]
+    OrderBy,
+    /// Query combined by `UNION`, `INTERSECT` or `EXCEPT`.
+    #[display(fmt = "COMBINATION")]
+    Combination,
+}
+
+/// Problem found in the query.
+#[derive(Display, Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum DiagnosticKind {
+    #[display(fmt = "table not found: `{_0}`")]
+    TableNotFound(String),
+    #[display(fmt = "table or alias not found: `{_0}`")]
+    TableAliasNotFound(String),
+    #[display(fmt = "column not found: `{_0}`")]
+    ColumnNotFound(String),
+    #[display(fmt = "ambiguous column: `{_0}`")]
+    AmbiguousColumn(String),
+    #[display(fmt = "type mismatch: `{_0}` compared to `{_1}`")]
+    TypeMismatch(ValueType, ValueType),
+    #[display(fmt = "`{_0}` does not accept `{_1}`")]
+    InvalidArgument(String, ValueType),
+    #[display(fmt = "column `{_0}` must appear in GROUP BY or be used in an aggregate function")]
+    NotGrouped(String),
+}
+
+/// Node of the query reported by a `Diagnostic`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum DiagnosticNode {
+    Table(TableName),
+    TableField(TableField),
+    Condition(ConditionWhere),
+    StringFunction(StringFunction),
+    AggFunction(AggFunction),
+}
+
+/// Problem found validating a query, with the node causing it.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub kind: DiagnosticKind,
+    /// Clauses from the outer query down to the one holding the node.
+    pub path: Vec<Clause>,
+    pub node: DiagnosticNode,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let path = self
+            .path
+            .iter()
+            .map(|c| c.to_string())
+            .collect::<Vec<_>>()
+            .join(" > ");
+        write!(f, "{path}: {}", self.kind)
+    }
+}
+
+/// Columns of a table or sub-query, `None` when they can't be known.
+type Columns = Option<Vec<(FieldName, Option<InferredType>)>>;
+
+/// Table or sub-query available to the fields of a query.
+struct Source {
+    /// Alias, or table name when there is no alias.
+    name: String,
+    columns: Columns,
+}
+
+/// Names visible while validating a `SELECT`.
+struct Scope {
+    clause: Clause,
+    ctes: Vec<(String, Columns)>,
+    sources: Vec<Source>,
+    /// Aliases of the columns, allowed in `GROUP BY` and `ORDER BY`.
+    aliases: Vec<String>,
+    grouped: bool,
+    groups: Vec<(usize, FieldName)>,
+    aggregates: usize,
+}
+
+/// Field resolved to a column of a scope.
+struct Resolved {
+    depth: usize,
+    source: usize,
+    value_type: Option<InferredType>,
+}
+
+pub(crate) struct QueryValidator<'c> {
+    catalog: &'c Catalog,
+    scopes: Vec<Scope>,
+    /// Columns of the last validated `SELECT`, read by the query using it as source.
+    output: Columns,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl<'c> QueryValidator<'c> {
+    pub(crate) fn new(catalog: &'c Catalog) -> Self {
+        Self {
+            catalog,
+            scopes: Vec::new(),
+            output: None,
+            diagnostics: Vec::new(),
+        }
+    }
+
+    pub(crate) fn validate(mut self, select: &Select) -> Vec<Diagnostic> {
+        self.validate_select(select);
+        self.diagnostics
+    }
+
+    fn validate_select(&mut self, select: &Select) {
+        self.scopes.push(Scope {
+            clause: Clause::With,
+            ctes: Vec::new(),
+            sources: Vec::new(),
+            aliases: select
+                .columns
+                .values_select
+                .iter()
+                .filter_map(|c| c.alias.as_ref().map(|a| a.alias().to_string()))
+                .collect(),
+            grouped: false,
+            groups: Vec::new(),
+            aggregates: 0,
+        });
+        for cte in select.ctes.iter() {
+            self.validate_select(&cte.query);
+            let mut columns = self.output.take();
+            if let Some(columns) = columns.as_mut().filter(|_| !cte.columns.is_empty()) {
+                for (column, name) in columns.iter_mut().zip(cte.columns.iter()) {
+                    column.0 = name.clone();
+                }
+            }
+            self.scope()
+                .ctes
+                .push((cte.name.name().to_string(), columns));
+            if let Some(recursive) = &cte.recursive {
+                self.validate_select(recursive);
+            }
+        }
+
+        self.scope().clause = Clause::From;
+        for from in select.from.iter() {
+            let source = self.source(from);
+            self.scope().sources.push(source);
+        }
+        self.scope().clause = Clause::Join;
+        for join in select.joins.iter() {
+            let source = self.source(&join.from);
+            self.scope().sources.push(source);
+            self.visit_logical_expr(&join.condition);
+        }
+        self.scope().clause = Clause::Where;
+        if let Some(where_expr) = &select.where_expr {
+            self.visit_logical_expr(where_expr);
+        }
+
+        self.scope().clause = Clause::GroupBy;
+        for group in select.groups.iter() {
+            self.visit_table_field(&group.table_field);
+            if let Ok(Some(resolved)) = self.resolve(&group.table_field) {
+                if resolved.depth == 0 {
+                    let key = (resolved.source, group.table_field.field_name.clone());
+                    self.scope().groups.push(key);
+                }
+            }
+        }
+        let mut finder = AggregateFinder::default();
+        for column in select.columns.values_select.iter() {
+            finder.visit_value_select(column);
+        }
+        if let Some(having_expr) = &select.having_expr {
+            finder.visit_logical_expr(having_expr);
+        }
+        self.scope().grouped = !select.groups.is_empty() || finder.found;
+
+        self.scope().clause = Clause::Columns;
+        for column in select.columns.values_select.iter() {
+            let grouped_alias = column.alias.as_ref().is_some_and(|alias| {
+                select.groups.iter().any(|g| {
+                    g.table_field.table.is_none() && g.table_field.field_name == alias.alias()
+                })
+            });
+            if !grouped_alias {
+                self.visit_value_select(column);
+            }
+        }
+        self.scope().clause = Clause::Having;
+        if let Some(having_expr) = &select.having_expr {
+            self.visit_logical_expr(having_expr);
+        }
+        self.scope().clause = Clause::Window;
+        for window in select.windows.iter() {
+            self.visit_named_window(window);
+        }
+        self.scope().clause = Clause::OrderBy;
+        for order_by in select.orders_by.orders_by.iter() {
+            self.visit_order_by(order_by);
+        }
+
+        let output = self.output_columns(select);
+        self.scope().clause = Clause::Combination;
+        if let Some(combination) = &select.combination {
+            self.validate_select(&combination.query);
+        }
+        self.scopes.pop();
+        self.output = output;
+    }
+
+    fn scope(&mut self) -> &mut Scope {
+        self.scopes
+            .last_mut()
+            .expect("scope of the validated select")
+    }
+
+    fn report(&mut self, kind: DiagnosticKind, node: DiagnosticNode) {
+        let path = self.scopes.iter().map(|s| s.clause).collect();
+        self.diagnostics.push(Diagnostic { kind, path, node });
+    }
+
+    fn source(&mut self, from: &FromSelect) -> Source {
+        let (name, columns) = match from.from_type() {
+            FromType::Table(table_name) => (
+                table_name.name().to_string(),
+                self.table_columns(table_name),
+            ),
+            FromType::Query(query) => {
+                self.validate_select(query);
+                (String::new(), self.output.take())
+            }
+        };
+        let name = from.alias().map_or(name, |a| a.alias().to_string());
+        Source { name, columns }
+    }
+
+    /// Columns of a common table expression or of a table of the catalog.
+    fn table_columns(&mut self, table_name: &TableName) -> Columns {
+        let name = table_name.name();
+        let cte = self
+            .scopes
+            .iter()
+            .rev()
+            .flat_map(|s| s.ctes.iter())
+            .find(|(cte, _)| cte == name);
+        if let Some((_, columns)) = cte {
+            return columns.clone();
+        }
+        match self.catalog.table_columns(table_name) {
+            Some(columns) => Some(columns.into_iter().map(|(n, t)| (n, Some(t))).collect()),
+            None => {
+                self.report(
+                    DiagnosticKind::TableNotFound(name.to_string()),
+                    DiagnosticNode::Table(table_name.clone()),
+                );
+                None
+            }
+        }
+    }
+
+    /// Columns returned by the select, expanding `*` from its sources.
+    fn output_columns(&self, select: &Select) -> Columns {
+        let scope = self.scopes.last()?;
+        let mut columns = Vec::new();
+        for column in select.columns.values_select.iter() {
+            if let ValueWhere::TableField(table_field) = &column.value_where {
+                if table_field.field_name == "*" {
+                    let qualifier = table_field.qualifier();
+                    for source in scope.sources.iter() {
+                        if qualifier.is_none_or(|q| q == source.name) {
+                            columns.extend(source.columns.clone()?);
+                        }
+                    }
+                    continue;
+                }
+            }
+            let name = column.try_field_name()?;
+            columns.push((name, column.infer_type(self)));
+        }
+        Some(columns)
+    }
+
+    /// Find the column of the field, `Ok(None)` when it can't be known (unknown sources).
+    fn resolve(&self, table_field: &TableField) -> Result<Option<Resolved>, DiagnosticKind> {
+        let name = &table_field.field_name;
+        let scopes = self.scopes.iter().rev().enumerate();
+        if let Some(qualifier) = table_field.qualifier() {
+            for (depth, scope) in scopes {
+                let Some((index, source)) = scope
+                    .sources
+                    .iter()
+                    .enumerate()
+                    .find(|(_, s)| s.name == qualifier)
+                else {
+                    continue;
+                };
+                if name == "*" {
+                    return Ok(None);
+                }
+                let Some(columns) = &source.columns else {
+                    return Ok(None);
+                };
+                return match columns.iter().find(|(c, _)| c == name) {
+                    Some((_, value_type)) => Ok(Some(Resolved {
+                        depth,
+                        source: index,
+                        value_type: *value_type,
+                    })),
+                    None => Err(DiagnosticKind::ColumnNotFound(format!(
+                        "{qualifier}.{name}"
+                    ))),
+                };
+            }
+            return Err(DiagnosticKind::TableAliasNotFound(qualifier.to_string()));
+        }
+        if name == "*" {
+            return Ok(None);
+        }
+        for (depth, scope) in scopes {
+            if matches!(scope.clause, Clause::GroupBy | Clause::OrderBy)
+                && depth == 0
+                && scope.aliases.iter().any(|a| name == a.as_str())
+            {
+                return Ok(None);
+            }
+            let mut found = scope.sources.iter().enumerate().filter_map(|(index, s)| {
+                let columns = s.columns.as_ref()?;
+                let (_, value_type) = columns.iter().find(|(c, _)| c == name)?;
+                Some(Resolved {
+                    depth,
+                    source: index,
+                    value_type: *value_type,
+                })
+            });
+            match (found.next(), found.next()) {
+                (Some(resolved), None) => return Ok(Some(resolved)),
+                (Some(_), Some(_)) => {
+                    return Err(DiagnosticKind::AmbiguousColumn(name.to_string()))
+                }
+                (None, _) => {}
+            }
+            if scope.sources.iter().any(|s| s.columns.is_none()) {
+                return Ok(None);
+            }
+        }
+        Err(DiagnosticKind::ColumnNotFound(name.to_string()))
+    }
+
+    /// Type of the value when it can be known without evaluating it.
+    fn value_type(&self, value: &ValueWhere) -> Option<ValueType> {
+        value.infer_type(self).map(|t| t.value_type)
+    }
+
+    fn check_comparison(
+        &mut self,
+        condition: &ConditionWhere,
+        left: &ValueWhere,
+        right: &ValueWhere,
+    ) {
+        if let (Some(left), Some(right)) = (self.value_type(left), self.value_type(right)) {
+            if !comparable(left, right) {
+                self.report(
+                    DiagnosticKind::TypeMismatch(left, right),
+                    DiagnosticNode::Condition(condition.clone()),
+                );
+            }
+        }
+    }
+
+    fn check_argument(
+        &mut self,
+        function: &str,
+        value: &ValueWhere,
+        accepts: fn(ValueType) -> bool,
+        node: impl FnOnce() -> DiagnosticNode,
+    ) {
+        if let Some(value_type) = self.value_type(value).filter(|t| !accepts(*t)) {
+            self.report(
+                DiagnosticKind::InvalidArgument(function.to_string(), value_type),
+                node(),
+            );
+        }
+    }
+}
+
+impl<'ast> Visitor<'ast> for QueryValidator<'_> {
+    fn visit_select(&mut self, select: &'ast Select) {
+        self.validate_select(select);
+    }
+
+    fn visit_table_field(&mut self, table_field: &'ast TableField) {
+        let resolved = match self.resolve(table_field) {
+            Ok(resolved) => resolved,
+            Err(kind) => {
+                self.report(kind, DiagnosticNode::TableField(table_field.clone()));
+                return;
+            }
+        };
+        let Some(resolved) = resolved.filter(|r| r.depth == 0) else {
+            return;
+        };
+        let scope = self.scopes.last().expect("scope of the validated select");
+        let checked = matches!(
+            scope.clause,
+            Clause::Columns | Clause::Having | Clause::OrderBy
+        );
+        let grouped = scope
+            .groups
+            .iter()
+            .any(|(source, name)| *source == resolved.source && name == &table_field.field_name);
+        if checked && scope.grouped && scope.aggregates == 0 && !grouped {
+            self.report(
+                DiagnosticKind::NotGrouped(table_field.to_string()),
+                DiagnosticNode::TableField(table_field.clone()),
+            );
+        }
+    }
+
+    fn visit_condition(&mut self, condition: &'ast ConditionWhere) {
+        match condition {
+            ConditionWhere::ConditionEq(left, right)
+            | ConditionWhere::ConditionDf(left, right)
+            | ConditionWhere::ConditionGt(left, right)
+            | ConditionWhere::ConditionLs(left, right)
+            | ConditionWhere::ConditionGe(left, right)
+            | ConditionWhere::ConditionLe(left, right)
+            | ConditionWhere::ConditionDistinct(left, right)
+            | ConditionWhere::ConditionNotDistinct(left, right) => {
+                self.check_comparison(condition, left, right)
+            }
+            ConditionWhere::ConditionLk(left, right)
+            | ConditionWhere::ConditionNotLk(left, right)
+            | ConditionWhere::ConditionILk(left, right) => {
+                let operator = match condition {
+                    ConditionWhere::ConditionLk(..) => "LIKE",
+                    ConditionWhere::ConditionNotLk(..) => "NOT LIKE",
+                    _ => "ILIKE",
+                };
+                for value in [left, right] {
+                    self.check_argument(
+                        operator,
+                        value,
+                        |t| t.is_string(),
+                        || DiagnosticNode::Condition(condition.clone()),
+                    );
+                }
+            }
+            ConditionWhere::ConditionBetween(value, start, end)
+            | ConditionWhere::ConditionNotBetween(value, start, end) => {
+                self.check_comparison(condition, value, start);
+                self.check_comparison(condition, value, end);
+            }
+            ConditionWhere::ConditionIn(value, ValuesListWhere::List(values))
+            | ConditionWhere::ConditionNotIn(value, ValuesListWhere::List(values)) => {
+                for item in values.0.iter() {
+                    self.check_comparison(condition, value, item);
+                }
+            }
+            _ => {}
+        }
+        walk_condition(self, condition);
+    }
+
+    fn visit_string_function(&mut self, function: &'ast StringFunction) {
+        let (name, value) = match function {
+            StringFunction::Upper(value) => ("UPPER", value),
+            StringFunction::Lower(value) => ("LOWER", value),
+            StringFunction::Substring(value, _, _) => ("SUBSTRING", value),
+            StringFunction::Replace(value, _, _) => ("REPLACE", value),
+            StringFunction::Concat(_) => return walk_string_function(self, function),
+        };
+        self.check_argument(
+            name,
+            value,
+            |t| t.is_string(),
+            || DiagnosticNode::StringFunction(function.clone()),
+        );
+        walk_string_function(self, function);
+    }
+
+    fn visit_agg_function(&mut self, function: &'ast AggFunction) {
+        let accepts: Option<fn(ValueType) -> bool> = match function.agg_type() {
+            AggFunctionType::Sum
+            | AggFunctionType::Avg
+            | AggFunctionType::StdDev
+            | AggFunctionType::Variance => Some(|t| t.is_numeric()),
+            AggFunctionType::BoolAnd | AggFunctionType::BoolOr => Some(|t| t == ValueType::Boolean),
+            _ => None,
+        };
+        if let Some(accepts) = accepts {
+            self.check_argument(
+                function.agg_type().name(),
+                function.value(),
+                accepts,
+                || DiagnosticNode::AggFunction(function.clone()),
+            );
+        }
+        self.scope().aggregates += 1;
+        walk_agg_function(self, function);
+        self.scope().aggregates -= 1;
+    }
+}
+
+impl FieldTypes for QueryValidator<'_> {
+    fn field_type(&self, table_field: &TableField) -> Option<InferredType> {
+        self.resolve(table_field).ok()??.value_type
+    }
+}
+
+/// Look for aggregate functions making the query grouped, ignoring sub-queries and windows.
+#[derive(Default)]
+struct AggregateFinder {
+    found: bool,
+}
+
+impl<'ast> Visitor<'ast> for AggregateFinder {
+    fn visit_select(&mut self, _select: &'ast Select) {}
+
+    fn visit_window_function(&mut self, _function: &'ast WindowFunction) {}
+
+    fn visit_agg_function(&mut self, _function: &'ast AggFunction) {
+        self.found = true;
+    }
+}
+
+fn comparable(left: ValueType, right: ValueType) -> bool {
+    left == right
+        || (left.is_numeric() && right.is_numeric())
+        || (left.is_temporal() && right.is_temporal())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        dialects::PostgresDialect,
+        schema::{Column, TableSchemaBuilder},
+        selections::{DbValueType, IntoValueWhere},
+    };
+    use pretty_assertions::assert_eq;
+
+    fn catalog() -> Catalog {
+        let customer = TableSchemaBuilder::new("CUSTOMER")
+            .column(Column::new("ID", DbValueType::Int64(0)).primary_key())
+            .column(Column::new("NAME", DbValueType::String(100)))
+            .build()
+            .unwrap();
+        let orders = TableSchemaBuilder::new("ORDERS")
+            .column(Column::new("ID", DbValueType::Uuid).primary_key())
+            .column(Column::new("CUSTOMER_ID", DbValueType::Int64(0)))
+            .column(Column::new("TOTAL", DbValueType::Decimal(10, 2)))
+            .column(Column::new("CREATED", DbValueType::Date))
+            .build()
+            .unwrap();
+        Catalog::new().with_table(customer).with_table(orders)
+    }
+
+    fn validate(sql: &str) -> Vec<Diagnostic> {
+        let query = Select::parse(sql, &PostgresDialect).unwrap();
+        catalog().validate_select(&query)
+    }
+
+    fn field(table_field: &str) -> DiagnosticNode {
+        DiagnosticNode::TableField(TableField::new(table_field))
+    }
+
+    #[test]
+    fn test_valid() {
+        let sql = r#"WITH "TOTALS" AS (
+                SELECT "CUSTOMER_ID", SUM("TOTAL") AS "AMOUNT" FROM "ORDERS" GROUP BY "CUSTOMER_ID"
+            )
+            SELECT "c"."NAME", "t"."AMOUNT", COUNT(*) AS "ORDERS"
+            FROM "CUSTOMER" "c"
+            INNER JOIN "TOTALS" "t" ON "t"."CUSTOMER_ID" = "c"."ID"
+            WHERE "c"."NAME" LIKE 'A%' AND EXISTS (
+                SELECT 1 FROM "ORDERS" "o" WHERE "o"."CUSTOMER_ID" = "c"."ID" AND "o"."TOTAL" > 10
+            )
+            GROUP BY "c"."NAME", "t"."AMOUNT"
+            ORDER BY "ORDERS" DESC"#;
+        assert_eq!(validate(sql), vec![]);
+        let sql =
+            r#"SELECT "s"."TOTAL" FROM (SELECT * FROM "ORDERS") "s" WHERE "s"."TOTAL" > 10.5"#;
+        assert_eq!(validate(sql), vec![]);
+    }
+
+    #[test]
+    fn test_names() {
+        let diagnostics = validate(
+            r#"SELECT "c"."NAM", "x"."ID", "ID" FROM "CUSTOMER" "c", "ORDERS", "PRODUCT""#,
+        );
+        assert_eq!(
+            diagnostics,
+            vec![
+                Diagnostic {
+                    kind: DiagnosticKind::TableNotFound("PRODUCT".to_string()),
+                    path: vec![Clause::From],
+                    node: DiagnosticNode::Table(TableName::from("PRODUCT")),
+                },
+                Diagnostic {
+                    kind: DiagnosticKind::ColumnNotFound("c.NAM".to_string()),
+                    path: vec![Clause::Columns],
+                    node: field("c.NAM"),
+                },
+                Diagnostic {
+                    kind: DiagnosticKind::TableAliasNotFound("x".to_string()),
+                    path: vec![Clause::Columns],
+                    node: field("x.ID"),
+                },
+                Diagnostic {
+                    kind: DiagnosticKind::AmbiguousColumn("ID".to_string()),
+                    path: vec![Clause::Columns],
+                    node: field("ID"),
+                },
+            ]
+        );
+        assert_eq!(
+            diagnostics[1].to_string(),
+            "SELECT: column not found: `c.NAM`"
+        );
+    }
+
+    #[test]
+    fn test_types() {
+        let diagnostics = validate(
+            r#"SELECT UPPER("TOTAL") FROM "ORDERS"
+            WHERE "ID" = 1 AND "CUSTOMER_ID" IN (1, 2) AND "CREATED" LIKE '2022%'"#,
+        );
+        let kinds = diagnostics
+            .iter()
+            .map(|d| d.kind.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            vec![
+                DiagnosticKind::TypeMismatch(ValueType::Uuid, ValueType::Int32),
+                DiagnosticKind::InvalidArgument("LIKE".to_string(), ValueType::Date),
+                DiagnosticKind::InvalidArgument("UPPER".to_string(), ValueType::Decimal),
+            ]
+        );
+        assert_eq!(
+            diagnostics[2].node,
+            DiagnosticNode::StringFunction(StringFunction::upper(TableField::new("TOTAL")))
+        );
+        let diagnostics = validate(r#"SELECT SUM("NAME") FROM "CUSTOMER""#);
+        assert_eq!(
+            diagnostics[0].to_string(),
+            "SELECT: `SUM` does not accept `String`"
+        );
+    }
+
+    #[test]
+    fn test_not_grouped() {
+        let diagnostics = validate(
+            r#"SELECT "CUSTOMER_ID", "CREATED", MAX("TOTAL") FROM "ORDERS"
+            GROUP BY "CUSTOMER_ID" HAVING COUNT("ID") > 1 ORDER BY "TOTAL""#,
+        );
+        assert_eq!(
+            diagnostics,
+            vec![
+                Diagnostic {
+                    kind: DiagnosticKind::NotGrouped("CREATED".to_string()),
+                    path: vec![Clause::Columns],
+                    node: field("CREATED"),
+                },
+                Diagnostic {
+                    kind: DiagnosticKind::NotGrouped("TOTAL".to_string()),
+                    path: vec![Clause::OrderBy],
+                    node: field("TOTAL"),
+                },
+            ]
+        );
+        let diagnostics = validate(r#"SELECT "NAME", COUNT(*) FROM "CUSTOMER""#);
+        assert_eq!(
+            diagnostics[0].kind,
+            DiagnosticKind::NotGrouped("NAME".to_string())
+        );
+    }
+
+    #[test]
+    fn test_sub_queries() {
+        let diagnostics = validate(
+            r#"SELECT "NAME" FROM "CUSTOMER" "c"
+            WHERE "ID" IN (SELECT "CUSTOMER_ID" FROM "ORDERS" WHERE "TOTAL" > "c"."NAME")
+            UNION SELECT "NAME" FROM "CUSTOMERS""#,
+        );
+        assert_eq!(
+            diagnostics,
+            vec![
+                Diagnostic {
+                    kind: DiagnosticKind::TypeMismatch(ValueType::Decimal, ValueType::String),
+                    path: vec![Clause::Where, Clause::Where],
+                    node: DiagnosticNode::Condition(ConditionWhere::ConditionGt(
+                        TableField::new("TOTAL").into_value_where(),
+                        TableField::new("c.NAME").into_value_where(),
+                    )),
+                },
+                Diagnostic {
+                    kind: DiagnosticKind::TableNotFound("CUSTOMERS".to_string()),
+                    path: vec![Clause::Combination, Clause::From],
+                    node: DiagnosticNode::Table(TableName::from("CUSTOMERS")),
+                },
+            ]
+        );
+    }
+}
@@ -1,31 +1,58 @@
-use super::table_field::{IntoTableField, TableField};
+use super::{
+    arithmetic_expr::arithmetic_expr_where::ArithmeticExprWhere,
+    case::{case_condition::CaseCondition, case_value::CaseValue},
+    logical_expr_where::{IntoLogicalExprWhere, LogicalExprWhere},
+    string_functions::StringFunction,
+    table_field::{IntoTableField, TableField},
+    value_where::ValueWhere,
+};
 use crate::{resolvers::args_resolver::ArgsResolver, selections::to_sql::ToSQL, SQLError};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
-/// Definition for SQL functions MIN, MAX, AVG, SUM and COUNT.
+/// Definition for SQL aggregate functions, like MIN, MAX, AVG, SUM and COUNT.
+/// The argument can be a field or any expression.
 /// # Example
 /// ```
-/// # use voxi_core::selections::AggFunction;
+/// # use voxi_core::selections::{AggFunction, ArithmeticExprWhere, TableField};
+/// # use voxi_core::resolvers::args_resolver_string::args_to_str;
 /// let min = AggFunction::min("PRICE");
+/// let total = AggFunction::sum(ArithmeticExprWhere::multiply(
+///     TableField::new("PRICE"),
+///     TableField::new("QTY"),
+/// ));
+/// assert_eq!(args_to_str(&total).unwrap(), r#"SUM("PRICE" * "QTY")"#);
 /// ```
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct AggFunction {
-    table_field: TableField,
+    #[serde(alias = "table_field", deserialize_with = "deserialize_value")]
+    pub(crate) value: Box<ValueWhere>,
     agg_type: AggFunctionType,
+    #[serde(default)]
+    distinct: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub(crate) filter: Option<LogicalExprWhere>,
 }
 
 impl AggFunction {
+    /// Create the aggregate function of `agg_type` over `value`.
+    pub fn new(agg_type: AggFunctionType, value: impl IntoAggArgument) -> Self {
+        Self {
+            value: Box::new(value.into_agg_argument()),
+            agg_type,
+            distinct: false,
+            filter: None,
+        }
+    }
+
     /// Define SQL function for MIN(field).
     /// # Example
     /// ```
     /// # use voxi_core::selections::AggFunction;
     /// let min = AggFunction::min("PRICE");
     /// ```
-    pub fn min(table_field: impl IntoTableField) -> Self {
-        Self {
-            table_field: table_field.into_table_field(),
-            agg_type: AggFunctionType::Min,
-        }
+    pub fn min(value: impl IntoAggArgument) -> Self {
+        Self::new(AggFunctionType::Min, value)
     }
 
     /// Define SQL function for MAX(field).
@@ -34,11 +61,8 @@ impl AggFunction {
     /// # use voxi_core::selections::AggFunction;
     /// let max = AggFunction::max("PRICE");
     /// ```
-    pub fn max(table_field: impl IntoTableField) -> Self {
-        Self {
-            table_field: table_field.into_table_field(),
-            agg_type: AggFunctionType::Max,
-        }
+    pub fn max(value: impl IntoAggArgument) -> Self {
+        Self::new(AggFunctionType::Max, value)
     }
 
     /// Define SQL function for AVG(field).
@@ -47,11 +71,8 @@ impl AggFunction {
     /// # use voxi_core::selections::AggFunction;
     /// let avg = AggFunction::avg("PRICE");
     /// ```
-    pub fn avg(table_field: impl IntoTableField) -> Self {
-        Self {
-            table_field: table_field.into_table_field(),
-            agg_type: AggFunctionType::Avg,
-        }
+    pub fn avg(value: impl IntoAggArgument) -> Self {
+        Self::new(AggFunctionType::Avg, value)
     }
 
     /// Define SQL function for SUM(field).
@@ -60,11 +81,8 @@ impl AggFunction {
     /// # use voxi_core::selections::AggFunction;
     /// let sum = AggFunction::sum("PRICE");
     /// ```
-    pub fn sum(table_field: impl IntoTableField) -> Self {
-        Self {
-            table_field: table_field.into_table_field(),
-            agg_type: AggFunctionType::Sum,
-        }
+    pub fn sum(value: impl IntoAggArgument) -> Self {
+        Self::new(AggFunctionType::Sum, value)
     }
 
     /// Define SQL function for COUNT(field).
@@ -73,11 +91,75 @@ impl AggFunction {
     /// # use voxi_core::selections::AggFunction;
     /// let count = AggFunction::count("PRICE");
     /// ```
-    pub fn count(table_field: impl IntoTableField) -> Self {
-        Self {
-            table_field: table_field.into_table_field(),
-            agg_type: AggFunctionType::Count,
-        }
+    pub fn count(value: impl IntoAggArgument) -> Self {
+        Self::new(AggFunctionType::Count, value)
+    }
+
+    /// Define SQL function concatenating the values with `separator`
+    /// (`STRING_AGG`, or `GROUP_CONCAT` for MySQL and SQLite).
+    /// # Example
+    /// ```
+    /// # use voxi_core::selections::AggFunction;
+    /// # use voxi_core::resolvers::args_resolver_string::args_to_str;
+    /// let names = AggFunction::string_agg("NAME", ", ");
+    /// assert_eq!(args_to_str(&names).unwrap(), r#"STRING_AGG("NAME", ', ')"#);
+    /// ```
+    pub fn string_agg(value: impl IntoAggArgument, separator: impl Into<String>) -> Self {
+        Self::new(AggFunctionType::StringAgg(separator.into()), value)
+    }
+
+    /// Define SQL function for ARRAY_AGG(field).
+    pub fn array_agg(value: impl IntoAggArgument) -> Self {
+        Self::new(AggFunctionType::ArrayAgg, value)
+    }
+
+    /// Define SQL function for BOOL_AND(field), `true` when all values are `true`.
+    pub fn bool_and(value: impl IntoAggArgument) -> Self {
+        Self::new(AggFunctionType::BoolAnd, value)
+    }
+
+    /// Define SQL function for BOOL_OR(field), `true` when any value is `true`.
+    pub fn bool_or(value: impl IntoAggArgument) -> Self {
+        Self::new(AggFunctionType::BoolOr, value)
+    }
+
+    /// Define SQL function for STDDEV(field).
+    pub fn stddev(value: impl IntoAggArgument) -> Self {
+        Self::new(AggFunctionType::StdDev, value)
+    }
+
+    /// Define SQL function for VARIANCE(field).
+    pub fn variance(value: impl IntoAggArgument) -> Self {
+        Self::new(AggFunctionType::Variance, value)
+    }
+
+    /// Aggregate only distinct values, like `COUNT(DISTINCT field)`.
+    /// # Example
+    /// ```
+    /// # use voxi_core::selections::AggFunction;
+    /// # use voxi_core::resolvers::args_resolver_string::args_to_str;
+    /// let customers = AggFunction::count("CUSTOMER_ID").distinct();
+    /// assert_eq!(args_to_str(&customers).unwrap(), r#"COUNT(DISTINCT "CUSTOMER_ID")"#);
+    /// ```
+    #[must_use]
+    pub fn distinct(mut self) -> Self {
+        self.distinct = true;
+        self
+    }
+
+    /// Aggregate only the rows matching the condition (`FILTER (WHERE ..)`).
+    /// Dialects without `FILTER` support receive the condition as a `CASE` inside the function.
+    /// # Example
+    /// ```
+    /// # use voxi_core::selections::{AggFunction, ConditionWhereOperation, TableField};
+    /// # use voxi_core::resolvers::args_resolver_string::args_to_str;
+    /// let paid = AggFunction::count("*").filter(TableField::new("PAID").equal(true));
+    /// assert_eq!(args_to_str(&paid).unwrap(), r#"COUNT(*) FILTER (WHERE "PAID" = true)"#);
+    /// ```
+    #[must_use]
+    pub fn filter(mut self, expression: impl IntoLogicalExprWhere) -> Self {
+        self.filter = Some(expression.into_logical_expr_where());
+        self
     }
 
     /// Get a reference to the agg function's agg type.
@@ -91,15 +173,31 @@ impl AggFunction {
         &self.agg_type
     }
 
-    /// Get a reference to the agg function's table field.
+    /// Get a reference to the agg function's argument.
+    pub fn value(&self) -> &ValueWhere {
+        &self.value
+    }
+
+    /// Get a reference to the agg function's table field, when the argument is a field.
     /// # Example
     /// ```
     /// # use voxi_core::selections::AggFunction;
     /// let agg_fun = AggFunction::avg("*");
-    /// assert_eq!(agg_fun.table_field(), "*");
+    /// assert_eq!(agg_fun.table_field().unwrap(), "*");
     /// ```
-    pub fn table_field(&self) -> &TableField {
-        &self.table_field
+    pub fn table_field(&self) -> Option<&TableField> {
+        match self.value.as_ref() {
+            ValueWhere::TableField(table_field) => Some(table_field),
+            _ => None,
+        }
+    }
+
+    pub fn is_distinct(&self) -> bool {
+        self.distinct
+    }
+
+    pub fn filter_expr(&self) -> Option<&LogicalExprWhere> {
+        self.filter.as_ref()
     }
 }
 
@@ -110,38 +208,166 @@ pub enum AggFunctionType {
     Avg,
     Sum,
     Count,
+    /// Concatenation of the values, holding the separator.
+    StringAgg(String),
+    ArrayAgg,
+    BoolAnd,
+    BoolOr,
+    StdDev,
+    Variance,
 }
 
-impl ToSQL for AggFunction {
-    fn to_sql(
-        &self,
-        args_resolver: &mut dyn ArgsResolver,
-    ) -> error_stack::Result<String, SQLError> {
-        let op = match &self.agg_type {
+impl AggFunctionType {
+    /// Standard SQL name of the function.
+    pub fn name(&self) -> &'static str {
+        match self {
             AggFunctionType::Min => "MIN",
             AggFunctionType::Max => "MAX",
             AggFunctionType::Avg => "AVG",
             AggFunctionType::Sum => "SUM",
             AggFunctionType::Count => "COUNT",
+            AggFunctionType::StringAgg(_) => "STRING_AGG",
+            AggFunctionType::ArrayAgg => "ARRAY_AGG",
+            AggFunctionType::BoolAnd => "BOOL_AND",
+            AggFunctionType::BoolOr => "BOOL_OR",
+            AggFunctionType::StdDev => "STDDEV",
+            AggFunctionType::Variance => "VARIANCE",
+        }
+    }
+}
+
+impl ToSQL for AggFunction {
+    fn to_sql(
+        &self,
+        args_resolver: &mut dyn ArgsResolver,
+    ) -> error_stack::Result<String, SQLError> {
+        let inline_filter =
+            self.filter.is_some() && !args_resolver.dialect().supports_aggregate_filter();
+        // Arguments must be resolved in the same order they appear in the SQL.
+        let mut value = match (&self.filter, inline_filter) {
+            (Some(filter), true) => {
+                let filter = filter.to_sql(args_resolver)?;
+                let value = match self.value.to_sql(args_resolver)? {
+                    v if v == "*" => "1".to_string(),
+                    v => v,
+                };
+                format!("CASE WHEN {filter} THEN {value} END")
+            }
+            _ => self.value.to_sql(args_resolver)?,
         };
-        Ok(format!(
-            "{}({})",
-            op,
-            self.table_field.to_sql(args_resolver)?
-        ))
+        let dialect = args_resolver.dialect();
+        let mut sql = match &self.agg_type {
+            AggFunctionType::StringAgg(separator) => {
+                let separator = dialect.string_literal(separator);
+                dialect
+                    .string_agg(&value, &separator, self.distinct)
+                    .ok_or_else(|| {
+                        SQLError::SQLResolver(format!(
+                            "DISTINCT string aggregation with separator {separator} is not supported by {}",
+                            dialect.name()
+                        ))
+                    })?
+            }
+            agg_type => {
+                let name = dialect.aggregate_name(agg_type).ok_or_else(|| {
+                    SQLError::SQLResolver(format!(
+                        "{} is not supported by {}",
+                        agg_type.name(),
+                        dialect.name()
+                    ))
+                })?;
+                if self.distinct {
+                    value = format!("DISTINCT {value}");
+                }
+                format!("{name}({value})")
+            }
+        };
+        if let (Some(filter), false) = (&self.filter, inline_filter) {
+            sql.push_str(&format!(
+                " FILTER (WHERE {})",
+                filter.to_sql(args_resolver)?
+            ));
+        }
+        Ok(sql)
+    }
+}
+
+/// Argument of an aggregate function.
+/// Strings are considered field names, as in `AggFunction::sum("PRICE")`.
+pub trait IntoAggArgument {
+    fn into_agg_argument(self) -> ValueWhere;
+}
+
+impl<T: IntoTableField> IntoAggArgument for T {
+    fn into_agg_argument(self) -> ValueWhere {
+        ValueWhere::TableField(self.into_table_field())
+    }
+}
+
+impl IntoAggArgument for ValueWhere {
+    fn into_agg_argument(self) -> ValueWhere {
+        self
+    }
+}
+
+impl IntoAggArgument for ArithmeticExprWhere {
+    fn into_agg_argument(self) -> ValueWhere {
+        ValueWhere::Expression(self)
     }
 }
 
+impl IntoAggArgument for StringFunction {
+    fn into_agg_argument(self) -> ValueWhere {
+        ValueWhere::StringFunction(Box::new(self))
+    }
+}
+
+impl IntoAggArgument for CaseCondition {
+    fn into_agg_argument(self) -> ValueWhere {
+        ValueWhere::CaseCondition(Box::new(self))
+    }
+}
+
+impl IntoAggArgument for CaseValue {
+    fn into_agg_argument(self) -> ValueWhere {
+        ValueWhere::CaseValue(Box::new(self))
+    }
+}
+
+/// Read the argument, also accepting the `TableField` saved as `table_field` before aggregates
+/// accepted expressions.
+fn deserialize_value<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Box<ValueWhere>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum AggValue {
+        Value(Box<ValueWhere>),
+        TableField(TableField),
+    }
+    Ok(match AggValue::deserialize(deserializer)? {
+        AggValue::Value(value) => value,
+        AggValue::TableField(table_field) => Box::new(ValueWhere::TableField(table_field)),
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::resolvers::args_resolver_string::ArgsResolverString;
+    use crate::dialects::{MsSqlDialect, MySqlDialect, PostgresDialect, SqliteDialect};
+    use crate::resolvers::args_resolver_positional::args_to_positional_with_dialect;
+    use crate::resolvers::args_resolver_string::{
+        args_to_str, args_to_str_with_dialect, ArgsResolverString,
+    };
+    use crate::selections::ConditionWhereOperation;
+    use crate::IntoNullableValue;
+    use pretty_assertions::assert_eq;
 
     #[test]
     fn test_count_function() {
         let mut args_resolver_string = ArgsResolverString::new();
         let agg_fun = AggFunction::count("*");
-        assert_eq!(agg_fun.table_field(), "*");
+        assert_eq!(agg_fun.table_field().unwrap(), "*");
         assert_eq!(agg_fun.agg_type(), &AggFunctionType::Count);
         assert_eq!(
             agg_fun.to_sql(&mut args_resolver_string).unwrap(),
@@ -153,7 +379,7 @@ mod tests {
     fn test_min_function() {
         let mut args_resolver_string = ArgsResolverString::new();
         let agg_fun = AggFunction::min("*");
-        assert_eq!(agg_fun.table_field(), "*");
+        assert_eq!(agg_fun.table_field().unwrap(), "*");
         assert_eq!(agg_fun.agg_type(), &AggFunctionType::Min);
         assert_eq!(agg_fun.to_sql(&mut args_resolver_string).unwrap(), "MIN(*)");
     }
@@ -162,7 +388,7 @@ mod tests {
     fn test_max_function() {
         let mut args_resolver_string = ArgsResolverString::new();
         let agg_fun = AggFunction::max("*");
-        assert_eq!(agg_fun.table_field(), "*");
+        assert_eq!(agg_fun.table_field().unwrap(), "*");
         assert_eq!(agg_fun.agg_type(), &AggFunctionType::Max);
         assert_eq!(agg_fun.to_sql(&mut args_resolver_string).unwrap(), "MAX(*)");
     }
@@ -171,7 +397,7 @@ mod tests {
     fn test_avg_function() {
         let mut args_resolver_string = ArgsResolverString::new();
         let agg_fun = AggFunction::avg("*");
-        assert_eq!(agg_fun.table_field(), "*");
+        assert_eq!(agg_fun.table_field().unwrap(), "*");
         assert_eq!(agg_fun.agg_type(), &AggFunctionType::Avg);
         assert_eq!(agg_fun.to_sql(&mut args_resolver_string).unwrap(), "AVG(*)");
     }
@@ -180,8 +406,157 @@ mod tests {
     fn test_sum_function() {
         let mut args_resolver_string = ArgsResolverString::new();
         let agg_fun = AggFunction::sum("*");
-        assert_eq!(agg_fun.table_field(), "*");
+        assert_eq!(agg_fun.table_field().unwrap(), "*");
         assert_eq!(agg_fun.agg_type(), &AggFunctionType::Sum);
         assert_eq!(agg_fun.to_sql(&mut args_resolver_string).unwrap(), "SUM(*)");
     }
+
+    #[test]
+    fn test_expression_distinct_filter() {
+        let total = AggFunction::sum(ArithmeticExprWhere::multiply(
+            TableField::new("PRICE"),
+            TableField::new("QTY"),
+        ))
+        .filter(TableField::new("CANCELED").equal(false));
+        assert_eq!(
+            args_to_str(&total).unwrap(),
+            r#"SUM("PRICE" * "QTY") FILTER (WHERE "CANCELED" = false)"#
+        );
+        assert_eq!(
+            args_to_str_with_dialect(&total, MySqlDialect).unwrap(),
+            "SUM(CASE WHEN `CANCELED` = FALSE THEN `PRICE` * `QTY` END)"
+        );
+        let paid = AggFunction::count("*").filter(TableField::new("PAID").equal(true));
+        assert_eq!(
+            args_to_str_with_dialect(&paid, MsSqlDialect).unwrap(),
+            "COUNT(CASE WHEN [PAID] = 1 THEN 1 END)"
+        );
+        let customers = AggFunction::count(("O", "CUSTOMER_ID")).distinct();
+        assert_eq!(
+            args_to_str(&customers).unwrap(),
+            r#"COUNT(DISTINCT "O"."CUSTOMER_ID")"#
+        );
+    }
+
+    #[test]
+    fn test_filter_positional_order() {
+        let total = AggFunction::sum(ArithmeticExprWhere::multiply(TableField::new("PRICE"), 2))
+            .filter(TableField::new("STATUS").equal("A"));
+        let (_, args) = args_to_positional_with_dialect(&total, MySqlDialect).unwrap();
+        assert_eq!(
+            args,
+            vec!["A".into_nullable_value(), 2i64.into_nullable_value()]
+        );
+        let (_, args) = args_to_positional_with_dialect(&total, PostgresDialect).unwrap();
+        assert_eq!(
+            args,
+            vec![2i64.into_nullable_value(), "A".into_nullable_value()]
+        );
+    }
+
+    #[test]
+    fn test_string_agg_dialects() {
+        let names = AggFunction::string_agg("NAME", "; ").distinct();
+        assert_eq!(
+            args_to_str(&names).unwrap(),
+            r#"STRING_AGG(DISTINCT "NAME", '; ')"#
+        );
+        assert_eq!(
+            args_to_str_with_dialect(&names, MySqlDialect).unwrap(),
+            "GROUP_CONCAT(DISTINCT `NAME` SEPARATOR '; ')"
+        );
+        assert!(args_to_str_with_dialect(&names, SqliteDialect).is_err());
+        assert!(args_to_str_with_dialect(&names, MsSqlDialect).is_err());
+        let names = AggFunction::string_agg("NAME", ",").distinct();
+        assert_eq!(
+            args_to_str_with_dialect(&names, SqliteDialect).unwrap(),
+            r#"GROUP_CONCAT(DISTINCT "NAME")"#
+        );
+        let names = AggFunction::string_agg("NAME", "; ");
+        assert_eq!(
+            args_to_str_with_dialect(&names, SqliteDialect).unwrap(),
+            r#"GROUP_CONCAT("NAME", '; ')"#
+        );
+        assert_eq!(
+            args_to_str_with_dialect(&names, MsSqlDialect).unwrap(),
+            "STRING_AGG([NAME], '; ')"
+        );
+    }
+
+    #[test]
+    fn test_other_aggregates() {
+        assert_eq!(
+            args_to_str(&AggFunction::array_agg("ID")).unwrap(),
+            r#"ARRAY_AGG("ID")"#
+        );
+        assert_eq!(
+            args_to_str(&AggFunction::bool_and("ACTIVE")).unwrap(),
+            r#"BOOL_AND("ACTIVE")"#
+        );
+        assert_eq!(
+            args_to_str_with_dialect(&AggFunction::bool_or("ACTIVE"), SqliteDialect).unwrap(),
+            r#"MAX("ACTIVE")"#
+        );
+        assert_eq!(
+            args_to_str(&AggFunction::stddev("PRICE")).unwrap(),
+            r#"STDDEV("PRICE")"#
+        );
+        assert_eq!(
+            args_to_str_with_dialect(&AggFunction::variance("PRICE"), MsSqlDialect).unwrap(),
+            "VAR([PRICE])"
+        );
+    }
+
+    #[test]
+    fn test_unsupported_aggregates() {
+        let array = AggFunction::array_agg("ID");
+        let bool_and = AggFunction::bool_and("ACTIVE");
+        let stddev = AggFunction::stddev("PRICE");
+        assert!(args_to_str_with_dialect(&array, MySqlDialect).is_err());
+        assert!(args_to_str_with_dialect(&array, MsSqlDialect).is_err());
+        assert!(args_to_str_with_dialect(&array, SqliteDialect).is_err());
+        assert_eq!(
+            args_to_str_with_dialect(&bool_and, MySqlDialect).unwrap(),
+            "MIN(`ACTIVE`)"
+        );
+        assert_eq!(
+            args_to_str_with_dialect(&bool_and, MsSqlDialect).unwrap(),
+            "MIN([ACTIVE])"
+        );
+        assert_eq!(
+            args_to_str_with_dialect(&AggFunction::bool_or("ACTIVE"), MsSqlDialect).unwrap(),
+            "MAX([ACTIVE])"
+        );
+        assert_eq!(
+            args_to_str_with_dialect(&stddev, MsSqlDialect).unwrap(),
+            "STDEV([PRICE])"
+        );
+        assert_eq!(
+            args_to_str_with_dialect(&stddev, MySqlDialect).unwrap(),
+            "STDDEV(`PRICE`)"
+        );
+        assert!(args_to_str_with_dialect(&stddev, SqliteDialect).is_err());
+        assert!(args_to_str_with_dialect(&AggFunction::variance("PRICE"), SqliteDialect).is_err());
+    }
+
+    #[test]
+    fn test_agg_serialize() {
+        let agg = AggFunction::string_agg("NAME", ",")
+            .distinct()
+            .filter(TableField::new("ACTIVE").equal(true));
+        let json = serde_json::to_string(&agg).unwrap();
+        let agg_de: AggFunction = serde_json::from_str(&json).unwrap();
+        assert_eq!(agg, agg_de);
+    }
+
+    #[test]
+    fn test_agg_deserialize_table_field() {
+        // saved before aggregates accepted expressions
+        let json = r#"{"table_field":{"n":"ORDERS","fn":"PRICE"},"agg_type":"Max"}"#;
+        let agg: AggFunction = serde_json::from_str(json).unwrap();
+        assert_eq!(agg, AggFunction::max(TableField::new("ORDERS.PRICE")));
+        assert_eq!(args_to_str(&agg).unwrap(), r#"MAX("ORDERS"."PRICE")"#);
+        let json = serde_json::to_string(&agg).unwrap();
+        assert_eq!(serde_json::from_str::<AggFunction>(&json).unwrap(), agg);
+    }
 }
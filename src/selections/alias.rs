@@ -50,9 +50,9 @@ impl fmt::Display for Alias {
 impl ToSQL for Alias {
     fn to_sql(
         &self,
-        _args_resolver: &mut dyn ArgsResolver,
+        args_resolver: &mut dyn ArgsResolver,
     ) -> error_stack::Result<String, SQLError> {
-        Ok(format!(r#""{}""#, self.alias))
+        Ok(args_resolver.dialect().quote_identifier(&self.alias))
     }
 }
 
@@ -132,6 +132,42 @@ pub trait IntoArithmeticExprWhere {
     fn into_arithmetic_expr_where(self) -> ArithmeticExprWhere;
 }
 
+impl ArithmeticExprWhere {
+    /// Binding strength of the operation, operands of lower precedence are rendered into "( )".
+    fn precedence(&self) -> u8 {
+        match self {
+            ArithmeticExprWhere::ValueWhere(v) => match v.as_ref() {
+                ValueWhere::Expression(e) => e.precedence(),
+                _ => 3,
+            },
+            ArithmeticExprWhere::Expression(_) => 3,
+            ArithmeticExprWhere::Add(_, _) | ArithmeticExprWhere::Subtract(_, _) => 1,
+            ArithmeticExprWhere::Multiply(_, _) | ArithmeticExprWhere::Divide(_, _) => 2,
+        }
+    }
+
+    /// Render a binary operation, operators are left associative so a right operand with the
+    /// same precedence is also rendered into "( )" to keep the tree structure.
+    fn binary_to_sql(
+        &self,
+        operator: &str,
+        e1: &ArithmeticExprWhere,
+        e2: &ArithmeticExprWhere,
+        args_resolver: &mut dyn ArgsResolver,
+    ) -> error_stack::Result<String, SQLError> {
+        let precedence = self.precedence();
+        let mut left = e1.to_sql(args_resolver)?;
+        if e1.precedence() < precedence {
+            left = format!("({left})");
+        }
+        let mut right = e2.to_sql(args_resolver)?;
+        if e2.precedence() <= precedence {
+            right = format!("({right})");
+        }
+        Ok(format!("{left} {operator} {right}"))
+    }
+}
+
 impl ToSQL for ArithmeticExprWhere {
     fn to_sql(
         &self,
@@ -140,38 +176,21 @@ impl ToSQL for ArithmeticExprWhere {
         let sql = match self {
             ArithmeticExprWhere::ValueWhere(c) => c.to_sql(args_resolver)?,
             ArithmeticExprWhere::Expression(e) => format!("({})", e.to_sql(args_resolver)?),
-            ArithmeticExprWhere::Add(e1, e2) => {
-                format!(
-                    "{} + {}",
-                    e1.to_sql(args_resolver)?,
-                    e2.to_sql(args_resolver)?
-                )
-            }
+            ArithmeticExprWhere::Add(e1, e2) => self.binary_to_sql("+", e1, e2, args_resolver)?,
             ArithmeticExprWhere::Subtract(e1, e2) => {
-                format!(
-                    "{} - {}",
-                    e1.to_sql(args_resolver)?,
-                    e2.to_sql(args_resolver)?
-                )
+                self.binary_to_sql("-", e1, e2, args_resolver)?
             }
             ArithmeticExprWhere::Multiply(e1, e2) => {
-                format!(
-                    "{} * {}",
-                    e1.to_sql(args_resolver)?,
-                    e2.to_sql(args_resolver)?
-                )
+                self.binary_to_sql("*", e1, e2, args_resolver)?
             }
             ArithmeticExprWhere::Divide(e1, e2) => {
-                format!(
-                    "{} / {}",
-                    e1.to_sql(args_resolver)?,
-                    e2.to_sql(args_resolver)?
-                )
+                self.binary_to_sql("/", e1, e2, args_resolver)?
             }
         };
         Ok(sql)
     }
 }
+
 impl IntoArithmeticExprWhere for ArithmeticExprWhere {
     fn into_arithmetic_expr_where(self) -> ArithmeticExprWhere {
         self
@@ -346,4 +365,73 @@ mod test_sql {
             r#"("FIELD_A" + "FIELD_B") / "FIELD_C""#
         )
     }
+
+    #[test]
+    fn test_precedence_matrix() {
+        let a = || TableField::new("A");
+        let b = || TableField::new("B");
+        let c = || TableField::new("C");
+        let matrix = vec![
+            (
+                ArithmeticExprWhere::multiply(ArithmeticExprWhere::add(a(), b()), c()),
+                r#"("A" + "B") * "C""#,
+            ),
+            (
+                ArithmeticExprWhere::add(a(), ArithmeticExprWhere::multiply(b(), c())),
+                r#""A" + "B" * "C""#,
+            ),
+            (
+                ArithmeticExprWhere::add(ArithmeticExprWhere::multiply(a(), b()), c()),
+                r#""A" * "B" + "C""#,
+            ),
+            (
+                ArithmeticExprWhere::subtract(ArithmeticExprWhere::subtract(a(), b()), c()),
+                r#""A" - "B" - "C""#,
+            ),
+            (
+                ArithmeticExprWhere::subtract(a(), ArithmeticExprWhere::subtract(b(), c())),
+                r#""A" - ("B" - "C")"#,
+            ),
+            (
+                ArithmeticExprWhere::subtract(a(), ArithmeticExprWhere::add(b(), c())),
+                r#""A" - ("B" + "C")"#,
+            ),
+            (
+                ArithmeticExprWhere::divide(a(), ArithmeticExprWhere::multiply(b(), c())),
+                r#""A" / ("B" * "C")"#,
+            ),
+            (
+                ArithmeticExprWhere::divide(ArithmeticExprWhere::multiply(a(), b()), c()),
+                r#""A" * "B" / "C""#,
+            ),
+            (
+                ArithmeticExprWhere::divide(a(), ArithmeticExprWhere::subtract(b(), c())),
+                r#""A" / ("B" - "C")"#,
+            ),
+        ];
+        for (op, sql) in matrix {
+            assert_eq!(args_to_str(&op).unwrap(), sql);
+        }
+    }
+
+    #[test]
+    fn test_precedence_with_expression() {
+        let a = || TableField::new("A");
+        let b = || TableField::new("B");
+        let c = || TableField::new("C");
+        let implicit = ArithmeticExprWhere::multiply(ArithmeticExprWhere::add(a(), b()), c());
+        let explicit = ArithmeticExprWhere::multiply(
+            ArithmeticExprWhere::expression(ArithmeticExprWhere::add(a(), b())),
+            c(),
+        );
+        assert_eq!(
+            args_to_str(&implicit).unwrap(),
+            args_to_str(&explicit).unwrap()
+        );
+        let redundant = ArithmeticExprWhere::add(
+            ArithmeticExprWhere::expression(ArithmeticExprWhere::multiply(a(), b())),
+            c(),
+        );
+        assert_eq!(args_to_str(&redundant).unwrap(), r#"("A" * "B") + "C""#);
+    }
 }
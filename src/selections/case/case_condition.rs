@@ -1,6 +1,9 @@
 use super::when_condition::WhenCondition;
 use crate::{
-    resolvers::args_resolver::ArgsResolver,
+    resolvers::{
+        args_resolver::ArgsResolver,
+        sql_format::{indented_level, line_break},
+    },
     selections::{
         to_sql::ToSQL,
         value_where::{IntoValueWhere, ValueWhere},
@@ -68,8 +71,18 @@ impl Default for CaseConditionBuilder {
 ///
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CaseCondition {
-    whens: Vec<WhenCondition>,
-    else_case: Option<ValueWhere>,
+    pub(crate) whens: Vec<WhenCondition>,
+    pub(crate) else_case: Option<ValueWhere>,
+}
+
+impl CaseCondition {
+    pub fn whens(&self) -> &[WhenCondition] {
+        self.whens.as_ref()
+    }
+
+    pub fn else_case(&self) -> Option<&ValueWhere> {
+        self.else_case.as_ref()
+    }
 }
 
 impl ToSQL for CaseCondition {
@@ -77,19 +90,21 @@ impl ToSQL for CaseCondition {
         &self,
         args_resolver: &mut dyn ArgsResolver,
     ) -> error_stack::Result<String, SQLError> {
-        let mut sql = "CASE ".to_string();
-        let whens = self
-            .whens
-            .iter()
-            .map(|w| w.to_sql(args_resolver))
-            .collect::<Result<Vec<_>, _>>()?
-            .join(" ");
-        sql.push_str(&whens);
-        if let Some(else_case) = &self.else_case {
-            use std::fmt::Write;
-            write!(sql, " ELSE {}", else_case.to_sql(args_resolver)?).unwrap();
-        }
-        sql.push_str(" END");
+        let mut sql = "CASE".to_string();
+        // `WHEN` and `ELSE` are one level deeper than `CASE` and `END`.
+        indented_level(args_resolver, |args_resolver| {
+            for when in self.whens.iter() {
+                sql.push_str(&line_break(args_resolver, " "));
+                sql.push_str(&when.to_sql(args_resolver)?);
+            }
+            if let Some(else_case) = &self.else_case {
+                sql.push_str(&line_break(args_resolver, " "));
+                sql.push_str(&format!("ELSE {}", else_case.to_sql(args_resolver)?));
+            }
+            Ok(())
+        })?;
+        sql.push_str(&line_break(args_resolver, " "));
+        sql.push_str("END");
         Ok(sql)
     }
 }
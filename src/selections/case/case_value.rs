@@ -1,6 +1,9 @@
 use super::when_value::{IntoWhenValue, WhenValue};
 use crate::{
-    resolvers::args_resolver::ArgsResolver,
+    resolvers::{
+        args_resolver::ArgsResolver,
+        sql_format::{indented_level, line_break},
+    },
     selections::{
         to_sql::ToSQL,
         value_where::{IntoValueWhere, ValueWhere},
@@ -169,9 +172,9 @@ impl CaseValueBuilder {
 /// ```
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CaseValue {
-    input: ValueWhere,
-    whens: Vec<WhenValue>,
-    else_case: Option<ValueWhere>,
+    pub(crate) input: ValueWhere,
+    pub(crate) whens: Vec<WhenValue>,
+    pub(crate) else_case: Option<ValueWhere>,
 }
 
 impl CaseValue {
@@ -182,6 +185,18 @@ impl CaseValue {
             else_case,
         }
     }
+
+    pub fn input(&self) -> &ValueWhere {
+        &self.input
+    }
+
+    pub fn whens(&self) -> &[WhenValue] {
+        self.whens.as_ref()
+    }
+
+    pub fn else_case(&self) -> Option<&ValueWhere> {
+        self.else_case.as_ref()
+    }
 }
 
 impl ToSQL for CaseValue {
@@ -189,19 +204,21 @@ impl ToSQL for CaseValue {
         &self,
         args_resolver: &mut dyn ArgsResolver,
     ) -> error_stack::Result<String, SQLError> {
-        let mut sql = format!("CASE {} ", self.input.to_sql(args_resolver)?);
-        let whens = self
-            .whens
-            .iter()
-            .map(|w| w.to_sql(args_resolver))
-            .collect::<Result<Vec<_>, _>>()?
-            .join(" ");
-        sql.push_str(&whens);
-        if let Some(else_case) = &self.else_case {
-            use std::fmt::Write;
-            write!(sql, " ELSE {}", else_case.to_sql(args_resolver)?).unwrap();
-        }
-        sql.push_str(" END");
+        let mut sql = format!("CASE {}", self.input.to_sql(args_resolver)?);
+        // `WHEN` and `ELSE` are one level deeper than `CASE` and `END`.
+        indented_level(args_resolver, |args_resolver| {
+            for when in self.whens.iter() {
+                sql.push_str(&line_break(args_resolver, " "));
+                sql.push_str(&when.to_sql(args_resolver)?);
+            }
+            if let Some(else_case) = &self.else_case {
+                sql.push_str(&line_break(args_resolver, " "));
+                sql.push_str(&format!("ELSE {}", else_case.to_sql(args_resolver)?));
+            }
+            Ok(())
+        })?;
+        sql.push_str(&line_break(args_resolver, " "));
+        sql.push_str("END");
         Ok(sql)
     }
 }
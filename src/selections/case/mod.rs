@@ -1,5 +1,4 @@
-
-pub mod case_value;
 pub mod case_condition;
-pub mod when_value;
+pub mod case_value;
 pub mod when_condition;
+pub mod when_value;
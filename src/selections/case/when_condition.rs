@@ -11,8 +11,8 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct WhenCondition {
-    when_condition: LogicalExprWhere,
-    then_value_where: ValueWhere,
+    pub(crate) when_condition: LogicalExprWhere,
+    pub(crate) then_value_where: ValueWhere,
 }
 
 impl WhenCondition {
@@ -25,6 +25,14 @@ impl WhenCondition {
             then_value_where: then_value_where.into_value_where(),
         }
     }
+
+    pub fn when_condition(&self) -> &LogicalExprWhere {
+        &self.when_condition
+    }
+
+    pub fn then_value_where(&self) -> &ValueWhere {
+        &self.then_value_where
+    }
 }
 
 impl ToSQL for WhenCondition {
@@ -10,8 +10,8 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct WhenValue {
-    when_value_where: ValueWhere,
-    then_value_where: ValueWhere,
+    pub(crate) when_value_where: ValueWhere,
+    pub(crate) then_value_where: ValueWhere,
 }
 
 impl WhenValue {
@@ -24,6 +24,14 @@ impl WhenValue {
             then_value_where: then_value_where.into_value_where(),
         }
     }
+
+    pub fn when_value_where(&self) -> &ValueWhere {
+        &self.when_value_where
+    }
+
+    pub fn then_value_where(&self) -> &ValueWhere {
+        &self.then_value_where
+    }
 }
 
 impl ToSQL for WhenValue {
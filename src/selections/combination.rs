@@ -1,4 +1,7 @@
-use crate::{resolvers::args_resolver::ArgsResolver, SQLError};
+use crate::{
+    resolvers::{args_resolver::ArgsResolver, sql_format::line_break},
+    SQLError,
+};
 
 use super::{
     select::{IntoSelect, Select},
@@ -9,7 +12,7 @@ use serde::{Deserialize, Serialize};
 /// Define combination with other query, like `UNION`, `UNION ALL`, `INTERCEPT` and `EXCEPT`.
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Combination {
-    query: Select,
+    pub(crate) query: Select,
     combination_type: CombinationType,
 }
 
@@ -47,6 +50,11 @@ impl Combination {
     pub fn query(&self) -> &Select {
         &self.query
     }
+
+    /// Get owned combination's query.
+    pub fn into_query(self) -> Select {
+        self.query
+    }
 }
 
 impl ToSQL for Combination {
@@ -60,7 +68,13 @@ impl ToSQL for Combination {
             CombinationType::Intersect => "INTERSECT",
             CombinationType::Except => "EXCEPT",
         };
-        Ok(format!("{} {}", lit, self.query.to_sql(args_resolver)?))
+        let separator = line_break(args_resolver, " ");
+        Ok(format!(
+            "{}{}{}",
+            lit,
+            separator,
+            self.query.to_sql(args_resolver)?
+        ))
     }
 }
 
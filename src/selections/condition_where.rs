@@ -3,9 +3,12 @@ use super::{
     to_sql::ToSQL,
     value_where::{IntoValueWhere, ValueWhere},
     values_where::{IntoValuesListWhere, ValuesListWhere},
-    ArithmeticExprWhere,
+    ArithmeticExprWhere, IntoArithmeticExprWhere,
+};
+use crate::{
+    resolvers::{args_resolver::ArgsResolver, sql_format::nested},
+    SQLError,
 };
-use crate::{resolvers::args_resolver::ArgsResolver, SQLError};
 use serde::{Deserialize, Serialize};
 
 // TODO: add comments
@@ -19,6 +22,16 @@ pub trait ConditionWhereOperation {
     fn greater_or_equal(self, value: impl IntoValueWhere) -> ConditionWhere;
     fn less_or_equal(self, value: impl IntoValueWhere) -> ConditionWhere;
     fn include(self, values: impl IntoValuesListWhere) -> ConditionWhere;
+    fn not_null(self) -> ConditionWhere;
+    fn not_include(self, values: impl IntoValuesListWhere) -> ConditionWhere;
+    fn not_like(self, value: impl IntoValueWhere) -> ConditionWhere;
+    fn ilike(self, value: impl IntoValueWhere) -> ConditionWhere;
+    fn between(self, low: impl IntoValueWhere, high: impl IntoValueWhere) -> ConditionWhere;
+    fn not_between(self, low: impl IntoValueWhere, high: impl IntoValueWhere) -> ConditionWhere;
+    fn distinct_from(self, value: impl IntoValueWhere) -> ConditionWhere;
+    fn not_distinct_from(self, value: impl IntoValueWhere) -> ConditionWhere;
+    fn any(self, operator: CompareOperator, query: impl IntoSelect) -> ConditionWhere;
+    fn all(self, operator: CompareOperator, query: impl IntoSelect) -> ConditionWhere;
 }
 
 pub trait IntoConditionWhere {
@@ -71,6 +84,70 @@ where
     fn null(self) -> ConditionWhere {
         ConditionWhere::null(self)
     }
+
+    fn not_null(self) -> ConditionWhere {
+        ConditionWhere::not_null(self)
+    }
+
+    fn not_include(self, into_values_condition: impl IntoValuesListWhere) -> ConditionWhere {
+        ConditionWhere::not_inc(self, into_values_condition)
+    }
+
+    fn not_like(self, value: impl IntoValueWhere) -> ConditionWhere {
+        ConditionWhere::not_like(self, value)
+    }
+
+    fn ilike(self, value: impl IntoValueWhere) -> ConditionWhere {
+        ConditionWhere::ilike(self, value)
+    }
+
+    fn between(self, low: impl IntoValueWhere, high: impl IntoValueWhere) -> ConditionWhere {
+        ConditionWhere::between(self, low, high)
+    }
+
+    fn not_between(self, low: impl IntoValueWhere, high: impl IntoValueWhere) -> ConditionWhere {
+        ConditionWhere::not_between(self, low, high)
+    }
+
+    fn distinct_from(self, value: impl IntoValueWhere) -> ConditionWhere {
+        ConditionWhere::distinct_from(self, value)
+    }
+
+    fn not_distinct_from(self, value: impl IntoValueWhere) -> ConditionWhere {
+        ConditionWhere::not_distinct_from(self, value)
+    }
+
+    fn any(self, operator: CompareOperator, query: impl IntoSelect) -> ConditionWhere {
+        ConditionWhere::any(self, operator, query)
+    }
+
+    fn all(self, operator: CompareOperator, query: impl IntoSelect) -> ConditionWhere {
+        ConditionWhere::all(self, operator, query)
+    }
+}
+
+/// Comparison operator applied to the rows of a sub-query by `ANY`/`ALL`.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CompareOperator {
+    Eq,
+    Df,
+    Gt,
+    Ls,
+    Ge,
+    Le,
+}
+
+impl CompareOperator {
+    pub fn sql(&self) -> &'static str {
+        match self {
+            CompareOperator::Eq => "=",
+            CompareOperator::Df => "<>",
+            CompareOperator::Gt => ">",
+            CompareOperator::Ls => "<",
+            CompareOperator::Ge => ">=",
+            CompareOperator::Le => "<=",
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
@@ -87,6 +164,16 @@ pub enum ConditionWhere {
     ConditionIn(ValueWhere, ValuesListWhere),
     ConditionBetween(ValueWhere, ValueWhere, ValueWhere),
     Exists(Box<Select>),
+    NotExists(Box<Select>),
+    ConditionNotNull(ValueWhere),
+    ConditionNotIn(ValueWhere, ValuesListWhere),
+    ConditionNotLk(ValueWhere, ValueWhere),
+    ConditionILk(ValueWhere, ValueWhere),
+    ConditionNotBetween(ValueWhere, ValueWhere, ValueWhere),
+    ConditionDistinct(ValueWhere, ValueWhere),
+    ConditionNotDistinct(ValueWhere, ValueWhere),
+    ConditionAny(ValueWhere, CompareOperator, Box<Select>),
+    ConditionAll(ValueWhere, CompareOperator, Box<Select>),
 }
 
 impl ToSQL for ConditionWhere {
@@ -95,7 +182,7 @@ impl ToSQL for ConditionWhere {
         args_resolver: &mut dyn ArgsResolver,
     ) -> error_stack::Result<String, SQLError> {
         let sql = match self {
-            ConditionWhere::Expression(f) => format!("{} IS NULL", f.to_sql(args_resolver)?),
+            ConditionWhere::Expression(f) => f.to_sql(args_resolver)?,
             ConditionWhere::ConditionNull(f) => format!("{} IS NULL", f.to_sql(args_resolver)?),
             ConditionWhere::ConditionEq(f, v) => {
                 format!(
@@ -161,18 +248,100 @@ impl ToSQL for ConditionWhere {
                     v2.to_sql(args_resolver)?
                 )
             }
-            ConditionWhere::Exists(s) => format!("EXISTS ({})", s.to_sql(args_resolver)?),
+            ConditionWhere::Exists(s) => {
+                format!("EXISTS ({})", nested(args_resolver, |r| s.to_sql(r))?)
+            }
+            ConditionWhere::NotExists(s) => {
+                format!("NOT EXISTS ({})", nested(args_resolver, |r| s.to_sql(r))?)
+            }
+            ConditionWhere::ConditionNotNull(f) => {
+                format!("{} IS NOT NULL", f.to_sql(args_resolver)?)
+            }
+            ConditionWhere::ConditionNotIn(f, v) => {
+                format!(
+                    "{} NOT IN ({})",
+                    f.to_sql(args_resolver)?,
+                    v.to_sql(args_resolver)?
+                )
+            }
+            ConditionWhere::ConditionNotLk(f, v) => {
+                format!(
+                    "{} NOT LIKE {}",
+                    f.to_sql(args_resolver)?,
+                    v.to_sql(args_resolver)?
+                )
+            }
+            ConditionWhere::ConditionILk(f, v) => {
+                let f = f.to_sql(args_resolver)?;
+                let v = v.to_sql(args_resolver)?;
+                if args_resolver.dialect().supports_ilike() {
+                    format!("{f} ILIKE {v}")
+                } else {
+                    format!("LOWER({f}) LIKE LOWER({v})")
+                }
+            }
+            ConditionWhere::ConditionNotBetween(f, v1, v2) => {
+                format!(
+                    "{} NOT BETWEEN {} AND {}",
+                    f.to_sql(args_resolver)?,
+                    v1.to_sql(args_resolver)?,
+                    v2.to_sql(args_resolver)?
+                )
+            }
+            ConditionWhere::ConditionDistinct(f, v) => {
+                let f = f.to_sql(args_resolver)?;
+                let v = v.to_sql(args_resolver)?;
+                args_resolver.dialect().is_distinct_from(&f, &v, false)
+            }
+            ConditionWhere::ConditionNotDistinct(f, v) => {
+                let f = f.to_sql(args_resolver)?;
+                let v = v.to_sql(args_resolver)?;
+                args_resolver.dialect().is_distinct_from(&f, &v, true)
+            }
+            ConditionWhere::ConditionAny(f, op, s) => {
+                format!(
+                    "{} {} ANY ({})",
+                    f.to_sql(args_resolver)?,
+                    op.sql(),
+                    nested(args_resolver, |r| s.to_sql(r))?
+                )
+            }
+            ConditionWhere::ConditionAll(f, op, s) => {
+                format!(
+                    "{} {} ALL ({})",
+                    f.to_sql(args_resolver)?,
+                    op.sql(),
+                    nested(args_resolver, |r| s.to_sql(r))?
+                )
+            }
         };
         Ok(sql)
     }
 }
 
 impl ConditionWhere {
+    /// Boolean-valued expression used as condition, like a boolean field.
+    /// # Example
+    /// ```
+    /// # use voxi_core::selections::{ConditionWhere, TableField};
+    /// # use voxi_core::resolvers::args_resolver_string::args_to_str;
+    /// let c1 = ConditionWhere::expression(TableField::new("ACTIVE"));
+    /// assert_eq!(args_to_str(&c1).unwrap(), r#""ACTIVE""#);
+    /// ```
+    pub fn expression(expression: impl IntoArithmeticExprWhere) -> ConditionWhere {
+        ConditionWhere::Expression(expression.into_arithmetic_expr_where())
+    }
+
     /// Condition xxx IS NULL
     pub fn null(into_value_where: impl IntoValueWhere) -> ConditionWhere {
         ConditionWhere::ConditionNull(into_value_where.into_value_where())
     }
 
+    /// Condition xxx IS NOT NULL
+    pub fn not_null(into_value_where: impl IntoValueWhere) -> ConditionWhere {
+        ConditionWhere::ConditionNotNull(into_value_where.into_value_where())
+    }
+
     /// Define condition "equal to", like format `<ValueWhere> = <ValueWhere>`.
     /// # Example
     /// ```
@@ -328,6 +497,153 @@ impl ConditionWhere {
     pub fn exists(into_sub_query: impl IntoSelect) -> ConditionWhere {
         ConditionWhere::Exists(Box::new(into_sub_query.into_select()))
     }
+
+    /// Define "not exist in sub-query", like format `NOT EXISTS (<query>)`.
+    /// # Example
+    /// ```
+    /// # use voxi_core::selections::QueryBuilder;
+    /// # use voxi_core::selections::ConditionWhere;
+    /// let query = QueryBuilder::new().field("FIELD").from("TABLE").build().unwrap();
+    /// let c1 = ConditionWhere::not_exists(query);
+    /// ```
+    pub fn not_exists(into_sub_query: impl IntoSelect) -> ConditionWhere {
+        ConditionWhere::NotExists(Box::new(into_sub_query.into_select()))
+    }
+
+    /// Define "not include in", like format `<ValueWhere> NOT IN (<ValueWhere>,...)`.
+    /// # Example
+    /// ```
+    /// # use voxi_core::selections::ConditionWhere;
+    /// # use voxi_core::resolvers::args_resolver_string::args_to_str;
+    /// let c1 = ConditionWhere::not_inc("TEXT_1", vec!["TEXT_2", "TEXT_3"]);
+    /// assert_eq!(args_to_str(&c1).unwrap(), r#"'TEXT_1' NOT IN ('TEXT_2','TEXT_3')"#);
+    /// ```
+    pub fn not_inc(
+        value_where_a: impl IntoValueWhere,
+        value_where_b: impl IntoValuesListWhere,
+    ) -> ConditionWhere {
+        let values = value_where_b.into_values();
+        ConditionWhere::ConditionNotIn(value_where_a.into_value_where(), values)
+    }
+
+    /// Define text condition `NOT LIKE`.
+    pub fn not_like(
+        value_where_a: impl IntoValueWhere,
+        value_where_b: impl IntoValueWhere,
+    ) -> ConditionWhere {
+        ConditionWhere::ConditionNotLk(
+            value_where_a.into_value_where(),
+            value_where_b.into_value_where(),
+        )
+    }
+
+    /// Define case-insensitive text condition `ILIKE`.
+    /// Dialects without `ILIKE` compare both sides with `LOWER`.
+    /// # Example
+    /// ```
+    /// # use voxi_core::selections::ConditionWhere;
+    /// # use voxi_core::resolvers::args_resolver_string::args_to_str;
+    /// let c1 = ConditionWhere::ilike("TEXT_1", "text%");
+    /// assert_eq!(args_to_str(&c1).unwrap(), r#"'TEXT_1' ILIKE 'text%'"#);
+    /// ```
+    pub fn ilike(
+        value_where_a: impl IntoValueWhere,
+        value_where_b: impl IntoValueWhere,
+    ) -> ConditionWhere {
+        ConditionWhere::ConditionILk(
+            value_where_a.into_value_where(),
+            value_where_b.into_value_where(),
+        )
+    }
+
+    /// Define condition "between", like format `<ValueWhere> BETWEEN <ValueWhere> AND <ValueWhere>`.
+    /// # Example
+    /// ```
+    /// # use voxi_core::selections::ConditionWhere;
+    /// # use voxi_core::resolvers::args_resolver_string::args_to_str;
+    /// let c1 = ConditionWhere::between(5, 1, 10);
+    /// assert_eq!(args_to_str(&c1).unwrap(), "5 BETWEEN 1 AND 10");
+    /// ```
+    pub fn between(
+        value_where: impl IntoValueWhere,
+        low: impl IntoValueWhere,
+        high: impl IntoValueWhere,
+    ) -> ConditionWhere {
+        ConditionWhere::ConditionBetween(
+            value_where.into_value_where(),
+            low.into_value_where(),
+            high.into_value_where(),
+        )
+    }
+
+    /// Define condition "not between", like format `<ValueWhere> NOT BETWEEN <ValueWhere> AND <ValueWhere>`.
+    pub fn not_between(
+        value_where: impl IntoValueWhere,
+        low: impl IntoValueWhere,
+        high: impl IntoValueWhere,
+    ) -> ConditionWhere {
+        ConditionWhere::ConditionNotBetween(
+            value_where.into_value_where(),
+            low.into_value_where(),
+            high.into_value_where(),
+        )
+    }
+
+    /// Define null-safe condition "different from", like format `<ValueWhere> IS DISTINCT FROM <ValueWhere>`.
+    pub fn distinct_from(
+        value_where_a: impl IntoValueWhere,
+        value_where_b: impl IntoValueWhere,
+    ) -> ConditionWhere {
+        ConditionWhere::ConditionDistinct(
+            value_where_a.into_value_where(),
+            value_where_b.into_value_where(),
+        )
+    }
+
+    /// Define null-safe condition "equal to", like format `<ValueWhere> IS NOT DISTINCT FROM <ValueWhere>`.
+    pub fn not_distinct_from(
+        value_where_a: impl IntoValueWhere,
+        value_where_b: impl IntoValueWhere,
+    ) -> ConditionWhere {
+        ConditionWhere::ConditionNotDistinct(
+            value_where_a.into_value_where(),
+            value_where_b.into_value_where(),
+        )
+    }
+
+    /// Define condition comparing with any row of the sub-query, like format `<ValueWhere> = ANY (<query>)`.
+    /// # Example
+    /// ```
+    /// # use voxi_core::selections::{CompareOperator, ConditionWhere, QueryBuilder, TableField};
+    /// # use voxi_core::resolvers::args_resolver_string::args_to_str;
+    /// let query = QueryBuilder::new().field("ID").from("TABLE").build().unwrap();
+    /// let c1 = ConditionWhere::any(TableField::new("ID"), CompareOperator::Eq, query);
+    /// assert_eq!(args_to_str(&c1).unwrap(), r#""ID" = ANY (SELECT "ID" FROM "TABLE")"#);
+    /// ```
+    pub fn any(
+        value_where: impl IntoValueWhere,
+        operator: CompareOperator,
+        into_sub_query: impl IntoSelect,
+    ) -> ConditionWhere {
+        ConditionWhere::ConditionAny(
+            value_where.into_value_where(),
+            operator,
+            Box::new(into_sub_query.into_select()),
+        )
+    }
+
+    /// Define condition comparing with all rows of the sub-query, like format `<ValueWhere> > ALL (<query>)`.
+    pub fn all(
+        value_where: impl IntoValueWhere,
+        operator: CompareOperator,
+        into_sub_query: impl IntoSelect,
+    ) -> ConditionWhere {
+        ConditionWhere::ConditionAll(
+            value_where.into_value_where(),
+            operator,
+            Box::new(into_sub_query.into_select()),
+        )
+    }
 }
 
 #[cfg(test)]
@@ -336,7 +652,10 @@ mod tests {
 
     use super::*;
     use crate::{
-        resolvers::args_resolver_string::{args_to_str, ArgsResolverString},
+        dialects::{MySqlDialect, SqliteDialect},
+        resolvers::args_resolver_string::{
+            args_to_str, args_to_str_with_dialect, ArgsResolverString,
+        },
         selections::{
             logical_expr_where::LogicalExprWhere, select::QueryBuilder,
             single_select::SingleSelectBuilder, LogicalExprWhereOps, TableField,
@@ -509,8 +828,14 @@ mod tests {
             c1.to_sql(&mut args_resolver_string).unwrap(),
             r#"EXISTS (SELECT "FIELD" FROM "TABLE")"#
         );
-        let c2 = ConditionWhere::Exists(Box::new(query));
+        let c2 = ConditionWhere::Exists(Box::new(query.clone()));
         assert_eq!(c1, c2);
+        assert_eq!(
+            ConditionWhere::not_exists(query)
+                .to_sql(&mut args_resolver_string)
+                .unwrap(),
+            r#"NOT EXISTS (SELECT "FIELD" FROM "TABLE")"#
+        );
     }
 
     #[test]
@@ -597,4 +922,117 @@ mod tests {
         );
         assert_eq!(args_to_str(&cond).unwrap(), r#""SYMBOL"."id" IN (1,2,3)"#);
     }
+
+    #[test]
+    fn test_negated_op() {
+        let name = &TableField::new("NAME");
+        assert_eq!(
+            args_to_str(&name.not_null()).unwrap(),
+            r#""NAME" IS NOT NULL"#
+        );
+        assert_eq!(
+            args_to_str(&name.not_include(vec!["A", "B"])).unwrap(),
+            r#""NAME" NOT IN ('A','B')"#
+        );
+        assert_eq!(
+            args_to_str(&name.not_like("A%")).unwrap(),
+            r#""NAME" NOT LIKE 'A%'"#
+        );
+        assert_eq!(
+            args_to_str(&TableField::new("AGE").between(18, 65)).unwrap(),
+            r#""AGE" BETWEEN 18 AND 65"#
+        );
+        assert_eq!(
+            args_to_str(&TableField::new("AGE").not_between(18, 65)).unwrap(),
+            r#""AGE" NOT BETWEEN 18 AND 65"#
+        );
+    }
+
+    #[test]
+    fn test_ilike_dialects() {
+        let cond = TableField::new("NAME").ilike("jo%");
+        assert_eq!(args_to_str(&cond).unwrap(), r#""NAME" ILIKE 'jo%'"#);
+        assert_eq!(
+            args_to_str_with_dialect(&cond, MySqlDialect).unwrap(),
+            "LOWER(`NAME`) LIKE LOWER('jo%')"
+        );
+    }
+
+    #[test]
+    fn test_distinct_from_dialects() {
+        let cond = TableField::new("A").distinct_from(TableField::new("B"));
+        assert_eq!(args_to_str(&cond).unwrap(), r#""A" IS DISTINCT FROM "B""#);
+        assert_eq!(
+            args_to_str_with_dialect(&cond, MySqlDialect).unwrap(),
+            "NOT (`A` <=> `B`)"
+        );
+        assert_eq!(
+            args_to_str_with_dialect(&cond, SqliteDialect).unwrap(),
+            r#""A" IS NOT "B""#
+        );
+        let cond = TableField::new("A").not_distinct_from(TableField::new("B"));
+        assert_eq!(
+            args_to_str(&cond).unwrap(),
+            r#""A" IS NOT DISTINCT FROM "B""#
+        );
+        assert_eq!(
+            args_to_str_with_dialect(&cond, MySqlDialect).unwrap(),
+            "`A` <=> `B`"
+        );
+    }
+
+    #[test]
+    fn test_sub_query_op() {
+        let query = QueryBuilder::new()
+            .field("PRICE")
+            .from("ITEM")
+            .build()
+            .unwrap();
+        let price = TableField::new("PRICE");
+        assert_eq!(
+            args_to_str(&price.clone().include(query.clone())).unwrap(),
+            r#""PRICE" IN (SELECT "PRICE" FROM "ITEM")"#
+        );
+        assert_eq!(
+            args_to_str(&price.clone().not_include(query.clone())).unwrap(),
+            r#""PRICE" NOT IN (SELECT "PRICE" FROM "ITEM")"#
+        );
+        assert_eq!(
+            args_to_str(&price.clone().all(CompareOperator::Gt, query.clone())).unwrap(),
+            r#""PRICE" > ALL (SELECT "PRICE" FROM "ITEM")"#
+        );
+        assert_eq!(
+            args_to_str(&price.any(CompareOperator::Eq, query)).unwrap(),
+            r#""PRICE" = ANY (SELECT "PRICE" FROM "ITEM")"#
+        );
+    }
+
+    #[test]
+    fn test_expression() {
+        let cond = ConditionWhere::expression(TableField::new("ACTIVE"));
+        assert_eq!(args_to_str(&cond).unwrap(), r#""ACTIVE""#);
+        let cond = cond.not();
+        assert_eq!(args_to_str(&cond).unwrap(), r#"NOT "ACTIVE""#);
+    }
+
+    #[test]
+    fn test_new_conditions_serialize() {
+        let query = QueryBuilder::new()
+            .field("ID")
+            .from("TABLE")
+            .build()
+            .unwrap();
+        let conds = vec![
+            TableField::new("A").not_null(),
+            TableField::new("A").not_include(vec![1, 2]),
+            TableField::new("A").ilike("x%"),
+            TableField::new("A").not_between(1, 2),
+            TableField::new("A").not_distinct_from(1),
+            TableField::new("A").include(query.clone()),
+            TableField::new("A").all(CompareOperator::Ge, query),
+        ];
+        let json = serde_json::to_string(&conds).unwrap();
+        let conds_de: Vec<ConditionWhere> = serde_json::from_str(&json).unwrap();
+        assert_eq!(conds, conds_de);
+    }
 }
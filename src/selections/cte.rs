@@ -0,0 +1,128 @@
+use super::{
+    from::{FromSelect, FromType, IntoFrom},
+    select::Select,
+    table_name::{IntoTableName, TableName},
+    to_sql::ToSQL,
+};
+use crate::FieldName;
+use crate::{
+    resolvers::{
+        args_resolver::ArgsResolver,
+        sql_format::{line_break, nested},
+    },
+    SQLError,
+};
+use serde::{Deserialize, Serialize};
+
+/// Common table expression, a named query defined by `WITH` and referenced as a table.
+/// When `recursive` is informed the query is rendered as `<query> UNION ALL <recursive>`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Cte {
+    pub name: TableName,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub columns: Vec<FieldName>,
+    pub query: Box<Select>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub recursive: Option<Box<Select>>,
+}
+
+impl Cte {
+    pub fn new(name: impl IntoTableName, query: Select) -> Self {
+        Self {
+            name: name.into_table_name(),
+            columns: Vec::new(),
+            query: Box::new(query),
+            recursive: None,
+        }
+    }
+
+    pub fn new_recursive(
+        name: impl IntoTableName,
+        columns: Vec<FieldName>,
+        anchor: Select,
+        recursive: Select,
+    ) -> Self {
+        Self {
+            name: name.into_table_name(),
+            columns,
+            query: Box::new(anchor),
+            recursive: Some(Box::new(recursive)),
+        }
+    }
+
+    /// Name used to reference the expression as a table.
+    pub fn name(&self) -> &TableName {
+        &self.name
+    }
+
+    pub fn is_recursive(&self) -> bool {
+        self.recursive.is_some()
+    }
+}
+
+impl ToSQL for Cte {
+    fn to_sql(
+        &self,
+        args_resolver: &mut dyn ArgsResolver,
+    ) -> error_stack::Result<String, SQLError> {
+        let mut sql = self.name.to_sql(args_resolver)?;
+        if !self.columns.is_empty() {
+            let columns = self
+                .columns
+                .iter()
+                .map(|c| c.to_sql(args_resolver))
+                .collect::<Result<Vec<_>, _>>()?
+                .join(", ");
+            sql.push_str(&format!(" ({columns})"));
+        }
+        let query = nested(args_resolver, |args_resolver| {
+            let mut query = self.query.to_sql(args_resolver)?;
+            if let Some(recursive) = &self.recursive {
+                let separator = line_break(args_resolver, " ");
+                query.push_str(&format!(
+                    "{separator}UNION ALL{separator}{}",
+                    recursive.to_sql(args_resolver)?
+                ));
+            }
+            Ok(query)
+        })?;
+        Ok(format!("{sql} AS ({query})"))
+    }
+}
+
+/// Render the `WITH` clause, including the trailing space, or an empty string without expressions.
+pub(crate) fn ctes_to_sql(
+    ctes: &[Cte],
+    args_resolver: &mut dyn ArgsResolver,
+) -> error_stack::Result<String, SQLError> {
+    if ctes.is_empty() {
+        return Ok(String::new());
+    }
+    let recursive = ctes.iter().any(|c| c.is_recursive())
+        && args_resolver.dialect().requires_recursive_keyword();
+    let separator = line_break(args_resolver, " ");
+    let ctes = ctes
+        .iter()
+        .map(|c| c.to_sql(args_resolver))
+        .collect::<Result<Vec<_>, _>>()?
+        .join(&format!(",{separator}"));
+    if recursive {
+        Ok(format!("WITH RECURSIVE {ctes}{separator}"))
+    } else {
+        Ok(format!("WITH {ctes}{separator}"))
+    }
+}
+
+impl IntoTableName for &Cte {
+    fn into_table_name(self) -> TableName {
+        self.name.clone()
+    }
+}
+
+impl IntoFrom for &Cte {
+    fn into_from(self) -> FromSelect {
+        FromSelect::new(FromType::Table(self.name.clone()), None)
+    }
+}
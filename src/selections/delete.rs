@@ -0,0 +1,276 @@
+use super::{
+    bind_name::{BindName, IntoBindName},
+    from::{FromSelect, IntoFrom},
+    insert::returning_to_sql,
+    logical_expr_where::{IntoLogicalExprWhere, LogicalExprWhere},
+    table::{IntoTable, Table},
+    table_field::IntoTablesField,
+    to_sql::ToSQL,
+    values_select::ValuesSelect,
+    LogicalExprWhereOps,
+};
+use crate::dialects::DeleteUsingStyle;
+use crate::{
+    resolvers::{args_resolver::ArgsResolver, args_resolver_binds::ArgsResolverBindsDecorator},
+    SQLError,
+};
+use crate::{IntoNullableValue, NullableValue};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// DeleteBuilder allow customize creating of a SQL `DELETE`.
+/// A where condition is required, unless `delete_all()` is called.
+/// # Example
+/// ```
+/// # use voxi_core::selections::{ConditionWhereOperation, DeleteBuilder, TableField};
+/// # use voxi_core::resolvers::args_resolver_string::args_to_str;
+/// let delete = DeleteBuilder::new("TABLE")
+///     .where_c(TableField::new("ID").equal(1))
+///     .build()
+///     .unwrap();
+/// assert_eq!(args_to_str(&delete).unwrap(), r#"DELETE FROM "TABLE" WHERE "ID" = 1"#);
+/// assert!(DeleteBuilder::new("TABLE").build().is_err());
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct DeleteBuilder {
+    table: Table,
+    using: Vec<FromSelect>,
+    where_expr: Option<LogicalExprWhere>,
+    returning: ValuesSelect,
+    binds_values: Vec<(BindName, NullableValue)>,
+    all: bool,
+}
+
+impl DeleteBuilder {
+    /// Create the `DeleteBuilder` for the target table.
+    pub fn new(table: impl IntoTable) -> Self {
+        Self {
+            table: table.into_table(),
+            using: Vec::new(),
+            where_expr: None,
+            returning: ValuesSelect::empty(),
+            binds_values: Vec::new(),
+            all: false,
+        }
+    }
+
+    /// Add a table or sub-query to be read by the delete (`DELETE .. USING`).
+    #[must_use]
+    pub fn using(mut self, from: impl IntoFrom) -> Self {
+        self.using.push(from.into_from());
+        self
+    }
+
+    /// Define where condition, if already exists a condition it will be combined with `AND`.
+    #[must_use]
+    pub fn where_c(mut self, expression: impl IntoLogicalExprWhere) -> Self {
+        let new_exp = expression.into_logical_expr_where();
+        self.where_expr = Some(match self.where_expr {
+            Some(expr) => expr.and(new_exp),
+            None => new_exp,
+        });
+        self
+    }
+
+    /// Allow build a delete without where condition, removing all rows of the table.
+    #[must_use]
+    pub fn delete_all(mut self) -> Self {
+        self.all = true;
+        self
+    }
+
+    /// Add fields to be returned by the statement (`RETURNING`).
+    #[must_use]
+    pub fn returning(mut self, columns: impl IntoTablesField) -> Self {
+        for table_field in columns.into_tables_field() {
+            self.returning.push(table_field);
+        }
+        self
+    }
+
+    /// Add bind value.
+    #[must_use]
+    pub fn add_bind(mut self, bind_name: impl IntoBindName, value: impl IntoNullableValue) -> Self {
+        self.binds_values
+            .push((bind_name.into_bind_name(), value.into_nullable_value()));
+        self
+    }
+
+    pub fn build(self) -> Result<Delete, SQLError> {
+        if self.where_expr.is_none() && !self.all {
+            return Err(SQLError::InvalidQueryBuilderConfiguration(
+                "no where condition has been defined, call `delete_all()` to delete all rows"
+                    .to_string(),
+            ));
+        }
+        Ok(Delete {
+            table: self.table,
+            using: self.using,
+            where_expr: self.where_expr,
+            returning: self.returning,
+            binds_values: self.binds_values,
+            all: self.all,
+        })
+    }
+}
+
+/// SQL `DELETE` statement.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Delete {
+    pub table: Table,
+    pub using: Vec<FromSelect>,
+    #[serde(rename = "where")]
+    pub where_expr: Option<LogicalExprWhere>,
+    pub returning: ValuesSelect,
+    pub binds_values: Vec<(BindName, NullableValue)>,
+    /// Allow the statement without where condition, removing all rows of the table.
+    #[serde(default)]
+    pub all: bool,
+}
+
+impl Delete {
+    /// Target table.
+    pub fn table(&self) -> &Table {
+        &self.table
+    }
+
+    /// Where expression.
+    pub fn where_expr(&self) -> Option<&LogicalExprWhere> {
+        self.where_expr.as_ref()
+    }
+}
+
+impl ToSQL for Delete {
+    fn to_sql(
+        &self,
+        args_resolver: &mut dyn ArgsResolver,
+    ) -> error_stack::Result<String, SQLError> {
+        if self.where_expr.is_none() && !self.all {
+            return Err(SQLError::InvalidQueryBuilderConfiguration(
+                "DELETE without where condition requires `all` to delete all rows".to_string(),
+            )
+            .into());
+        }
+        let hash = self.binds_values.iter().cloned().collect::<HashMap<_, _>>();
+        let args_resolver = &mut ArgsResolverBindsDecorator::new(args_resolver, &hash);
+
+        let using_style = args_resolver.dialect().delete_using_style();
+        let mut sql = match (using_style, self.using.is_empty()) {
+            (_, true) | (DeleteUsingStyle::Using, _) => {
+                format!("DELETE FROM {}", self.table.to_sql(args_resolver)?)
+            }
+            (DeleteUsingStyle::TargetFrom, _) => {
+                let target = match self.table.alias() {
+                    Some(alias) => alias.to_sql(args_resolver)?,
+                    None => self.table.name().to_sql(args_resolver)?,
+                };
+                format!("DELETE {target} FROM {}", self.table.to_sql(args_resolver)?)
+            }
+            (DeleteUsingStyle::Unsupported, _) => {
+                return Err(SQLError::SQLResolver(format!(
+                    "DELETE with additional tables is not supported by {}",
+                    args_resolver.dialect().name()
+                ))
+                .into());
+            }
+        };
+
+        if !self.using.is_empty() {
+            let using = self
+                .using
+                .iter()
+                .map(|f| f.to_sql(args_resolver))
+                .collect::<Result<Vec<_>, _>>()?
+                .join(", ");
+            match using_style {
+                DeleteUsingStyle::Using => sql.push_str(&format!(" USING {using}")),
+                _ => sql.push_str(&format!(", {using}")),
+            }
+        }
+
+        if let Some(w) = self.where_expr.as_ref() {
+            sql.push_str(&format!(" WHERE {}", w.to_sql(args_resolver)?));
+        }
+
+        if !self.returning.is_empty() {
+            sql.push_str(&returning_to_sql(&self.returning, args_resolver)?);
+        }
+        Ok(sql)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dialects::{MySqlDialect, SqliteDialect};
+    use crate::resolvers::args_resolver_positional::{args_to_positional, PlaceholderStyle};
+    use crate::resolvers::args_resolver_string::{args_to_str, args_to_str_with_dialect};
+    use crate::selections::{ConditionWhereOperation, TableField, ValueWhere};
+    use pretty_assertions::assert_eq;
+
+    fn delete_using() -> Delete {
+        DeleteBuilder::new(Table::from("ORDER_ITEM", "I"))
+            .using(Table::from("ORDERS", "O"))
+            .where_c(TableField::new(("I", "ORDER_ID")).equal(TableField::new(("O", "ID"))))
+            .where_c(TableField::new(("O", "STATUS")).equal(ValueWhere::bind("status")))
+            .add_bind("status", "CANCELED")
+            .returning(("I", "ID"))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_delete_using_returning() {
+        assert_eq!(
+            args_to_str(&delete_using()).unwrap(),
+            r#"DELETE FROM "ORDER_ITEM" AS "I" USING "ORDERS" "O" WHERE "I"."ORDER_ID" = "O"."ID" AND "O"."STATUS" = 'CANCELED' RETURNING "I"."ID""#
+        );
+    }
+
+    #[test]
+    fn test_delete_binds_positional() {
+        let (sql, args) = args_to_positional(&delete_using(), PlaceholderStyle::Dollar).unwrap();
+        assert!(sql.contains(r#""O"."STATUS" = $1"#));
+        assert_eq!(args, vec![NullableValue::String(Some("CANCELED".into()))]);
+    }
+
+    #[test]
+    fn test_delete_dialects() {
+        let delete = DeleteBuilder::new(Table::from("ORDER_ITEM", "I"))
+            .using(Table::from("ORDERS", "O"))
+            .where_c(TableField::new(("I", "ORDER_ID")).equal(TableField::new(("O", "ID"))))
+            .build()
+            .unwrap();
+        assert_eq!(
+            args_to_str_with_dialect(&delete, MySqlDialect).unwrap(),
+            "DELETE `I` FROM `ORDER_ITEM` AS `I`, `ORDERS` `O` WHERE `I`.`ORDER_ID` = `O`.`ID`"
+        );
+        assert!(args_to_str_with_dialect(&delete, SqliteDialect).is_err());
+    }
+
+    #[test]
+    fn test_delete_all() {
+        assert!(DeleteBuilder::new("TABLE").build().is_err());
+        let delete = DeleteBuilder::new("TABLE").delete_all().build().unwrap();
+        assert_eq!(args_to_str(&delete).unwrap(), r#"DELETE FROM "TABLE""#);
+    }
+
+    #[test]
+    fn test_delete_without_where() {
+        let delete = DeleteBuilder::new("TABLE").delete_all().build().unwrap();
+        let mut json = serde_json::to_value(&delete).unwrap();
+        json.as_object_mut().unwrap().remove("all");
+        assert_eq!(json["where"], serde_json::Value::Null);
+        let delete: Delete = serde_json::from_value(json).unwrap();
+        assert!(!delete.all);
+        assert!(args_to_str(&delete).is_err());
+    }
+
+    #[test]
+    fn test_delete_serialize() {
+        let delete = delete_using();
+        let json = serde_json::to_string(&delete).unwrap();
+        let delete_de: Delete = serde_json::from_str(&json).unwrap();
+        assert_eq!(delete, delete_de);
+    }
+}
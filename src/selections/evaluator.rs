@@ -0,0 +1,697 @@
+use super::{
+    arithmetic_expr::arithmetic_expr_where::ArithmeticExprWhere,
+    case::{case_condition::CaseCondition, case_value::CaseValue},
+    condition_where::ConditionWhere,
+    logical_expr_where::LogicalExprWhere,
+    string_functions::StringFunction,
+    table_field::TableField,
+    value_where::ValueWhere,
+    values_where::ValuesListWhere,
+};
+#[cfg(feature = "objects")]
+use crate::objects::sub_set_values::SubsetValues;
+use crate::{EvalError, FieldName, IntoValueType, NullableValue, Value, ValueType};
+use error_stack::{Report, ResultExt};
+use rust_decimal::Decimal;
+use std::{cmp::Ordering, collections::HashMap};
+
+/// Source of the field values used to evaluate expressions in memory, like a loaded row.
+pub trait FieldValues {
+    fn field_value(&self, table_field: &TableField) -> Option<NullableValue>;
+}
+
+impl<F> FieldValues for F
+where
+    F: Fn(&TableField) -> Option<NullableValue>,
+{
+    fn field_value(&self, table_field: &TableField) -> Option<NullableValue> {
+        self(table_field)
+    }
+}
+
+impl FieldValues for HashMap<FieldName, NullableValue> {
+    fn field_value(&self, table_field: &TableField) -> Option<NullableValue> {
+        self.get(&table_field.field_name).cloned()
+    }
+}
+
+#[cfg(feature = "objects")]
+impl FieldValues for SubsetValues {
+    fn field_value(&self, table_field: &TableField) -> Option<NullableValue> {
+        self.by_name(table_field.field_name.clone())
+            .map(|v| v.opt_value.clone())
+    }
+}
+
+/// In-memory evaluation of an expression with SQL semantics, `NULL` propagates and
+/// conditions follow three-valued logic.
+/// # Example
+/// ```
+/// # use voxi_core::selections::{ConditionWhereOperation, Evaluate, TableField};
+/// # use voxi_core::{FieldName, IntoNullableValue, NullableValue};
+/// # use std::collections::HashMap;
+/// let mut row = HashMap::new();
+/// row.insert(FieldName::from("PRICE"), 10.into_nullable_value());
+/// row.insert(FieldName::from("NAME"), NullableValue::String(None));
+/// let cond = TableField::new("PRICE").greater(5);
+/// assert_eq!(cond.evaluate_bool(&row).unwrap(), Some(true));
+/// let cond = TableField::new("NAME").equal("A");
+/// assert_eq!(cond.evaluate_bool(&row).unwrap(), None);
+/// ```
+pub trait Evaluate {
+    fn evaluate(&self, values: &dyn FieldValues) -> error_stack::Result<NullableValue, EvalError>;
+
+    /// Evaluate a condition, `None` means SQL `UNKNOWN`.
+    fn evaluate_bool(
+        &self,
+        values: &dyn FieldValues,
+    ) -> error_stack::Result<Option<bool>, EvalError> {
+        to_bool(self.evaluate(values)?)
+    }
+}
+
+fn to_bool(value: NullableValue) -> error_stack::Result<Option<bool>, EvalError> {
+    match value {
+        NullableValue::Boolean(value) => Ok(value),
+        other => Err(Report::new(EvalError::TypeMismatch(format!(
+            "expected boolean, found {}",
+            other.value_type()
+        )))),
+    }
+}
+
+fn unsupported<T>(node: &str) -> error_stack::Result<T, EvalError> {
+    Err(Report::new(EvalError::Unsupported(node.to_string())))
+}
+
+impl Evaluate for ValueWhere {
+    fn evaluate(&self, values: &dyn FieldValues) -> error_stack::Result<NullableValue, EvalError> {
+        match self {
+            ValueWhere::TableField(table_field) => values
+                .field_value(table_field)
+                .ok_or_else(|| Report::new(EvalError::FieldNotFound(table_field.to_string()))),
+            ValueWhere::LiteralValue(value) => Ok(value.clone()),
+            ValueWhere::Expression(expression) => expression.evaluate(values),
+            ValueWhere::CaseCondition(case) => case.evaluate(values),
+            ValueWhere::CaseValue(case) => case.evaluate(values),
+            ValueWhere::StringFunction(function) => function.evaluate(values),
+            ValueWhere::BindParameter(bind) => {
+                unsupported(&format!("bind parameter {}", bind.name()))
+            }
+            ValueWhere::SingleQuery(_) => unsupported("sub-query"),
+            ValueWhere::AggFunction(_) => unsupported("aggregate function"),
+            ValueWhere::WindowFunction(_) => unsupported("window function"),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum ArithmeticOp {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+/// Numeric value, integers are promoted to `Decimal` when combined with one.
+enum Number {
+    Int(i64),
+    Decimal(Decimal),
+}
+
+fn to_number(value: &NullableValue) -> error_stack::Result<Option<Number>, EvalError> {
+    match value {
+        NullableValue::Int32(v) => Ok(v.map(|v| Number::Int(v as i64))),
+        NullableValue::Int64(v) => Ok(v.map(Number::Int)),
+        NullableValue::Decimal(v) => Ok(v.map(Number::Decimal)),
+        other => Err(Report::new(EvalError::TypeMismatch(format!(
+            "expected number, found {}",
+            other.value_type()
+        )))),
+    }
+}
+
+fn arithmetic(
+    op: ArithmeticOp,
+    a: NullableValue,
+    b: NullableValue,
+) -> error_stack::Result<NullableValue, EvalError> {
+    let decimal = matches!(a, NullableValue::Decimal(_)) || matches!(b, NullableValue::Decimal(_));
+    let (a, b) = match (to_number(&a)?, to_number(&b)?) {
+        (Some(a), Some(b)) => (a, b),
+        _ if decimal => return Ok(NullableValue::Decimal(None)),
+        _ => return Ok(NullableValue::Int64(None)),
+    };
+    match (a, b) {
+        (Number::Int(a), Number::Int(b)) => {
+            if matches!(op, ArithmeticOp::Divide) && b == 0 {
+                return Err(Report::new(EvalError::DivisionByZero));
+            }
+            // Integer division truncates, as in SQL.
+            let result = match op {
+                ArithmeticOp::Add => a.checked_add(b),
+                ArithmeticOp::Subtract => a.checked_sub(b),
+                ArithmeticOp::Multiply => a.checked_mul(b),
+                ArithmeticOp::Divide => a.checked_div(b),
+            };
+            result
+                .map(|v| NullableValue::Int64(Some(v)))
+                .ok_or_else(|| Report::new(EvalError::Overflow))
+        }
+        (a, b) => {
+            let a = match a {
+                Number::Int(v) => Decimal::from(v),
+                Number::Decimal(v) => v,
+            };
+            let b = match b {
+                Number::Int(v) => Decimal::from(v),
+                Number::Decimal(v) => v,
+            };
+            if matches!(op, ArithmeticOp::Divide) && b.is_zero() {
+                return Err(Report::new(EvalError::DivisionByZero));
+            }
+            let result = match op {
+                ArithmeticOp::Add => a.checked_add(b),
+                ArithmeticOp::Subtract => a.checked_sub(b),
+                ArithmeticOp::Multiply => a.checked_mul(b),
+                ArithmeticOp::Divide => a.checked_div(b),
+            };
+            result
+                .map(|v| NullableValue::Decimal(Some(v)))
+                .ok_or_else(|| Report::new(EvalError::Overflow))
+        }
+    }
+}
+
+impl Evaluate for ArithmeticExprWhere {
+    fn evaluate(&self, values: &dyn FieldValues) -> error_stack::Result<NullableValue, EvalError> {
+        let (op, a, b) = match self {
+            ArithmeticExprWhere::ValueWhere(value) => return value.evaluate(values),
+            ArithmeticExprWhere::Expression(expression) => return expression.evaluate(values),
+            ArithmeticExprWhere::Add(a, b) => (ArithmeticOp::Add, a, b),
+            ArithmeticExprWhere::Subtract(a, b) => (ArithmeticOp::Subtract, a, b),
+            ArithmeticExprWhere::Multiply(a, b) => (ArithmeticOp::Multiply, a, b),
+            ArithmeticExprWhere::Divide(a, b) => (ArithmeticOp::Divide, a, b),
+        };
+        arithmetic(op, a.evaluate(values)?, b.evaluate(values)?)
+    }
+}
+
+/// Compare two values, `None` when any of them is `NULL`.
+fn compare(
+    a: &NullableValue,
+    b: &NullableValue,
+) -> error_stack::Result<Option<Ordering>, EvalError> {
+    let (a, b) = match (a.value(), b.value()) {
+        (Some(a), Some(b)) => (a, b),
+        _ => return Ok(None),
+    };
+    let ordering = match (&a, &b) {
+        (Value::String(a), Value::String(b)) => a.cmp(b),
+        (Value::Uuid(a), Value::Uuid(b)) => a.cmp(b),
+        (Value::Boolean(a), Value::Boolean(b)) => a.cmp(b),
+        (Value::Date(a), Value::Date(b)) => a.cmp(b),
+        (Value::DateTime(a), Value::DateTime(b)) => a.cmp(b),
+        (Value::Date(a), Value::DateTime(b)) => a.and_time(Default::default()).cmp(b),
+        (Value::DateTime(a), Value::Date(b)) => a.cmp(&b.and_time(Default::default())),
+        (Value::Json(a), Value::Json(b)) => a.to_string().cmp(&b.to_string()),
+        (
+            Value::Int32(_) | Value::Int64(_) | Value::Decimal(_),
+            Value::Int32(_) | Value::Int64(_) | Value::Decimal(_),
+        ) => to_decimal(&a).cmp(&to_decimal(&b)),
+        _ => {
+            return Err(Report::new(EvalError::TypeMismatch(format!(
+                "cannot compare {} with {}",
+                a.value_type(),
+                b.value_type()
+            ))))
+        }
+    };
+    Ok(Some(ordering))
+}
+
+fn to_decimal(value: &Value) -> Decimal {
+    match value {
+        Value::Int32(v) => Decimal::from(*v),
+        Value::Int64(v) => Decimal::from(*v),
+        Value::Decimal(v) => *v,
+        _ => unreachable!("only numbers are converted"),
+    }
+}
+
+fn to_string(value: NullableValue) -> error_stack::Result<Option<String>, EvalError> {
+    match value {
+        NullableValue::String(value) => Ok(value),
+        other => Err(Report::new(EvalError::TypeMismatch(format!(
+            "expected string, found {}",
+            other.value_type()
+        )))),
+    }
+}
+
+/// Match `text` against a `LIKE` pattern, where `%` is any sequence and `_` any single character.
+fn like_match(text: &str, pattern: &str) -> bool {
+    let text = text.chars().collect::<Vec<_>>();
+    let pattern = pattern.chars().collect::<Vec<_>>();
+    // matches[j]: the text consumed so far matches pattern[..j]
+    let mut matches = vec![false; pattern.len() + 1];
+    matches[0] = true;
+    for j in 1..=pattern.len() {
+        matches[j] = matches[j - 1] && pattern[j - 1] == '%';
+    }
+    for c in text.iter() {
+        let mut next = vec![false; pattern.len() + 1];
+        for j in 1..=pattern.len() {
+            next[j] = match pattern[j - 1] {
+                '%' => next[j - 1] || matches[j],
+                '_' => matches[j - 1],
+                p => matches[j - 1] && p == *c,
+            };
+        }
+        matches = next;
+    }
+    matches[pattern.len()]
+}
+
+fn like(
+    value: NullableValue,
+    pattern: NullableValue,
+    case_insensitive: bool,
+) -> error_stack::Result<Option<bool>, EvalError> {
+    match (to_string(value)?, to_string(pattern)?) {
+        (Some(value), Some(pattern)) if case_insensitive => Ok(Some(like_match(
+            &value.to_lowercase(),
+            &pattern.to_lowercase(),
+        ))),
+        (Some(value), Some(pattern)) => Ok(Some(like_match(&value, &pattern))),
+        _ => Ok(None),
+    }
+}
+
+fn and(a: Option<bool>, b: Option<bool>) -> Option<bool> {
+    match (a, b) {
+        (Some(false), _) | (_, Some(false)) => Some(false),
+        (Some(true), Some(true)) => Some(true),
+        _ => None,
+    }
+}
+
+fn or(a: Option<bool>, b: Option<bool>) -> Option<bool> {
+    match (a, b) {
+        (Some(true), _) | (_, Some(true)) => Some(true),
+        (Some(false), Some(false)) => Some(false),
+        _ => None,
+    }
+}
+
+fn compare_with(
+    a: &ValueWhere,
+    b: &ValueWhere,
+    values: &dyn FieldValues,
+    check: fn(Ordering) -> bool,
+) -> error_stack::Result<Option<bool>, EvalError> {
+    let ordering = compare(&a.evaluate(values)?, &b.evaluate(values)?)?;
+    Ok(ordering.map(check))
+}
+
+fn include(
+    value: &ValueWhere,
+    list: &ValuesListWhere,
+    values: &dyn FieldValues,
+) -> error_stack::Result<Option<bool>, EvalError> {
+    let list = match list {
+        ValuesListWhere::List(list) => list,
+        ValuesListWhere::SingleSelect(_) | ValuesListWhere::Select(_) => {
+            return unsupported("sub-query")
+        }
+    };
+    let value = value.evaluate(values)?;
+    // Without a match, any `NULL` in the comparison makes the result unknown.
+    let mut result = Some(false);
+    for item in list.values_where() {
+        match compare(&value, &item.evaluate(values)?)? {
+            Some(Ordering::Equal) => return Ok(Some(true)),
+            Some(_) => {}
+            None => result = None,
+        }
+    }
+    Ok(result)
+}
+
+fn between(
+    value: &ValueWhere,
+    low: &ValueWhere,
+    high: &ValueWhere,
+    values: &dyn FieldValues,
+) -> error_stack::Result<Option<bool>, EvalError> {
+    let value = value.evaluate(values)?;
+    let low = compare(&value, &low.evaluate(values)?)?.map(|o| o != Ordering::Less);
+    let high = compare(&value, &high.evaluate(values)?)?.map(|o| o != Ordering::Greater);
+    Ok(and(low, high))
+}
+
+fn distinct(
+    a: &ValueWhere,
+    b: &ValueWhere,
+    values: &dyn FieldValues,
+) -> error_stack::Result<bool, EvalError> {
+    let (a, b) = (a.evaluate(values)?, b.evaluate(values)?);
+    match (a.is_null(), b.is_null()) {
+        (true, true) => Ok(false),
+        (false, false) => Ok(compare(&a, &b)? != Some(Ordering::Equal)),
+        _ => Ok(true),
+    }
+}
+
+impl Evaluate for ConditionWhere {
+    fn evaluate(&self, values: &dyn FieldValues) -> error_stack::Result<NullableValue, EvalError> {
+        let result = match self {
+            ConditionWhere::Expression(expression) => to_bool(expression.evaluate(values)?)?,
+            ConditionWhere::ConditionEq(a, b) => compare_with(a, b, values, Ordering::is_eq)?,
+            ConditionWhere::ConditionDf(a, b) => compare_with(a, b, values, Ordering::is_ne)?,
+            ConditionWhere::ConditionGt(a, b) => compare_with(a, b, values, Ordering::is_gt)?,
+            ConditionWhere::ConditionLs(a, b) => compare_with(a, b, values, Ordering::is_lt)?,
+            ConditionWhere::ConditionGe(a, b) => compare_with(a, b, values, Ordering::is_ge)?,
+            ConditionWhere::ConditionLe(a, b) => compare_with(a, b, values, Ordering::is_le)?,
+            ConditionWhere::ConditionNull(a) => Some(a.evaluate(values)?.is_null()),
+            ConditionWhere::ConditionNotNull(a) => Some(!a.evaluate(values)?.is_null()),
+            ConditionWhere::ConditionLk(a, b) => {
+                like(a.evaluate(values)?, b.evaluate(values)?, false)?
+            }
+            ConditionWhere::ConditionNotLk(a, b) => {
+                like(a.evaluate(values)?, b.evaluate(values)?, false)?.map(|r| !r)
+            }
+            ConditionWhere::ConditionILk(a, b) => {
+                like(a.evaluate(values)?, b.evaluate(values)?, true)?
+            }
+            ConditionWhere::ConditionIn(a, list) => include(a, list, values)?,
+            ConditionWhere::ConditionNotIn(a, list) => include(a, list, values)?.map(|r| !r),
+            ConditionWhere::ConditionBetween(a, low, high) => between(a, low, high, values)?,
+            ConditionWhere::ConditionNotBetween(a, low, high) => {
+                between(a, low, high, values)?.map(|r| !r)
+            }
+            ConditionWhere::ConditionDistinct(a, b) => Some(distinct(a, b, values)?),
+            ConditionWhere::ConditionNotDistinct(a, b) => Some(!distinct(a, b, values)?),
+            ConditionWhere::Exists(_)
+            | ConditionWhere::NotExists(_)
+            | ConditionWhere::ConditionAny(_, _, _)
+            | ConditionWhere::ConditionAll(_, _, _) => return unsupported("sub-query"),
+        };
+        Ok(NullableValue::Boolean(result))
+    }
+}
+
+impl Evaluate for LogicalExprWhere {
+    fn evaluate(&self, values: &dyn FieldValues) -> error_stack::Result<NullableValue, EvalError> {
+        let result = match self {
+            LogicalExprWhere::Condition(condition) => condition.evaluate_bool(values)?,
+            LogicalExprWhere::Not(expression) => expression.evaluate_bool(values)?.map(|r| !r),
+            LogicalExprWhere::Expression(expression) => expression.evaluate_bool(values)?,
+            LogicalExprWhere::And(a, b) => and(a.evaluate_bool(values)?, b.evaluate_bool(values)?),
+            LogicalExprWhere::Or(a, b) => or(a.evaluate_bool(values)?, b.evaluate_bool(values)?),
+        };
+        Ok(NullableValue::Boolean(result))
+    }
+}
+
+/// Result of a `CASE` without matching branch: `ELSE` or a `NULL` typed as the first branch.
+fn case_else(
+    else_case: Option<&ValueWhere>,
+    first_then: Option<&ValueWhere>,
+    values: &dyn FieldValues,
+) -> error_stack::Result<NullableValue, EvalError> {
+    match (else_case, first_then) {
+        (Some(else_case), _) => else_case.evaluate(values),
+        (None, Some(then)) => Ok(NullableValue::null(then.evaluate(values)?.value_type())),
+        (None, None) => Ok(NullableValue::null(ValueType::String)),
+    }
+}
+
+impl Evaluate for CaseCondition {
+    fn evaluate(&self, values: &dyn FieldValues) -> error_stack::Result<NullableValue, EvalError> {
+        for when in self.whens() {
+            if when.when_condition().evaluate_bool(values)? == Some(true) {
+                return when.then_value_where().evaluate(values);
+            }
+        }
+        let first_then = self.whens().first().map(|w| w.then_value_where());
+        case_else(self.else_case(), first_then, values)
+    }
+}
+
+impl Evaluate for CaseValue {
+    fn evaluate(&self, values: &dyn FieldValues) -> error_stack::Result<NullableValue, EvalError> {
+        let input = self.input().evaluate(values)?;
+        for when in self.whens() {
+            if compare(&input, &when.when_value_where().evaluate(values)?)? == Some(Ordering::Equal)
+            {
+                return when.then_value_where().evaluate(values);
+            }
+        }
+        let first_then = self.whens().first().map(|w| w.then_value_where());
+        case_else(self.else_case(), first_then, values)
+    }
+}
+
+impl Evaluate for StringFunction {
+    fn evaluate(&self, values: &dyn FieldValues) -> error_stack::Result<NullableValue, EvalError> {
+        let result = match self {
+            StringFunction::Upper(value) => {
+                to_string(value.evaluate(values)?)?.map(|v| v.to_uppercase())
+            }
+            StringFunction::Lower(value) => {
+                to_string(value.evaluate(values)?)?.map(|v| v.to_lowercase())
+            }
+            StringFunction::Substring(value, start, length) => {
+                // Positions are 1-based, the range is [start, start + length).
+                let (start, end) = (*start as usize, *start as usize + *length as usize);
+                to_string(value.evaluate(values)?)?.map(|v| {
+                    v.chars()
+                        .enumerate()
+                        .filter(|(i, _)| (start..end).contains(&(i + 1)))
+                        .map(|(_, c)| c)
+                        .collect()
+                })
+            }
+            StringFunction::Replace(value, old, new) => {
+                let value = to_string(value.evaluate(values)?)?;
+                let old = to_string(old.evaluate(values)?)?;
+                let new = to_string(new.evaluate(values)?)?;
+                match (value, old, new) {
+                    (Some(value), Some(old), Some(new)) => Some(value.replace(&old, &new)),
+                    _ => None,
+                }
+            }
+            // As in SQL `CONCAT`, `NULL` values are ignored.
+            StringFunction::Concat(list) => Some(
+                list.values_where()
+                    .iter()
+                    .map(|v| v.evaluate(values).map(|v| v.value()))
+                    .collect::<Result<Vec<_>, _>>()
+                    .attach_printable("evaluating CONCAT")?
+                    .into_iter()
+                    .flatten()
+                    .map(|v| v.to_string())
+                    .collect(),
+            ),
+        };
+        Ok(NullableValue::String(result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::selections::{
+        CaseConditionBuilder, CaseValueBuilder, ConditionWhereOperation, IntoValueWhere,
+        LogicalExprWhereOps, SingleSelectBuilder, WhenCondition, WhenValue,
+    };
+    use crate::IntoNullableValue;
+    use pretty_assertions::assert_eq;
+    use rust_decimal_macros::dec;
+
+    fn row() -> HashMap<FieldName, NullableValue> {
+        let mut row = HashMap::new();
+        row.insert(FieldName::from("QTY"), 3.into_nullable_value());
+        row.insert(FieldName::from("PRICE"), dec!(2.5).into_nullable_value());
+        row.insert(FieldName::from("NAME"), "Widget".into_nullable_value());
+        row.insert(FieldName::from("NOTE"), NullableValue::String(None));
+        row.insert(FieldName::from("ACTIVE"), true.into_nullable_value());
+        row
+    }
+
+    fn field(name: &str) -> TableField {
+        TableField::new(name)
+    }
+
+    #[test]
+    fn test_arithmetic_promotion() {
+        let total = ArithmeticExprWhere::multiply(field("PRICE"), field("QTY"));
+        assert_eq!(
+            total.evaluate(&row()).unwrap(),
+            dec!(7.5).into_nullable_value()
+        );
+        let ints = ArithmeticExprWhere::divide(field("QTY"), 2);
+        assert_eq!(ints.evaluate(&row()).unwrap(), 1i64.into_nullable_value());
+        let error = ArithmeticExprWhere::divide(field("QTY"), 0)
+            .evaluate(&row())
+            .unwrap_err();
+        assert_eq!(error.current_context(), &EvalError::DivisionByZero);
+    }
+
+    #[test]
+    fn test_null_semantics() {
+        let row = row();
+        assert_eq!(field("NOTE").equal("A").evaluate_bool(&row).unwrap(), None);
+        assert_eq!(
+            field("NOTE").null().evaluate_bool(&row).unwrap(),
+            Some(true)
+        );
+        let unknown_or_true = field("NOTE").equal("A").or(field("QTY").equal(3));
+        assert_eq!(unknown_or_true.evaluate_bool(&row).unwrap(), Some(true));
+        let unknown_and_true = field("NOTE").equal("A").and(field("QTY").equal(3));
+        assert_eq!(unknown_and_true.evaluate_bool(&row).unwrap(), None);
+        assert_eq!(unknown_and_true.not().evaluate_bool(&row).unwrap(), None);
+        let not_in = field("QTY").not_include(vec![
+            1.into_value_where(),
+            ValueWhere::LiteralValue(NullableValue::Int32(None)),
+        ]);
+        assert_eq!(not_in.evaluate_bool(&row).unwrap(), None);
+        assert_eq!(
+            field("NOTE")
+                .not_distinct_from(NullableValue::String(None))
+                .evaluate_bool(&row)
+                .unwrap(),
+            Some(true)
+        );
+        assert_eq!(
+            ArithmeticExprWhere::add(
+                field("QTY"),
+                ValueWhere::LiteralValue(NullableValue::Int32(None))
+            )
+            .evaluate(&row)
+            .unwrap(),
+            NullableValue::Int64(None)
+        );
+    }
+
+    #[test]
+    fn test_comparisons() {
+        let row = row();
+        assert_eq!(
+            field("PRICE").greater(2).evaluate_bool(&row).unwrap(),
+            Some(true)
+        );
+        assert_eq!(
+            field("QTY")
+                .between(1, dec!(3.0))
+                .evaluate_bool(&row)
+                .unwrap(),
+            Some(true)
+        );
+        assert_eq!(
+            field("QTY")
+                .include(vec![1, 3])
+                .evaluate_bool(&row)
+                .unwrap(),
+            Some(true)
+        );
+        let error = field("NAME").greater(1).evaluate_bool(&row).unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            EvalError::TypeMismatch(_)
+        ));
+    }
+
+    #[test]
+    fn test_like() {
+        let row = row();
+        assert_eq!(
+            field("NAME").like("W%t").evaluate_bool(&row).unwrap(),
+            Some(true)
+        );
+        assert_eq!(
+            field("NAME").like("W_dget").evaluate_bool(&row).unwrap(),
+            Some(true)
+        );
+        assert_eq!(
+            field("NAME").like("w%").evaluate_bool(&row).unwrap(),
+            Some(false)
+        );
+        assert_eq!(
+            field("NAME").ilike("w%").evaluate_bool(&row).unwrap(),
+            Some(true)
+        );
+        assert_eq!(
+            field("NAME").not_like("%x%").evaluate_bool(&row).unwrap(),
+            Some(true)
+        );
+        assert!(like_match("", "%"));
+        assert!(!like_match("ab", "a"));
+    }
+
+    #[test]
+    fn test_case_and_string_functions() {
+        let row = row();
+        let case = CaseConditionBuilder::new()
+            .when_value(WhenCondition::new(field("QTY").greater(5), "MANY"))
+            .when_value(WhenCondition::new(field("QTY").greater(1), "FEW"))
+            .build();
+        assert_eq!(case.evaluate(&row).unwrap(), "FEW".into_nullable_value());
+        let case = CaseValueBuilder::new(field("NAME"))
+            .when_value(WhenValue::new("Other", 1))
+            .build();
+        assert_eq!(case.evaluate(&row).unwrap(), NullableValue::Int32(None));
+        let upper = StringFunction::upper(field("NAME"));
+        assert_eq!(
+            upper.evaluate(&row).unwrap(),
+            "WIDGET".into_nullable_value()
+        );
+        let substring = StringFunction::substring(field("NAME"), 2, 3);
+        assert_eq!(
+            substring.evaluate(&row).unwrap(),
+            "idg".into_nullable_value()
+        );
+        let concat = StringFunction::concat(vec![
+            field("NAME").into_value_where(),
+            field("NOTE").into_value_where(),
+            field("QTY").into_value_where(),
+        ]);
+        assert_eq!(
+            concat.evaluate(&row).unwrap(),
+            "Widget3".into_nullable_value()
+        );
+    }
+
+    #[test]
+    fn test_unsupported() {
+        let query = SingleSelectBuilder::field("ID").from("TABLE").build();
+        let error = field("QTY")
+            .include(query)
+            .evaluate_bool(&row())
+            .unwrap_err();
+        assert!(matches!(error.current_context(), EvalError::Unsupported(_)));
+        let error = field("MISSING").null().evaluate_bool(&row()).unwrap_err();
+        assert_eq!(
+            error.current_context(),
+            &EvalError::FieldNotFound("MISSING".to_string())
+        );
+    }
+
+    #[cfg(feature = "objects")]
+    #[test]
+    fn test_subset_values() {
+        let mut values = SubsetValues::new();
+        values.set_value("QTY", 3);
+        values.set_value("ACTIVE", true);
+        let cond = field("QTY")
+            .greater_or_equal(3)
+            .and(ConditionWhere::expression(field("ACTIVE")));
+        assert_eq!(cond.evaluate_bool(&values).unwrap(), Some(true));
+        let lookup = |table_field: &TableField| match table_field.field_name.0.as_str() {
+            "QTY" => Some(1.into_nullable_value()),
+            _ => Some(NullableValue::Boolean(None)),
+        };
+        assert_eq!(cond.evaluate_bool(&lookup).unwrap(), Some(false));
+    }
+}
@@ -8,11 +8,12 @@ use crate::{
         table_field::IntoTablesField,
         value_select_attrib::{ValueSelectAttrib, ValuesSelectAttribs},
         values_select::{IntoValuesSelect, ValuesSelect},
-        IntoTableField, IntoValueSelect, TableField, ToSQL,
+        IntoTableField, IntoValueSelect, Select, TableField, TableTypes, ToSQL, ValueWhere,
     },
     SQLError,
 };
 use crate::{IntoFieldName, IntoValueType, ValueType};
+use error_stack::Report;
 use serde::{Deserialize, Serialize};
 use std::ops::Add;
 use std::sync::Arc;
@@ -36,6 +37,56 @@ impl FieldsAttribsBuilder {
         }
     }
 
+    /// Create `FieldsAttribsBuilder` with a field for each column of the query, its type and
+    /// nullability inferred from the columns of the tables.
+    /// Columns without name or with a type that can't be inferred are an error.
+    /// # Example
+    /// ```
+    /// # use voxi_core::selections::{FieldsAttribsBuilder, InferredType, Select, TableName};
+    /// # use voxi_core::dialects::PostgresDialect;
+    /// # use voxi_core::{FieldName, ValueType};
+    /// # use std::collections::HashMap;
+    /// let mut tables = HashMap::new();
+    /// tables.insert(
+    ///     TableName::from("PRODUCT"),
+    ///     vec![(FieldName::from("PRICE"), InferredType::nullable(ValueType::Decimal))],
+    /// );
+    /// let sql = r#"SELECT "PRICE", COUNT(*) AS "TOTAL" FROM "PRODUCT" GROUP BY "PRICE""#;
+    /// let query = Select::parse(sql, &PostgresDialect).unwrap();
+    /// let fields = FieldsAttribsBuilder::from_select(&query, &tables).unwrap().build();
+    /// let total = fields.field_attribs_by_name("TOTAL").unwrap();
+    /// assert_eq!(total.value_type, ValueType::Int64);
+    /// assert!(!total.nullable && total.calculated);
+    /// assert!(fields.field_attribs_by_name("PRICE").unwrap().nullable);
+    /// ```
+    pub fn from_select(
+        select: &Select,
+        tables: &dyn TableTypes,
+    ) -> error_stack::Result<Self, SQLError> {
+        let mut fields_attribs = Vec::new();
+        for (column, inferred) in select.infer_columns(tables) {
+            let Some(name) = column.try_field_name() else {
+                return Err(Report::new(SQLError::InvalidQueryBuilderConfiguration(
+                    format!("column `{}` has no alias", column.value_where),
+                )));
+            };
+            let Some(inferred) = inferred else {
+                return Err(Report::new(SQLError::TypeNotInferred(name.to_string())));
+            };
+            let calculated = !matches!(column.value_where, ValueWhere::TableField(_));
+            let field_attribs = FieldAttribs::new(
+                inferred.value_type,
+                name.clone(),
+                name.as_ref(),
+                Some(column),
+            )
+            .with_nullable(inferred.nullable)
+            .with_calculated(calculated);
+            fields_attribs.push(field_attribs);
+        }
+        Ok(Self { fields_attribs })
+    }
+
     pub fn add_str(
         &mut self,
         name: &str,
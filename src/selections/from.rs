@@ -5,7 +5,10 @@ use super::{
     table_name::{IntoTableName, TableName},
     to_sql::ToSQL,
 };
-use crate::{resolvers::args_resolver::ArgsResolver, SQLError};
+use crate::{
+    resolvers::{args_resolver::ArgsResolver, sql_format::nested},
+    SQLError,
+};
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 
@@ -23,7 +26,7 @@ pub struct FromSelect {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     alias: Option<Alias>,
-    from_type: FromType,
+    pub(crate) from_type: FromType,
 }
 
 pub trait IntoFromSelect {
@@ -202,10 +205,13 @@ impl ToSQL for FromSelect {
     ) -> error_stack::Result<String, SQLError> {
         let table = match &self.from_type {
             FromType::Table(t) => t.to_sql(args_resolver)?,
-            FromType::Query(q) => format!("({})", q.to_sql(args_resolver)?),
+            FromType::Query(q) => format!("({})", nested(args_resolver, |r| q.to_sql(r))?),
         };
         let sql = match &self.alias {
-            Some(alias) => format!(r#"{table} "{alias}""#),
+            Some(alias) => format!(
+                "{table} {}",
+                args_resolver.dialect().quote_identifier(&alias.to_string())
+            ),
             None => table,
         };
         Ok(sql)
@@ -9,7 +9,7 @@ use serde::{Deserialize, Serialize};
 /// Definition for SQL GROUP BY.
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct GroupBy {
-    table_field: TableField,
+    pub(crate) table_field: TableField,
 }
 
 impl GroupBy {
@@ -0,0 +1,428 @@
+use super::{
+    select::IntoSelect,
+    table_field::IntoTablesField,
+    table_name::{IntoTableName, TableName},
+    to_sql::ToSQL,
+    upsert::{merge_to_sql, ConflictAction, OnConflict, UpsertSet},
+    value_where::IntoValueWhere,
+    values_select::ValuesSelect,
+    values_where::{IntoValuesWhere, ValuesWhere},
+    Select,
+};
+use crate::dialects::UpsertStyle;
+#[cfg(feature = "objects")]
+use crate::objects::sub_set_values::SubsetValues;
+use crate::{resolvers::args_resolver::ArgsResolver, SQLError};
+use crate::{FieldName, IntoFieldName};
+use serde::{Deserialize, Serialize};
+
+/// Source of the rows inserted by `INSERT`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum InsertSource {
+    /// One or many rows `VALUES (..), (..)`.
+    Values(Vec<ValuesWhere>),
+    /// Rows returned by a query `INSERT .. SELECT`.
+    Select(Box<Select>),
+}
+
+/// InsertBuilder allow customize creating of a SQL `INSERT`.
+/// # Example
+/// ```
+/// # use voxi_core::selections::{InsertBuilder, IntoValueWhere};
+/// # use voxi_core::resolvers::args_resolver_string::args_to_str;
+/// let insert = InsertBuilder::new("TABLE")
+///     .columns(vec!["ID", "NAME"])
+///     .values(vec![1.into_value_where(), "TEXT".into_value_where()])
+///     .build()
+///     .unwrap();
+/// assert_eq!(
+///     args_to_str(&insert).unwrap(),
+///     r#"INSERT INTO "TABLE" ("ID", "NAME") VALUES (1, 'TEXT')"#
+/// );
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct InsertBuilder {
+    table: TableName,
+    columns: Vec<FieldName>,
+    source: Option<InsertSource>,
+    returning: ValuesSelect,
+    on_conflict: Option<OnConflict>,
+}
+
+impl InsertBuilder {
+    /// Create the `InsertBuilder` for the target table.
+    pub fn new(table: impl IntoTableName) -> Self {
+        Self {
+            table: table.into_table_name(),
+            columns: Vec::new(),
+            source: None,
+            returning: ValuesSelect::empty(),
+            on_conflict: None,
+        }
+    }
+
+    /// Add a column to be filled.
+    #[must_use]
+    pub fn column(mut self, column: impl IntoFieldName) -> Self {
+        self.columns.push(column.into_field_name());
+        self
+    }
+
+    /// Add columns to be filled.
+    #[must_use]
+    pub fn columns<F: IntoFieldName>(mut self, columns: Vec<F>) -> Self {
+        self.columns
+            .extend(columns.into_iter().map(|c| c.into_field_name()));
+        self
+    }
+
+    /// Add a row of values, in the same order of the columns.
+    /// If a query had been defined as source it will be replaced.
+    #[must_use]
+    pub fn values(mut self, row: impl IntoValuesWhere) -> Self {
+        let row = row.into_values_where();
+        match &mut self.source {
+            Some(InsertSource::Values(rows)) => rows.push(row),
+            _ => self.source = Some(InsertSource::Values(vec![row])),
+        }
+        self
+    }
+
+    /// Define a query as source of rows (`INSERT .. SELECT`).
+    #[must_use]
+    pub fn select(mut self, query: impl IntoSelect) -> Self {
+        self.source = Some(InsertSource::Select(Box::new(query.into_select())));
+        self
+    }
+
+    /// Add fields to be returned by the statement (`RETURNING`).
+    #[must_use]
+    pub fn returning(mut self, columns: impl IntoTablesField) -> Self {
+        for table_field in columns.into_tables_field() {
+            self.returning.push(table_field);
+        }
+        self
+    }
+
+    /// Define the columns of the unique constraint that make this insert an upsert.
+    /// Conflicting rows must be updated with `update_excluded`/`update_set` or ignored with `do_nothing`,
+    /// before or after defining the columns.
+    #[must_use]
+    pub fn on_conflict<F: IntoFieldName>(mut self, target: Vec<F>) -> Self {
+        let target = target.into_iter().map(|f| f.into_field_name()).collect();
+        let action = self
+            .on_conflict
+            .take()
+            .map(|c| c.action)
+            .unwrap_or(ConflictAction::DoUpdate(Vec::new()));
+        self.on_conflict = Some(OnConflict::new(target, action));
+        self
+    }
+
+    /// Keep the existing row when a conflict happens.
+    #[must_use]
+    pub fn do_nothing(mut self) -> Self {
+        let target = self
+            .on_conflict
+            .take()
+            .map(|c| c.target)
+            .unwrap_or_default();
+        self.on_conflict = Some(OnConflict::new(target, ConflictAction::DoNothing));
+        self
+    }
+
+    /// On conflict, update the field with the value proposed by the inserted row.
+    #[must_use]
+    pub fn update_excluded(self, field_name: impl IntoFieldName) -> Self {
+        self.add_upsert_set(UpsertSet::excluded(field_name))
+    }
+
+    /// On conflict, update the field with an expression.
+    #[must_use]
+    pub fn update_set(self, field_name: impl IntoFieldName, value: impl IntoValueWhere) -> Self {
+        self.add_upsert_set(UpsertSet::value(field_name, value))
+    }
+
+    fn add_upsert_set(mut self, set: UpsertSet) -> Self {
+        let (target, sets) = match self.on_conflict.take() {
+            Some(OnConflict {
+                target,
+                action: ConflictAction::DoUpdate(mut sets),
+            }) => {
+                sets.push(set);
+                (target, sets)
+            }
+            Some(OnConflict { target, .. }) => (target, vec![set]),
+            None => (Vec::new(), vec![set]),
+        };
+        self.on_conflict = Some(OnConflict::new(target, ConflictAction::DoUpdate(sets)));
+        self
+    }
+
+    pub fn build(self) -> Result<Insert, SQLError> {
+        if self.columns.is_empty() {
+            return Err(SQLError::InvalidQueryBuilderConfiguration(
+                "no column has been defined".to_string(),
+            ));
+        }
+        let source = match self.source {
+            Some(source) => source,
+            None => {
+                return Err(SQLError::InvalidQueryBuilderConfiguration(
+                    "no values or query has been defined".to_string(),
+                ))
+            }
+        };
+        if let InsertSource::Values(rows) = &source {
+            if let Some(row) = rows
+                .iter()
+                .find(|row| row.values_where().len() != self.columns.len())
+            {
+                return Err(SQLError::InvalidQueryBuilderConfiguration(format!(
+                    "row has {} values but {} columns has been defined",
+                    row.values_where().len(),
+                    self.columns.len()
+                )));
+            }
+        }
+        if let Some(on_conflict) = &self.on_conflict {
+            if let ConflictAction::DoUpdate(sets) = &on_conflict.action {
+                if sets.is_empty() {
+                    return Err(SQLError::InvalidQueryBuilderConfiguration(
+                        "no conflict assignment has been defined".to_string(),
+                    ));
+                }
+                if on_conflict.target.is_empty() {
+                    return Err(SQLError::InvalidQueryBuilderConfiguration(
+                        "no conflict target column has been defined".to_string(),
+                    ));
+                }
+            }
+        }
+        Ok(Insert {
+            table: self.table,
+            columns: self.columns,
+            source,
+            returning: self.returning,
+            on_conflict: self.on_conflict,
+        })
+    }
+}
+
+/// SQL `INSERT` statement.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Insert {
+    pub table: TableName,
+    pub columns: Vec<FieldName>,
+    pub source: InsertSource,
+    pub returning: ValuesSelect,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub on_conflict: Option<OnConflict>,
+}
+
+impl Insert {
+    /// Create an insert of a single row, using field names as columns.
+    /// # Example
+    /// ```
+    /// # use voxi_core::objects::sub_set_values::SubsetValues;
+    /// # use voxi_core::selections::Insert;
+    /// # use voxi_core::resolvers::args_resolver_string::args_to_str;
+    /// let mut subset_values = SubsetValues::new();
+    /// subset_values.set_value("ID", 1);
+    /// subset_values.set_value("NAME", "TEXT");
+    /// let insert = Insert::from_subset_values("TABLE", &subset_values).unwrap();
+    /// assert_eq!(
+    ///     args_to_str(&insert).unwrap(),
+    ///     r#"INSERT INTO "TABLE" ("ID", "NAME") VALUES (1, 'TEXT')"#
+    /// );
+    /// ```
+    #[cfg(feature = "objects")]
+    pub fn from_subset_values(
+        table: impl IntoTableName,
+        subset_values: &SubsetValues,
+    ) -> Result<Self, SQLError> {
+        let (columns, row): (Vec<_>, Vec<_>) = subset_values
+            .values()
+            .iter()
+            .map(|(name, value)| (name.clone(), value.opt_value.clone()))
+            .unzip();
+        InsertBuilder::new(table)
+            .columns(columns)
+            .values(row)
+            .build()
+    }
+
+    /// Target table.
+    pub fn table(&self) -> &TableName {
+        &self.table
+    }
+
+    pub fn columns(&self) -> &[FieldName] {
+        self.columns.as_ref()
+    }
+
+    pub fn source(&self) -> &InsertSource {
+        &self.source
+    }
+}
+
+impl ToSQL for InsertSource {
+    fn to_sql(
+        &self,
+        args_resolver: &mut dyn ArgsResolver,
+    ) -> error_stack::Result<String, SQLError> {
+        let sql = match self {
+            InsertSource::Values(rows) => {
+                let rows = rows
+                    .iter()
+                    .map(|row| {
+                        let values = row
+                            .values_where()
+                            .iter()
+                            .map(|v| v.to_sql(args_resolver))
+                            .collect::<Result<Vec<_>, _>>()?;
+                        Ok(format!("({})", values.join(", ")))
+                    })
+                    .collect::<error_stack::Result<Vec<_>, SQLError>>()?;
+                format!("VALUES {}", rows.join(", "))
+            }
+            InsertSource::Select(query) => query.to_sql(args_resolver)?,
+        };
+        Ok(sql)
+    }
+}
+
+impl ToSQL for Insert {
+    fn to_sql(
+        &self,
+        args_resolver: &mut dyn ArgsResolver,
+    ) -> error_stack::Result<String, SQLError> {
+        if let Some(on_conflict) = &self.on_conflict {
+            if args_resolver.dialect().upsert_style() == UpsertStyle::Merge {
+                return merge_to_sql(self, on_conflict, args_resolver);
+            }
+        }
+        let columns = self
+            .columns
+            .iter()
+            .map(|c| c.to_sql(args_resolver))
+            .collect::<Result<Vec<_>, _>>()?
+            .join(", ");
+        let mut sql = format!(
+            "INSERT INTO {} ({columns}) {}",
+            self.table.to_sql(args_resolver)?,
+            self.source.to_sql(args_resolver)?
+        );
+        if let Some(on_conflict) = &self.on_conflict {
+            sql.push_str(&on_conflict.to_sql_suffix(&self.columns, args_resolver)?);
+        }
+        if !self.returning.is_empty() {
+            sql.push_str(&returning_to_sql(&self.returning, args_resolver)?);
+        }
+        Ok(sql)
+    }
+}
+
+/// Render ` RETURNING <columns>`, failing when the dialect has no support for it.
+pub(crate) fn returning_to_sql(
+    returning: &ValuesSelect,
+    args_resolver: &mut dyn ArgsResolver,
+) -> error_stack::Result<String, SQLError> {
+    if !args_resolver.dialect().supports_returning() {
+        return Err(SQLError::SQLResolver(format!(
+            "RETURNING is not supported by {}",
+            args_resolver.dialect().name()
+        ))
+        .into());
+    }
+    Ok(format!(" RETURNING {}", returning.to_sql(args_resolver)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dialects::MySqlDialect;
+    use crate::resolvers::args_resolver_positional::{args_to_positional, PlaceholderStyle};
+    use crate::resolvers::args_resolver_string::{args_to_str, args_to_str_with_dialect};
+    use crate::selections::QueryBuilder;
+    use crate::{IntoNullableValue, NullableValue, ValueType};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_insert_many_rows() {
+        let insert = InsertBuilder::new("TABLE")
+            .column("ID")
+            .column("NAME")
+            .values(vec![1.into_nullable_value(), "A".into_nullable_value()])
+            .values(vec![
+                2.into_nullable_value(),
+                NullableValue::null(ValueType::String),
+            ])
+            .build()
+            .unwrap();
+        assert_eq!(
+            args_to_str(&insert).unwrap(),
+            r#"INSERT INTO "TABLE" ("ID", "NAME") VALUES (1, 'A'), (2, NULL)"#
+        );
+    }
+
+    #[test]
+    fn test_insert_select_returning() {
+        let query = QueryBuilder::new()
+            .field("ID")
+            .from("OTHER")
+            .build()
+            .unwrap();
+        let insert = InsertBuilder::new("TABLE")
+            .column("ID")
+            .select(query)
+            .returning("ID")
+            .build()
+            .unwrap();
+        assert_eq!(
+            args_to_str(&insert).unwrap(),
+            r#"INSERT INTO "TABLE" ("ID") SELECT "ID" FROM "OTHER" RETURNING "ID""#
+        );
+        assert!(args_to_str_with_dialect(&insert, MySqlDialect).is_err());
+    }
+
+    #[test]
+    fn test_insert_positional() {
+        let insert = InsertBuilder::new("TABLE")
+            .columns(vec!["ID", "NAME"])
+            .values(vec![
+                1.into_nullable_value(),
+                "O'Brien".into_nullable_value(),
+            ])
+            .build()
+            .unwrap();
+        let (sql, args) = args_to_positional(&insert, PlaceholderStyle::Dollar).unwrap();
+        assert_eq!(sql, r#"INSERT INTO "TABLE" ("ID", "NAME") VALUES ($1, $2)"#);
+        assert_eq!(args.len(), 2);
+    }
+
+    #[test]
+    fn test_insert_invalid() {
+        let err = InsertBuilder::new("TABLE").column("ID").build();
+        assert!(err.is_err());
+        let err = InsertBuilder::new("TABLE")
+            .columns(vec!["ID", "NAME"])
+            .values(vec![1])
+            .build();
+        assert!(err.is_err());
+    }
+
+    #[test]
+    fn test_insert_serialize() {
+        let insert = InsertBuilder::new("TABLE")
+            .column("ID")
+            .values(vec![1])
+            .returning("ID")
+            .build()
+            .unwrap();
+        let json = serde_json::to_string(&insert).unwrap();
+        let insert_de: Insert = serde_json::from_str(&json).unwrap();
+        assert_eq!(insert, insert_de);
+    }
+}
@@ -2,12 +2,13 @@ use super::from::{FromSelect, IntoFrom};
 use crate::{
     resolvers::args_resolver::ArgsResolver,
     selections::{
-        condition_where::{ConditionWhere, IntoConditionWhere},
+        condition_where::ConditionWhere,
+        logical_expr_where::{IntoLogicalExprWhere, LogicalExprWhere},
         to_sql::ToSQL,
     },
     SQLError,
 };
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
 /// Definition for SQL join relation between other tables/queries.
 /// ```
@@ -26,8 +27,9 @@ use serde::{Deserialize, Serialize};
 /// ```
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Join {
-    from: FromSelect,
-    condition: ConditionWhere,
+    pub(crate) from: FromSelect,
+    #[serde(deserialize_with = "deserialize_condition")]
+    pub(crate) condition: LogicalExprWhere,
     join_type: JoinType,
 }
 
@@ -63,10 +65,10 @@ impl Join {
     ///     r#"INNER JOIN "DETAIL" "DET" ON "DET"."MASTER" = "MAS"."ID""#
     /// );
     /// ```
-    pub fn inner(from: impl IntoFrom, condition: impl IntoConditionWhere) -> Self {
+    pub fn inner(from: impl IntoFrom, condition: impl IntoLogicalExprWhere) -> Self {
         Self {
             from: from.into_from(),
-            condition: condition.into_condition_where(),
+            condition: condition.into_logical_expr_where(),
             join_type: JoinType::Inner,
         }
     }
@@ -86,10 +88,10 @@ impl Join {
     ///     r#"FULL JOIN "DETAIL" "DET" ON "DET"."MASTER" = "MAS"."ID""#
     /// );
     /// ```
-    pub fn full(from: impl IntoFrom, condition: impl IntoConditionWhere) -> Self {
+    pub fn full(from: impl IntoFrom, condition: impl IntoLogicalExprWhere) -> Self {
         Self {
             from: from.into_from(),
-            condition: condition.into_condition_where(),
+            condition: condition.into_logical_expr_where(),
             join_type: JoinType::Full,
         }
     }
@@ -109,10 +111,10 @@ impl Join {
     ///     r#"LEFT JOIN "DETAIL" "DET" ON "DET"."MASTER" = "MAS"."ID""#
     /// );
     /// ```
-    pub fn left(from: impl IntoFrom, condition: impl IntoConditionWhere) -> Self {
+    pub fn left(from: impl IntoFrom, condition: impl IntoLogicalExprWhere) -> Self {
         Self {
             from: from.into_from(),
-            condition: condition.into_condition_where(),
+            condition: condition.into_logical_expr_where(),
             join_type: JoinType::Left,
         }
     }
@@ -132,16 +134,24 @@ impl Join {
     ///     r#"RIGHT JOIN "DETAIL" "DET" ON "DET"."MASTER" = "MAS"."ID""#
     /// );
     /// ```
-    pub fn right(from: impl IntoFrom, condition: impl IntoConditionWhere) -> Self {
+    pub fn right(from: impl IntoFrom, condition: impl IntoLogicalExprWhere) -> Self {
         Self {
             from: from.into_from(),
-            condition: condition.into_condition_where(),
+            condition: condition.into_logical_expr_where(),
             join_type: JoinType::Right,
         }
     }
 
-    /// Get a reference to the condition's table name.
-    pub fn condition(&self) -> &ConditionWhere {
+    /// Get a reference to the join's condition, when it's a single condition.
+    pub fn condition(&self) -> Option<&ConditionWhere> {
+        match &self.condition {
+            LogicalExprWhere::Condition(condition) => Some(condition),
+            _ => None,
+        }
+    }
+
+    /// Get a reference to the join's `ON` expression.
+    pub fn on(&self) -> &LogicalExprWhere {
         &self.condition
     }
 
@@ -211,12 +221,32 @@ impl ToSQL for Join {
     }
 }
 
+/// Read the join condition, also accepting a single `ConditionWhere` as saved before the join
+/// condition became a `LogicalExprWhere`.
+fn deserialize_condition<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<LogicalExprWhere, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum JoinCondition {
+        Expression(LogicalExprWhere),
+        Condition(Box<ConditionWhere>),
+    }
+    Ok(match JoinCondition::deserialize(deserializer)? {
+        JoinCondition::Expression(expression) => expression,
+        JoinCondition::Condition(condition) => LogicalExprWhere::Condition(condition),
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
         resolvers::args_resolver_string::ArgsResolverString,
-        selections::{condition_where::ConditionWhereOperation, table_field::IntoTableField},
+        selections::{
+            condition_where::ConditionWhereOperation, table_field::IntoTableField,
+            LogicalExprWhereOps,
+        },
     };
     use pretty_assertions::assert_eq;
 
@@ -227,7 +257,10 @@ mod tests {
         let field_master = "MAS.ID".into_table_field();
         let join = Join::inner("DETAIL DET", field_detail.equal(field_master));
         assert_eq!(
-            join.condition().to_sql(&mut args_resolver_string).unwrap(),
+            join.condition()
+                .unwrap()
+                .to_sql(&mut args_resolver_string)
+                .unwrap(),
             r#""DET"."MASTER" = "MAS"."ID""#
         );
     }
@@ -288,4 +321,31 @@ mod tests {
         let new_join = Join::new(join.clone());
         assert_eq!(join, new_join);
     }
+
+    #[test]
+    fn test_deserialize_legacy_condition() {
+        let field_detail = "DET.MASTER".into_table_field();
+        let field_master = "MAS.ID".into_table_field();
+        let join = Join::inner("DETAIL DET", field_detail.equal(field_master));
+        let mut json = serde_json::to_value(&join).unwrap();
+        // saved before the condition became a `LogicalExprWhere`: `{"condition":{"ConditionEq":..}}`
+        json["condition"] = json["condition"]["Condition"].take();
+        assert!(json["condition"].get("ConditionEq").is_some());
+        assert_eq!(serde_json::from_value::<Join>(json).unwrap(), join);
+
+        let composite = Join::left(
+            "DETAIL DET",
+            "DET.MASTER"
+                .into_table_field()
+                .equal("MAS.ID".into_table_field())
+                .and(
+                    "DET.YEAR"
+                        .into_table_field()
+                        .equal("MAS.YEAR".into_table_field()),
+                ),
+        );
+        let json = serde_json::to_string(&composite).unwrap();
+        assert_eq!(serde_json::from_str::<Join>(&json).unwrap(), composite);
+        assert_eq!(composite.condition(), None);
+    }
 }
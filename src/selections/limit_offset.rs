@@ -37,9 +37,11 @@ impl LimitOffset {
 impl ToSQL for LimitOffset {
     fn to_sql(
         &self,
-        _args_resolver: &mut dyn ArgsResolver,
+        args_resolver: &mut dyn ArgsResolver,
     ) -> error_stack::Result<String, SQLError> {
-        Ok(format!("LIMIT {} OFFSET {}", self.limit, self.offset))
+        Ok(args_resolver
+            .dialect()
+            .limit_offset(self.limit, self.offset))
     }
 }
 
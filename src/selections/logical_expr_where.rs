@@ -120,6 +120,39 @@ impl LogicalExprWhere {
     }
 }
 
+impl LogicalExprWhere {
+    /// Binding strength of the operation, operands of lower precedence are rendered into "( )".
+    fn precedence(&self) -> u8 {
+        match self {
+            LogicalExprWhere::Condition(_) | LogicalExprWhere::Expression(_) => 4,
+            LogicalExprWhere::Not(_) => 3,
+            LogicalExprWhere::And(_, _) => 2,
+            LogicalExprWhere::Or(_, _) => 1,
+        }
+    }
+
+    /// Render a binary operation, operators are left associative so a right operand with the
+    /// same precedence is also rendered into "( )" to keep the tree structure.
+    fn binary_to_sql(
+        &self,
+        operator: &str,
+        e1: &LogicalExprWhere,
+        e2: &LogicalExprWhere,
+        args_resolver: &mut dyn ArgsResolver,
+    ) -> error_stack::Result<String, SQLError> {
+        let precedence = self.precedence();
+        let mut left = e1.to_sql(args_resolver)?;
+        if e1.precedence() < precedence {
+            left = format!("({left})");
+        }
+        let mut right = e2.to_sql(args_resolver)?;
+        if e2.precedence() <= precedence {
+            right = format!("({right})");
+        }
+        Ok(format!("{left} {operator} {right}"))
+    }
+}
+
 impl ToSQL for LogicalExprWhere {
     fn to_sql(
         &self,
@@ -127,22 +160,13 @@ impl ToSQL for LogicalExprWhere {
     ) -> error_stack::Result<String, SQLError> {
         let sql = match self {
             LogicalExprWhere::Condition(c) => c.to_sql(args_resolver)?,
+            LogicalExprWhere::Not(e) if e.precedence() < self.precedence() => {
+                format!("NOT ({})", e.to_sql(args_resolver)?)
+            }
             LogicalExprWhere::Not(e) => format!("NOT {}", e.to_sql(args_resolver)?),
             LogicalExprWhere::Expression(e) => format!("({})", e.to_sql(args_resolver)?),
-            LogicalExprWhere::And(e1, e2) => {
-                format!(
-                    "{} AND {}",
-                    e1.to_sql(args_resolver)?,
-                    e2.to_sql(args_resolver)?
-                )
-            }
-            LogicalExprWhere::Or(e1, e2) => {
-                format!(
-                    "{} OR {}",
-                    e1.to_sql(args_resolver)?,
-                    e2.to_sql(args_resolver)?
-                )
-            }
+            LogicalExprWhere::And(e1, e2) => self.binary_to_sql("AND", e1, e2, args_resolver)?,
+            LogicalExprWhere::Or(e1, e2) => self.binary_to_sql("OR", e1, e2, args_resolver)?,
         };
         Ok(sql)
     }
@@ -255,4 +279,53 @@ mod tests {
             r#""FIELD_A" = "FIELD_B" OR "FIELD_B" = "FIELD_C""#
         );
     }
+
+    #[test]
+    fn test_precedence_matrix() {
+        let a = || FieldName::new("A").equal(1);
+        let b = || FieldName::new("B").equal(2);
+        let c = || FieldName::new("C").equal(3);
+        let matrix = vec![
+            (a().or(b()).and(c()), r#"("A" = 1 OR "B" = 2) AND "C" = 3"#),
+            (a().and(b().or(c())), r#""A" = 1 AND ("B" = 2 OR "C" = 3)"#),
+            (a().and(b()).or(c()), r#""A" = 1 AND "B" = 2 OR "C" = 3"#),
+            (a().or(b().and(c())), r#""A" = 1 OR "B" = 2 AND "C" = 3"#),
+            (a().or(b()).or(c()), r#""A" = 1 OR "B" = 2 OR "C" = 3"#),
+            (a().or(b().or(c())), r#""A" = 1 OR ("B" = 2 OR "C" = 3)"#),
+            (
+                a().and(b().and(c())),
+                r#""A" = 1 AND ("B" = 2 AND "C" = 3)"#,
+            ),
+            (a().or(b()).not(), r#"NOT ("A" = 1 OR "B" = 2)"#),
+            (a().and(b()).not(), r#"NOT ("A" = 1 AND "B" = 2)"#),
+            (a().not().and(b()), r#"NOT "A" = 1 AND "B" = 2"#),
+            (a().not().not(), r#"NOT NOT "A" = 1"#),
+        ];
+        for (log_expr, sql) in matrix {
+            assert_eq!(args_to_str(&log_expr).unwrap(), sql);
+        }
+    }
+
+    #[test]
+    fn test_precedence_with_exp() {
+        let a = || FieldName::new("A").equal(1);
+        let b = || FieldName::new("B").equal(2);
+        let c = || FieldName::new("C").equal(3);
+        let matrix = vec![
+            (a().or(b()).and(c()), a().or(b()).exp().and(c())),
+            (a().and(b().or(c())), a().and(b().or(c()).exp())),
+            (a().or(b()).not(), a().or(b()).exp().not()),
+        ];
+        for (implicit, explicit) in matrix {
+            assert_eq!(
+                args_to_str(&implicit).unwrap(),
+                args_to_str(&explicit).unwrap()
+            );
+        }
+        // Redundant expressions are kept, without doubled parentheses.
+        assert_eq!(
+            args_to_str(&a().and(b()).exp().and(c())).unwrap(),
+            r#"("A" = 1 AND "B" = 2) AND "C" = 3"#
+        );
+    }
 }
@@ -5,18 +5,24 @@ pub mod bind_name;
 pub(crate) mod case;
 pub(crate) mod combination;
 pub(crate) mod condition_where;
+pub(crate) mod cte;
+pub(crate) mod delete;
+pub(crate) mod evaluator;
 pub(crate) mod field_attribs;
 pub(crate) mod fields_attribs;
 pub(crate) mod from;
 pub(crate) mod group_by;
+pub(crate) mod insert;
 pub(crate) mod join;
 pub(crate) mod limit_offset;
 pub(crate) mod logical_expr_where;
 pub(crate) mod macros;
 pub(crate) mod order_by;
 pub(crate) mod orders;
+pub(crate) mod parser;
 pub mod query;
 pub(crate) mod select;
+pub(crate) mod simplifier;
 pub(crate) mod single_select;
 pub(crate) mod string_functions;
 pub(crate) mod table;
@@ -25,6 +31,9 @@ pub(crate) mod table_field_alias;
 pub(crate) mod table_name;
 pub(crate) mod tables_names;
 pub(crate) mod to_sql;
+pub(crate) mod type_inference;
+pub(crate) mod update;
+pub(crate) mod upsert;
 pub(crate) mod value_select;
 pub(crate) mod value_select_attrib;
 pub mod value_select_name;
@@ -33,8 +42,10 @@ pub(crate) mod value_where;
 pub(crate) mod value_where_type;
 pub(crate) mod values_select;
 pub(crate) mod values_where;
+pub mod visitor;
+pub(crate) mod window_function;
 
-pub use agg_functions::{AggFunction, AggFunctionType};
+pub use agg_functions::{AggFunction, AggFunctionType, IntoAggArgument};
 pub use alias::{Alias, IntoAlias};
 pub use arithmetic_expr::arithmetic_expr_where::{ArithmeticExprWhere, IntoArithmeticExprWhere};
 pub use case::case_condition::{CaseCondition, CaseConditionBuilder, IntoWhenCondition};
@@ -42,9 +53,15 @@ pub use case::case_value::{CaseValue, CaseValueBuilder};
 pub use case::when_condition::WhenCondition;
 pub use case::when_value::WhenValue;
 pub use combination::{Combination, CombinationType};
-pub use condition_where::{ConditionWhere, ConditionWhereOperation, IntoConditionWhere};
+pub use condition_where::{
+    CompareOperator, ConditionWhere, ConditionWhereOperation, IntoConditionWhere,
+};
+pub use cte::Cte;
+pub use delete::{Delete, DeleteBuilder};
+pub use evaluator::{Evaluate, FieldValues};
 pub use from::{FromSelect, FromType, IntoFrom, IntoFromSelect, QueryAlias};
 pub use group_by::{GroupBy, IntoGroupBy};
+pub use insert::{Insert, InsertBuilder, InsertSource};
 pub use join::{IntoJoin, Join, JoinType};
 pub use limit_offset::{IntoLimitOffset, LimitOffset};
 pub use logical_expr_where::{IntoLogicalExprWhere, LogicalExprWhere, LogicalExprWhereOps};
@@ -58,12 +75,20 @@ pub use table_field::{IntoTableField, TableField};
 pub use table_name::{IntoTableName, TableName};
 pub use tables_names::TablesNames;
 pub use to_sql::ToSQL;
+pub use type_inference::{FieldTypes, InferType, InferredType, TableTypes};
+pub use update::{Update, UpdateBuilder, UpdateSet};
+pub use upsert::{ConflictAction, OnConflict, UpsertSet, UpsertValue};
 pub use value_select::{IntoValueSelect, ValueSelect};
 pub use value_select_attrib::{IntoValuesSelectAttribs, ValueSelectAttrib, ValuesSelectAttribs};
 pub use value_where::{IntoValueWhere, ValueWhere};
 pub use value_where_type::{IntoValueWhereType, ValueWhereType};
 pub use values_select::{IntoValuesSelect, ValuesSelect};
 pub use values_where::{IntoValuesListWhere, IntoValuesWhere, ValuesListWhere, ValuesWhere};
+pub use visitor::{Visitor, VisitorMut};
+pub use window_function::{
+    FrameBound, FrameUnits, NamedWindow, Over, WindowFrame, WindowFunction, WindowFunctionType,
+    WindowSpec,
+};
 
 // TODO: re export other types here
 // TODO: change visibility to pub(crate) -> fix unit test that will break
@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 /// ```
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct OrderBy {
-    table_field: TableField,
+    pub(crate) table_field: TableField,
     #[serde(rename = "type")]
     order_by_type: OrderByType,
 }
@@ -5,7 +5,7 @@ use serde::{Deserialize, Serialize};
 // TODO: add comment
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct OrdersBy {
-    orders_by: Vec<OrderBy>,
+    pub(crate) orders_by: Vec<OrderBy>,
 }
 
 impl OrdersBy {
@@ -0,0 +1,931 @@
+use super::{
+    is_reserved,
+    lexer::{Token, TokenKind},
+    Parser,
+};
+use crate::{
+    selections::{
+        AggFunction, AggFunctionType, Alias, ArithmeticExprWhere, CaseConditionBuilder, CaseValue,
+        CompareOperator, ConditionWhere, FrameBound, FrameUnits, IntoValueWhere, IntoValuesWhere,
+        LogicalExprWhere, SingleQuery, StringFunction, Table, TableField, TableName, ValueWhere,
+        ValuesListWhere, WhenCondition, WhenValue, WindowFrame, WindowFunction, WindowFunctionType,
+        WindowSpec,
+    },
+    FieldName, NullableValue, ParseError,
+};
+use chrono::{NaiveDate, NaiveDateTime};
+use error_stack::Report;
+use rust_decimal::Decimal;
+use std::str::FromStr;
+
+impl LogicalExprWhere {
+    /// Parse a logical expression, like the ones used in `WHERE` clause.
+    /// Identifiers can be quoted or not, parentheses only group operations.
+    /// Integer numbers are `Int32` (`Int64` when they don't fit), numbers with decimal point
+    /// are `Decimal` and `NULL` is a `String` null.
+    /// The SQL doesn't keep the type of literals, parsing it only results in the original
+    /// expression when its literals follow these rules: `5i64` and `dec!(5)` are rendered as `5`
+    /// and read back as `Int32`.
+    /// # Example
+    /// ```
+    /// # use voxi_core::selections::LogicalExprWhere;
+    /// # use voxi_core::resolvers::args_resolver_string::args_to_str;
+    /// let expr = LogicalExprWhere::parse("price < 1000 - discount and name in ('PS5','XBOX')").unwrap();
+    /// assert_eq!(
+    ///     args_to_str(&expr).unwrap(),
+    ///     r#""price" < 1000 - "discount" AND "name" IN ('PS5','XBOX')"#
+    /// );
+    /// let error = LogicalExprWhere::parse("price <").unwrap_err();
+    /// assert_eq!(error.current_context().to_string(), "expected value, found end of text at line 1, column 8");
+    /// ```
+    pub fn parse(text: &str) -> error_stack::Result<LogicalExprWhere, ParseError> {
+        let mut parser = Parser::new(text)?;
+        let expr = parser.logical_expr()?;
+        parser.expect_end()?;
+        Ok(expr)
+    }
+}
+
+impl ValueWhere {
+    /// Parse a value expression, like a computed column.
+    /// See `LogicalExprWhere::parse` for the accepted syntax.
+    /// # Example
+    /// ```
+    /// # use voxi_core::selections::{ArithmeticExprWhere, TableField, ValueWhere, IntoValueWhere};
+    /// let value = ValueWhere::parse("PRODUCT.price * qty").unwrap();
+    /// let expected = ArithmeticExprWhere::multiply(TableField::new("PRODUCT.price"), TableField::new("qty"));
+    /// assert_eq!(value, expected.into_value_where());
+    /// ```
+    pub fn parse(text: &str) -> error_stack::Result<ValueWhere, ParseError> {
+        let mut parser = Parser::new(text)?;
+        let value = parser.value_expr()?;
+        parser.expect_end()?;
+        Ok(value)
+    }
+}
+
+/// Operand of an arithmetic operation, expressions are used directly.
+fn to_arith(value: ValueWhere) -> ArithmeticExprWhere {
+    match value {
+        ValueWhere::Expression(expression) => expression,
+        value => ArithmeticExprWhere::ValueWhere(Box::new(value)),
+    }
+}
+
+fn number_value(text: &str) -> Option<NullableValue> {
+    if text.contains(['.', 'e', 'E']) {
+        let decimal = Decimal::from_str(text).or_else(|_| Decimal::from_scientific(text));
+        return decimal.ok().map(|v| NullableValue::Decimal(Some(v)));
+    }
+    if let Ok(v) = text.parse::<i32>() {
+        return Some(NullableValue::Int32(Some(v)));
+    }
+    if let Ok(v) = text.parse::<i64>() {
+        return Some(NullableValue::Int64(Some(v)));
+    }
+    Decimal::from_str(text)
+        .ok()
+        .map(|v| NullableValue::Decimal(Some(v)))
+}
+
+/// Aggregate function type from its name, including the names used by each dialect.
+fn agg_function_type(name: &str) -> Option<AggFunctionType> {
+    let agg_type = match name {
+        "MIN" => AggFunctionType::Min,
+        "MAX" => AggFunctionType::Max,
+        "AVG" => AggFunctionType::Avg,
+        "SUM" => AggFunctionType::Sum,
+        "COUNT" => AggFunctionType::Count,
+        "STRING_AGG" | "GROUP_CONCAT" => AggFunctionType::StringAgg(String::new()),
+        "ARRAY_AGG" => AggFunctionType::ArrayAgg,
+        "BOOL_AND" => AggFunctionType::BoolAnd,
+        "BOOL_OR" => AggFunctionType::BoolOr,
+        "STDDEV" | "STDEV" => AggFunctionType::StdDev,
+        "VARIANCE" | "VAR" => AggFunctionType::Variance,
+        _ => return None,
+    };
+    Some(agg_type)
+}
+
+fn compare_operator(symbol: &str) -> CompareOperator {
+    match symbol {
+        "=" => CompareOperator::Eq,
+        "<>" | "!=" => CompareOperator::Df,
+        "<" => CompareOperator::Ls,
+        ">" => CompareOperator::Gt,
+        "<=" => CompareOperator::Le,
+        _ => CompareOperator::Ge,
+    }
+}
+
+impl Parser {
+    pub(crate) fn is_sub_query_at(&self, offset: usize) -> bool {
+        self.is_keyword_at(offset, "SELECT") || self.is_keyword_at(offset, "WITH")
+    }
+
+    pub(crate) fn logical_expr(&mut self) -> error_stack::Result<LogicalExprWhere, ParseError> {
+        let mut expr = self.and_expr()?;
+        while self.eat_keyword("OR") {
+            expr = LogicalExprWhere::Or(Box::new(expr), Box::new(self.and_expr()?));
+        }
+        Ok(expr)
+    }
+
+    fn and_expr(&mut self) -> error_stack::Result<LogicalExprWhere, ParseError> {
+        let mut expr = self.not_expr()?;
+        while self.eat_keyword("AND") {
+            expr = LogicalExprWhere::And(Box::new(expr), Box::new(self.not_expr()?));
+        }
+        Ok(expr)
+    }
+
+    fn not_expr(&mut self) -> error_stack::Result<LogicalExprWhere, ParseError> {
+        if self.eat_keyword("NOT") {
+            return Ok(LogicalExprWhere::Not(Box::new(self.not_expr()?)));
+        }
+        self.predicate()
+    }
+
+    /// Return `true` when the current token continues a value, so a `( )` before it encloses
+    /// a value and not a logical expression.
+    fn continues_value(&self) -> bool {
+        const SYMBOLS: &[&str] = &[
+            "+", "-", "*", "/", "||", "=", "<>", "!=", "<", ">", "<=", ">=", "<=>",
+        ];
+        const KEYWORDS: &[&str] = &["IS", "IN", "LIKE", "ILIKE", "BETWEEN"];
+        SYMBOLS.iter().any(|s| self.is_symbol(s))
+            || KEYWORDS.iter().any(|k| self.is_keyword(k))
+            || (self.is_keyword("NOT") && KEYWORDS[1..].iter().any(|k| self.is_keyword_at(1, k)))
+    }
+
+    fn group(&mut self) -> error_stack::Result<LogicalExprWhere, ParseError> {
+        self.expect_symbol("(")?;
+        let expr = self.logical_expr()?;
+        self.expect_symbol(")")?;
+        Ok(expr)
+    }
+
+    fn predicate(&mut self) -> error_stack::Result<LogicalExprWhere, ParseError> {
+        if self.eat_keyword("EXISTS") {
+            let query = self.sub_query()?;
+            let condition = ConditionWhere::Exists(Box::new(query));
+            return Ok(LogicalExprWhere::Condition(Box::new(condition)));
+        }
+        let start = self.pos;
+        let mut group_error = None;
+        if self.is_symbol("(") && !self.is_sub_query_at(1) && !self.value_groups.contains(&start) {
+            match self.group() {
+                Ok(expr) if !self.continues_value() => return Ok(expr),
+                Ok(_) => {}
+                Err(error) => group_error = Some(error),
+            }
+            self.pos = start;
+            self.value_groups.insert(start);
+        }
+        let condition = self.value_expr().and_then(|value| self.condition(value));
+        match (condition, group_error) {
+            (Ok(condition), _) => Ok(LogicalExprWhere::Condition(Box::new(condition))),
+            // Report the error found further in the text.
+            (Err(error), Some(group_error)) => {
+                let position =
+                    |e: &Report<ParseError>| (e.current_context().line, e.current_context().column);
+                if position(&group_error) > position(&error) {
+                    Err(group_error)
+                } else {
+                    Err(error)
+                }
+            }
+            (Err(error), None) => Err(error),
+        }
+    }
+
+    fn condition(&mut self, value: ValueWhere) -> error_stack::Result<ConditionWhere, ParseError> {
+        type Compare = fn(ValueWhere, ValueWhere) -> ConditionWhere;
+        let compares: [(&str, Compare); 7] = [
+            ("=", ConditionWhere::ConditionEq),
+            ("<>", ConditionWhere::ConditionDf),
+            ("!=", ConditionWhere::ConditionDf),
+            ("<", ConditionWhere::ConditionLs),
+            (">", ConditionWhere::ConditionGt),
+            ("<=", ConditionWhere::ConditionLe),
+            (">=", ConditionWhere::ConditionGe),
+        ];
+        if let Some((symbol, compare)) = compares.iter().find(|(s, _)| self.is_symbol(s)) {
+            self.next();
+            let operator = compare_operator(symbol);
+            if self.eat_keyword("ANY") {
+                let query = Box::new(self.sub_query()?);
+                return Ok(ConditionWhere::ConditionAny(value, operator, query));
+            }
+            if self.eat_keyword("ALL") {
+                let query = Box::new(self.sub_query()?);
+                return Ok(ConditionWhere::ConditionAll(value, operator, query));
+            }
+            return Ok(compare(value, self.value_expr()?));
+        }
+        // MySQL null-safe equality.
+        if self.eat_symbol("<=>") {
+            return Ok(ConditionWhere::ConditionNotDistinct(
+                value,
+                self.value_expr()?,
+            ));
+        }
+        if self.eat_keyword("IS") {
+            let not = self.eat_keyword("NOT");
+            if self.eat_keyword("NULL") {
+                return Ok(match not {
+                    true => ConditionWhere::ConditionNotNull(value),
+                    false => ConditionWhere::ConditionNull(value),
+                });
+            }
+            let distinct = self.eat_keyword("DISTINCT");
+            if distinct {
+                self.expect_keyword("FROM")?;
+            }
+            // Without `DISTINCT FROM` it is the SQLite null-safe comparison, `IS` meaning equality.
+            let other = self.value_expr()?;
+            return Ok(match not == distinct {
+                true => ConditionWhere::ConditionNotDistinct(value, other),
+                false => ConditionWhere::ConditionDistinct(value, other),
+            });
+        }
+        let not = self.is_keyword("NOT");
+        if not {
+            let not_token = self.next();
+            if self.is_keyword("ILIKE") {
+                return Err(self.error_at(&not_token, "`NOT ILIKE` is not supported"));
+            }
+        }
+        if self.eat_keyword("LIKE") {
+            let pattern = self.value_expr()?;
+            return Ok(match not {
+                true => ConditionWhere::ConditionNotLk(value, pattern),
+                false => ConditionWhere::ConditionLk(value, pattern),
+            });
+        }
+        if !not && self.eat_keyword("ILIKE") {
+            return Ok(ConditionWhere::ConditionILk(value, self.value_expr()?));
+        }
+        if self.eat_keyword("IN") {
+            let list = self.values_list()?;
+            return Ok(match not {
+                true => ConditionWhere::ConditionNotIn(value, list),
+                false => ConditionWhere::ConditionIn(value, list),
+            });
+        }
+        if self.eat_keyword("BETWEEN") {
+            let low = self.value_expr()?;
+            self.expect_keyword("AND")?;
+            let high = self.value_expr()?;
+            return Ok(match not {
+                true => ConditionWhere::ConditionNotBetween(value, low, high),
+                false => ConditionWhere::ConditionBetween(value, low, high),
+            });
+        }
+        if not {
+            return Err(self.expected("`LIKE`, `IN` or `BETWEEN`"));
+        }
+        Ok(ConditionWhere::Expression(to_arith(value)))
+    }
+
+    fn values_list(&mut self) -> error_stack::Result<ValuesListWhere, ParseError> {
+        if self.is_symbol("(") && self.is_sub_query_at(1) {
+            return Ok(ValuesListWhere::Select(Box::new(self.sub_query()?)));
+        }
+        self.expect_symbol("(")?;
+        let values = self.values()?;
+        self.expect_symbol(")")?;
+        Ok(ValuesListWhere::List(values.into_values_where()))
+    }
+
+    /// Values separated by comma.
+    pub(crate) fn values(&mut self) -> error_stack::Result<Vec<ValueWhere>, ParseError> {
+        let mut values = vec![self.value_expr()?];
+        while self.eat_symbol(",") {
+            values.push(self.value_expr()?);
+        }
+        Ok(values)
+    }
+
+    /// Value, where `||` concatenates the operands.
+    pub(crate) fn value_expr(&mut self) -> error_stack::Result<ValueWhere, ParseError> {
+        let value = self.additive()?;
+        if !self.is_symbol("||") {
+            return Ok(value);
+        }
+        let mut values = vec![value];
+        while self.eat_symbol("||") {
+            values.push(self.additive()?);
+        }
+        Ok(StringFunction::concat(values).into_value_where())
+    }
+
+    fn additive(&mut self) -> error_stack::Result<ValueWhere, ParseError> {
+        let mut value = self.term()?;
+        loop {
+            let operation = if self.eat_symbol("+") {
+                ArithmeticExprWhere::Add
+            } else if self.eat_symbol("-") {
+                ArithmeticExprWhere::Subtract
+            } else {
+                return Ok(value);
+            };
+            let right = to_arith(self.term()?);
+            value = ValueWhere::Expression(operation(Box::new(to_arith(value)), Box::new(right)));
+        }
+    }
+
+    fn term(&mut self) -> error_stack::Result<ValueWhere, ParseError> {
+        let mut value = self.unary()?;
+        loop {
+            let operation = if self.eat_symbol("*") {
+                ArithmeticExprWhere::Multiply
+            } else if self.eat_symbol("/") {
+                ArithmeticExprWhere::Divide
+            } else {
+                return Ok(value);
+            };
+            let right = to_arith(self.unary()?);
+            value = ValueWhere::Expression(operation(Box::new(to_arith(value)), Box::new(right)));
+        }
+    }
+
+    fn unary(&mut self) -> error_stack::Result<ValueWhere, ParseError> {
+        if !self.is_symbol("-") {
+            return self.primary();
+        }
+        let minus = self.next();
+        match &self.peek().kind {
+            TokenKind::Number(number) => {
+                let number = format!("-{number}");
+                let value = self.literal_number(&number)?;
+                Ok(ValueWhere::LiteralValue(value))
+            }
+            _ => Err(self.error_at(&minus, "unary minus is only supported before numbers")),
+        }
+    }
+
+    fn literal_number(&mut self, number: &str) -> error_stack::Result<NullableValue, ParseError> {
+        let token = self.next();
+        number_value(number)
+            .ok_or_else(|| self.error_at(&token, format!("invalid number `{number}`")))
+    }
+
+    /// Read a literal of a type written before a string, like `DATE '2022-01-31'`.
+    fn typed_literal(&mut self) -> error_stack::Result<NullableValue, ParseError> {
+        let keyword = self.next();
+        let token = self.next();
+        let TokenKind::String(text) = &token.kind else {
+            return Err(self.error_at(&token, "expected string"));
+        };
+        self.date_value(&keyword, &token, text)
+    }
+
+    /// Read the MSSQL date literal `CAST('2022-01-31' AS DATE)`, the only cast supported.
+    fn cast_literal(&mut self) -> error_stack::Result<NullableValue, ParseError> {
+        let cast = self.next();
+        self.expect_symbol("(")?;
+        let token = self.next();
+        let TokenKind::String(text) = &token.kind else {
+            return Err(self.error_at(&cast, "`CAST` is only supported for date literals"));
+        };
+        self.expect_keyword("AS")?;
+        let keyword = self.next();
+        let is_type =
+            |t: &str| matches!(&keyword.kind, TokenKind::Word(w) if w.eq_ignore_ascii_case(t));
+        if !["DATE", "DATETIME", "DATETIME2", "TIMESTAMP"]
+            .iter()
+            .any(|t| is_type(t))
+        {
+            return Err(self.error_at(&cast, "`CAST` is only supported for date literals"));
+        }
+        let value = self.date_value(&keyword, &token, text)?;
+        self.expect_symbol(")")?;
+        Ok(value)
+    }
+
+    fn date_value(
+        &self,
+        keyword: &Token,
+        token: &Token,
+        text: &str,
+    ) -> error_stack::Result<NullableValue, ParseError> {
+        let value = if self.is_date(&keyword.kind) {
+            NaiveDate::parse_from_str(text, "%Y-%m-%d")
+                .ok()
+                .map(|v| NullableValue::Date(Some(v)))
+        } else {
+            NaiveDateTime::parse_from_str(text, "%Y-%m-%d %H:%M:%S%.f")
+                .or_else(|_| NaiveDateTime::parse_from_str(text, "%Y-%m-%dT%H:%M:%S%.f"))
+                .ok()
+                .map(|v| NullableValue::DateTime(Some(v)))
+        };
+        value.ok_or_else(|| self.error_at(token, format!("invalid {} '{text}'", keyword.kind)))
+    }
+
+    fn is_date(&self, kind: &TokenKind) -> bool {
+        matches!(kind, TokenKind::Word(word) if word.eq_ignore_ascii_case("DATE"))
+    }
+
+    fn primary(&mut self) -> error_stack::Result<ValueWhere, ParseError> {
+        let token = self.peek().clone();
+        let value = match &token.kind {
+            TokenKind::Number(number) => self.literal_number(number)?,
+            TokenKind::String(text) => {
+                self.next();
+                NullableValue::String(Some(text.clone()))
+            }
+            TokenKind::Bind(name) => {
+                self.next();
+                return Ok(ValueWhere::bind(name.as_str()));
+            }
+            TokenKind::Symbol("(") => {
+                if self.is_sub_query_at(1) {
+                    let query = self.sub_query()?;
+                    if query.columns.len() != 1 {
+                        return Err(
+                            self.error_at(&token, "sub-query used as value must have one column")
+                        );
+                    }
+                    return Ok(SingleQuery::new(query).into_value_where());
+                }
+                self.next();
+                let value = self.value_expr()?;
+                self.expect_symbol(")")?;
+                return Ok(value);
+            }
+            TokenKind::QuotedIdent(_) => return self.table_field().map(ValueWhere::TableField),
+            TokenKind::Word(word) => {
+                let upper = word.to_uppercase();
+                match upper.as_str() {
+                    "TRUE" | "FALSE" => {
+                        self.next();
+                        NullableValue::Boolean(Some(upper == "TRUE"))
+                    }
+                    "NULL" => {
+                        self.next();
+                        NullableValue::String(None)
+                    }
+                    "DATE" | "TIMESTAMP"
+                        if matches!(self.peek_at(1).kind, TokenKind::String(_)) =>
+                    {
+                        self.typed_literal()?
+                    }
+                    "CAST" if self.is_symbol_at(1, "(") => self.cast_literal()?,
+                    "CASE" => return self.case(),
+                    _ if self.is_symbol_at(1, "(") && !is_reserved(word) => {
+                        return self.function(upper)
+                    }
+                    _ => return self.table_field().map(ValueWhere::TableField),
+                }
+            }
+            _ => return Err(self.expected("value")),
+        };
+        Ok(ValueWhere::LiteralValue(value))
+    }
+
+    /// Read a field, with optional table, like `PRODUCT.price`.
+    pub(crate) fn table_field(&mut self) -> error_stack::Result<TableField, ParseError> {
+        if !matches!(
+            self.peek().kind,
+            TokenKind::QuotedIdent(_) | TokenKind::Word(_)
+        ) {
+            return Err(self.expected("value"));
+        }
+        let name = self.identifier()?;
+        if !self.eat_symbol(".") {
+            return Ok(TableField {
+                table: None,
+                field_name: FieldName(name),
+            });
+        }
+        let field_name = match self.eat_symbol("*") {
+            true => "*".to_string(),
+            false => self.identifier()?,
+        };
+        Ok(TableField {
+            table: Some(Table {
+                name: TableName(name),
+                alias: None,
+            }),
+            field_name: FieldName(field_name),
+        })
+    }
+
+    fn case(&mut self) -> error_stack::Result<ValueWhere, ParseError> {
+        self.expect_keyword("CASE")?;
+        if self.is_keyword("WHEN") {
+            let mut builder = CaseConditionBuilder::new();
+            while self.eat_keyword("WHEN") {
+                let condition = self.logical_expr()?;
+                self.expect_keyword("THEN")?;
+                builder = builder.when_value(WhenCondition::new(condition, self.value_expr()?));
+            }
+            if self.eat_keyword("ELSE") {
+                builder = builder.else_case(self.value_expr()?);
+            }
+            self.expect_keyword("END")?;
+            return Ok(ValueWhere::CaseCondition(Box::new(builder.build())));
+        }
+        let input = self.value_expr()?;
+        let mut whens = Vec::new();
+        while self.eat_keyword("WHEN") {
+            let value = self.value_expr()?;
+            self.expect_keyword("THEN")?;
+            whens.push(WhenValue::new(value, self.value_expr()?));
+        }
+        if whens.is_empty() {
+            return Err(self.expected("`WHEN`"));
+        }
+        let else_case = match self.eat_keyword("ELSE") {
+            true => Some(self.value_expr()?),
+            false => None,
+        };
+        self.expect_keyword("END")?;
+        let case = CaseValue::new(input, whens, else_case);
+        Ok(ValueWhere::CaseValue(Box::new(case)))
+    }
+
+    /// Read a non negative integer number.
+    pub(crate) fn integer<T: FromStr>(&mut self) -> error_stack::Result<T, ParseError> {
+        let token = self.next();
+        match &token.kind {
+            TokenKind::Number(number) => number
+                .parse()
+                .map_err(|_| self.error_at(&token, format!("invalid number `{number}`"))),
+            kind => Err(self.error_at(&token, format!("expected number, found {kind}"))),
+        }
+    }
+
+    fn function(&mut self, name: String) -> error_stack::Result<ValueWhere, ParseError> {
+        let name_token = self.next();
+        self.expect_symbol("(")?;
+        if let Some(agg_type) = agg_function_type(&name) {
+            return self.agg_function(agg_type);
+        }
+        if let Some(function) = self.window_only_function(&name)? {
+            return self.over(function);
+        }
+        let function = match name.as_str() {
+            "UPPER" => StringFunction::Upper(self.value_expr()?),
+            "LOWER" => StringFunction::Lower(self.value_expr()?),
+            "SUBSTRING" | "SUBSTR" => {
+                let value = self.value_expr()?;
+                let (start, length) = if self.eat_keyword("FROM") {
+                    let start = self.integer()?;
+                    self.expect_keyword("FOR")?;
+                    (start, self.integer()?)
+                } else {
+                    self.expect_symbol(",")?;
+                    let start = self.integer()?;
+                    self.expect_symbol(",")?;
+                    (start, self.integer()?)
+                };
+                StringFunction::Substring(value, start, length)
+            }
+            "REPLACE" => {
+                let value = self.value_expr()?;
+                self.expect_symbol(",")?;
+                let old = self.value_expr()?;
+                self.expect_symbol(",")?;
+                StringFunction::Replace(value, old, self.value_expr()?)
+            }
+            "CONCAT" => StringFunction::concat(self.values()?),
+            _ => return Err(self.error_at(&name_token, format!("unknown function `{name}`"))),
+        };
+        self.expect_symbol(")")?;
+        Ok(function.into_value_where())
+    }
+
+    fn agg_function(
+        &mut self,
+        agg_type: AggFunctionType,
+    ) -> error_stack::Result<ValueWhere, ParseError> {
+        let distinct = self.eat_keyword("DISTINCT");
+        let value = if self.eat_symbol("*") {
+            TableField::new("*").into_value_where()
+        } else {
+            self.value_expr()?
+        };
+        let mut function = match agg_type {
+            AggFunctionType::StringAgg(_) => {
+                // MySQL `GROUP_CONCAT` separates the value with `SEPARATOR` instead of comma.
+                if !self.eat_keyword("SEPARATOR") {
+                    self.expect_symbol(",")?;
+                }
+                let token = self.next();
+                let TokenKind::String(separator) = token.kind else {
+                    return Err(self.error_at(&token, "expected string separator"));
+                };
+                AggFunction::string_agg(value, separator)
+            }
+            agg_type => AggFunction::new(agg_type, value),
+        };
+        self.expect_symbol(")")?;
+        if distinct {
+            function = function.distinct();
+        }
+        if self.eat_keyword("FILTER") {
+            self.expect_symbol("(")?;
+            self.expect_keyword("WHERE")?;
+            function = function.filter(self.logical_expr()?);
+            self.expect_symbol(")")?;
+        }
+        if self.is_keyword("OVER") {
+            return self.over(WindowFunction::aggregate(function));
+        }
+        Ok(function.into_value_where())
+    }
+
+    /// Read the arguments of a function that is only valid with `OVER`, like `RANK()`.
+    fn window_only_function(
+        &mut self,
+        name: &str,
+    ) -> error_stack::Result<Option<WindowFunction>, ParseError> {
+        let function = match name {
+            "ROW_NUMBER" => WindowFunction::row_number(),
+            "RANK" => WindowFunction::rank(),
+            "DENSE_RANK" => WindowFunction::dense_rank(),
+            "NTILE" => WindowFunction::ntile(self.integer()?),
+            "FIRST_VALUE" => WindowFunction::first_value(self.value_expr()?),
+            "LAST_VALUE" => WindowFunction::last_value(self.value_expr()?),
+            "LAG" | "LEAD" => {
+                let value = self.value_expr()?;
+                let offset = match self.eat_symbol(",") {
+                    true => self.integer()?,
+                    false => 1,
+                };
+                let default = match self.eat_symbol(",") {
+                    true => Some(self.value_expr()?),
+                    false => None,
+                };
+                let function = match name {
+                    "LAG" => WindowFunctionType::Lag {
+                        value,
+                        offset,
+                        default,
+                    },
+                    _ => WindowFunctionType::Lead {
+                        value,
+                        offset,
+                        default,
+                    },
+                };
+                WindowFunction::new(function)
+            }
+            _ => return Ok(None),
+        };
+        self.expect_symbol(")")?;
+        if !self.is_keyword("OVER") {
+            return Err(self.expected("`OVER`"));
+        }
+        Ok(Some(function))
+    }
+
+    /// Read the window of a function, `OVER (<window>)` or `OVER <name>`.
+    fn over(&mut self, function: WindowFunction) -> error_stack::Result<ValueWhere, ParseError> {
+        self.expect_keyword("OVER")?;
+        if !self.eat_symbol("(") {
+            let name = self.identifier()?;
+            return Ok(function
+                .over_named(Alias { alias: name })
+                .into_value_where());
+        }
+        let spec = self.window_spec()?;
+        self.expect_symbol(")")?;
+        Ok(function.over(spec).into_value_where())
+    }
+
+    /// Read the content of a window, without the enclosing parentheses.
+    pub(crate) fn window_spec(&mut self) -> error_stack::Result<WindowSpec, ParseError> {
+        let mut spec = WindowSpec::new();
+        if !matches!(self.peek().kind, TokenKind::Symbol(")"))
+            && !["PARTITION", "ORDER", "ROWS", "RANGE"]
+                .iter()
+                .any(|k| self.is_keyword(k))
+        {
+            return Err(self.error_at(
+                self.peek(),
+                "windows extending a named window are not supported",
+            ));
+        }
+        if self.eat_keyword("PARTITION") {
+            self.expect_keyword("BY")?;
+            spec.partition_by = self.fields_list("PARTITION BY")?;
+        }
+        if self.eat_keyword("ORDER") {
+            self.expect_keyword("BY")?;
+            spec.orders_by = self.orders_by()?;
+        }
+        let units = if self.eat_keyword("ROWS") {
+            FrameUnits::Rows
+        } else if self.eat_keyword("RANGE") {
+            FrameUnits::Range
+        } else {
+            return Ok(spec);
+        };
+        let frame = if self.eat_keyword("BETWEEN") {
+            let start = self.frame_bound()?;
+            self.expect_keyword("AND")?;
+            WindowFrame::between(units, start, self.frame_bound()?)
+        } else {
+            WindowFrame::new(units, self.frame_bound()?)
+        };
+        Ok(spec.frame(frame))
+    }
+
+    fn frame_bound(&mut self) -> error_stack::Result<FrameBound, ParseError> {
+        if self.eat_keyword("UNBOUNDED") {
+            if self.eat_keyword("PRECEDING") {
+                return Ok(FrameBound::UnboundedPreceding);
+            }
+            self.expect_keyword("FOLLOWING")?;
+            return Ok(FrameBound::UnboundedFollowing);
+        }
+        if self.eat_keyword("CURRENT") {
+            self.expect_keyword("ROW")?;
+            return Ok(FrameBound::CurrentRow);
+        }
+        if !matches!(self.peek().kind, TokenKind::Number(_)) {
+            return Err(self.expected("frame bound"));
+        }
+        let rows = self.integer()?;
+        if self.eat_keyword("PRECEDING") {
+            return Ok(FrameBound::Preceding(rows));
+        }
+        self.expect_keyword("FOLLOWING")?;
+        Ok(FrameBound::Following(rows))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        resolvers::args_resolver_string::args_to_str,
+        selections::{
+            AggFunction, ArithmeticExprWhere, CaseConditionBuilder, CaseValueBuilder,
+            ConditionWhere, ConditionWhereOperation, IntoLogicalExprWhere, IntoValueWhere,
+            LogicalExprWhere, LogicalExprWhereOps, StringFunction, TableField, ValueWhere,
+            WhenCondition, WhenValue,
+        },
+        NullableValue, ParseError,
+    };
+    use chrono::NaiveDate;
+    use pretty_assertions::assert_eq;
+    use rust_decimal_macros::dec;
+
+    fn field(name: &str) -> TableField {
+        TableField::new(name)
+    }
+
+    fn round_trip(expr: LogicalExprWhere) {
+        let sql = args_to_str(&expr).unwrap();
+        let parsed = LogicalExprWhere::parse(&sql).unwrap_or_else(|e| panic!("{sql}: {e:?}"));
+        assert_eq!(parsed, expr, "{sql}");
+    }
+
+    #[test]
+    fn test_round_trip_conditions() {
+        let date = NaiveDate::from_ymd_opt(2022, 1, 31).unwrap();
+        let expressions = vec![
+            field("A").equal(1).into_logical_expr_where(),
+            field("T.A").diff("x'y").and(field("B").greater(dec!(1.50))),
+            field("A")
+                .less(-3)
+                .or(field("B").greater_or_equal(i64::MAX)),
+            field("A").less_or_equal(date).not(),
+            field("A").null().and(field("B").not_null()),
+            field("A").like("X%").or(field("A").not_like("%Y")),
+            field("A").ilike("x%").into_logical_expr_where(),
+            field("A")
+                .include(vec![1, 2])
+                .and(field("B").not_include(vec!["a", "b"])),
+            field("A")
+                .between(1, 10)
+                .or(field("B").not_between(dec!(0.5), dec!(1.5))),
+            field("A")
+                .distinct_from(field("B"))
+                .and(field("A").not_distinct_from(1)),
+            ConditionWhere::expression(field("ACTIVE")).and(field("B").equal(true)),
+            field("A")
+                .equal(1)
+                .or(field("B").equal(2))
+                .and(field("C").equal(3)),
+            field("A")
+                .equal(1)
+                .and(field("B").equal(2).or(field("C").equal(3))),
+            field("A")
+                .equal(1)
+                .or(field("B").equal(2))
+                .not()
+                .and(field("C").equal(3)),
+        ];
+        for expr in expressions {
+            round_trip(expr);
+        }
+        // literals are read back by their text, not by their original type
+        for value in [5i64.into_value_where(), dec!(5).into_value_where()] {
+            let sql = args_to_str(&field("A").equal(value)).unwrap();
+            assert_eq!(sql, r#""A" = 5"#);
+            let parsed = LogicalExprWhere::parse(&sql).unwrap();
+            assert_eq!(parsed, field("A").equal(5i32).into_logical_expr_where());
+        }
+    }
+
+    #[test]
+    fn test_round_trip_values() {
+        let case = CaseConditionBuilder::new()
+            .when_value(WhenCondition::new(field("A").greater(1), "BIG"))
+            .else_case("SMALL")
+            .build();
+        let case_value = CaseValueBuilder::new(field("S"))
+            .when_value(WhenValue::new(1, "One"))
+            .build();
+        let values = vec![
+            ArithmeticExprWhere::multiply(
+                ArithmeticExprWhere::add(field("A"), field("B")),
+                field("C"),
+            )
+            .into_value_where(),
+            ArithmeticExprWhere::subtract(
+                field("A"),
+                ArithmeticExprWhere::subtract(field("B"), field("C")),
+            )
+            .into_value_where(),
+            ValueWhere::CaseCondition(Box::new(case)),
+            ValueWhere::CaseValue(Box::new(case_value)),
+            StringFunction::upper(field("A")).into_value_where(),
+            StringFunction::substring(field("A"), 2, 3).into_value_where(),
+            StringFunction::replace(field("A"), "a", "b").into_value_where(),
+            StringFunction::concat(vec![field("A").into_value_where(), "-".into_value_where()])
+                .into_value_where(),
+            AggFunction::count("*").into_value_where(),
+            AggFunction::sum(field("A")).distinct().into_value_where(),
+            AggFunction::string_agg(field("A"), ", ")
+                .filter(field("B").equal(1))
+                .into_value_where(),
+        ];
+        for value in values {
+            round_trip(field("X").equal(value).into_logical_expr_where());
+        }
+        let value = ArithmeticExprWhere::add(field("A"), 5i64).into_value_where();
+        let parsed = ValueWhere::parse(&args_to_str(&value).unwrap()).unwrap();
+        assert_eq!(
+            parsed,
+            ArithmeticExprWhere::add(field("A"), 5i32.into_value_where()).into_value_where()
+        );
+    }
+
+    #[test]
+    fn test_parse_user_text() {
+        let expr = LogicalExprWhere::parse(
+            "(PRODUCT.price * qty) >= 10 and not (name like 'X%' or code = :code)",
+        )
+        .unwrap();
+        let expected = ConditionWhere::ge(
+            ArithmeticExprWhere::multiply(field("PRODUCT.price"), field("qty")),
+            10,
+        )
+        .and(
+            field("name")
+                .like("X%")
+                .or(field("code").equal(ValueWhere::bind("code")))
+                .not(),
+        );
+        assert_eq!(expr, expected);
+        let value = ValueWhere::parse("lower(name) % 2");
+        assert!(value.is_err());
+        let value = ValueWhere::parse("NULL").unwrap();
+        assert_eq!(value, ValueWhere::LiteralValue(NullableValue::String(None)));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |text: &str| {
+            LogicalExprWhere::parse(text)
+                .unwrap_err()
+                .current_context()
+                .clone()
+        };
+        assert_eq!(
+            error("A = 1 AND\n  B IN (1, 2"),
+            ParseError::new(2, 13, "expected `)`, found end of text")
+        );
+        assert_eq!(
+            error("A = 1 B"),
+            ParseError::new(1, 7, "expected end of text, found `B`")
+        );
+        assert_eq!(
+            error("(A = 1 OR B = ) AND C = 1"),
+            ParseError::new(1, 15, "expected value, found `)`")
+        );
+        assert_eq!(
+            error("SQRT(A) > 1"),
+            ParseError::new(1, 1, "unknown function `SQRT`")
+        );
+        assert_eq!(
+            error("A IN (SELECT 1)"),
+            ParseError::new(1, 15, "expected `FROM`, found `)`")
+        );
+    }
+}
@@ -0,0 +1,297 @@
+use crate::ParseError;
+use error_stack::Report;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum TokenKind {
+    /// Unquoted identifier or keyword.
+    Word(String),
+    /// Identifier delimited by `"`, `` ` `` or `[ ]`.
+    QuotedIdent(String),
+    Number(String),
+    String(String),
+    /// Bind parameter name, written as `:name`.
+    Bind(String),
+    Symbol(&'static str),
+    Eof,
+}
+
+impl fmt::Display for TokenKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TokenKind::Word(word) => write!(f, "`{word}`"),
+            TokenKind::QuotedIdent(ident) => write!(f, "identifier `{ident}`"),
+            TokenKind::Number(number) => write!(f, "number `{number}`"),
+            TokenKind::String(string) => write!(f, "string '{string}'"),
+            TokenKind::Bind(name) => write!(f, "bind `:{name}`"),
+            TokenKind::Symbol(symbol) => write!(f, "`{symbol}`"),
+            TokenKind::Eof => write!(f, "end of text"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Token {
+    pub kind: TokenKind,
+    pub line: usize,
+    pub column: usize,
+}
+
+/// Symbols ordered so that longer ones are tried first.
+const SYMBOLS: &[&str] = &[
+    "<=>", "<>", "!=", "<=", ">=", "||", "(", ")", ",", ".", "+", "-", "*", "/", "=", "<", ">", ";",
+];
+
+struct Lexer {
+    chars: Vec<char>,
+    /// Backslash escapes characters inside strings, like MySQL does.
+    backslash_escapes: bool,
+    pos: usize,
+    line: usize,
+    column: usize,
+}
+
+impl Lexer {
+    fn peek(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.pos + offset).copied()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek(0)?;
+        self.pos += 1;
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        Some(c)
+    }
+
+    fn error(&self, line: usize, column: usize, message: String) -> Report<ParseError> {
+        Report::new(ParseError::new(line, column, message))
+    }
+
+    fn skip_blanks(&mut self) -> error_stack::Result<(), ParseError> {
+        loop {
+            match (self.peek(0), self.peek(1)) {
+                (Some(c), _) if c.is_whitespace() => {
+                    self.bump();
+                }
+                (Some('-'), Some('-')) => {
+                    while !matches!(self.peek(0), None | Some('\n')) {
+                        self.bump();
+                    }
+                }
+                (Some('/'), Some('*')) => {
+                    let (line, column) = (self.line, self.column);
+                    self.bump();
+                    self.bump();
+                    loop {
+                        match (self.bump(), self.peek(0)) {
+                            (Some('*'), Some('/')) => {
+                                self.bump();
+                                break;
+                            }
+                            (None, _) => {
+                                return Err(self.error(line, column, "unterminated comment".into()))
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+                _ => return Ok(()),
+            }
+        }
+    }
+
+    /// Read a text delimited by `close`, where a doubled `close` is the escaped character.
+    fn delimited(&mut self, close: char, what: &str) -> error_stack::Result<String, ParseError> {
+        let (line, column) = (self.line, self.column);
+        self.bump();
+        let mut text = String::new();
+        loop {
+            match self.bump() {
+                Some(c) if c == close && self.peek(0) == Some(close) => {
+                    self.bump();
+                    text.push(c);
+                }
+                Some(c) if c == close => return Ok(text),
+                Some('\\') if close == '\'' && self.backslash_escapes => match self.bump() {
+                    Some('0') => text.push('\0'),
+                    Some('n') => text.push('\n'),
+                    Some('r') => text.push('\r'),
+                    Some('t') => text.push('\t'),
+                    // Kept escaped, so they still match the literal character in `LIKE` patterns.
+                    Some(c @ ('%' | '_')) => {
+                        text.push('\\');
+                        text.push(c);
+                    }
+                    Some(c) => text.push(c),
+                    None => return Err(self.error(line, column, format!("unterminated {what}"))),
+                },
+                Some(c) => text.push(c),
+                None => return Err(self.error(line, column, format!("unterminated {what}"))),
+            }
+        }
+    }
+
+    fn word(&mut self) -> String {
+        let mut word = String::new();
+        while let Some(c) = self.peek(0).filter(|c| c.is_alphanumeric() || *c == '_') {
+            word.push(c);
+            self.bump();
+        }
+        word
+    }
+
+    fn number(&mut self) -> String {
+        let mut number = String::new();
+        let digits = |lexer: &mut Lexer, number: &mut String| {
+            while let Some(c) = lexer.peek(0).filter(char::is_ascii_digit) {
+                number.push(c);
+                lexer.bump();
+            }
+        };
+        digits(self, &mut number);
+        if self.peek(0) == Some('.') && self.peek(1).is_some_and(|c| c.is_ascii_digit()) {
+            number.push('.');
+            self.bump();
+            digits(self, &mut number);
+        }
+        if matches!(self.peek(0), Some('e' | 'E')) {
+            let sign = matches!(self.peek(1), Some('+' | '-')) as usize;
+            if self.peek(1 + sign).is_some_and(|c| c.is_ascii_digit()) {
+                for _ in 0..=sign {
+                    number.push(self.bump().unwrap());
+                }
+                digits(self, &mut number);
+            }
+        }
+        number
+    }
+
+    fn token(&mut self) -> error_stack::Result<Token, ParseError> {
+        self.skip_blanks()?;
+        let (line, column) = (self.line, self.column);
+        let kind = match self.peek(0) {
+            None => TokenKind::Eof,
+            Some('\'') => TokenKind::String(self.delimited('\'', "string")?),
+            Some('"') => TokenKind::QuotedIdent(self.delimited('"', "identifier")?),
+            Some('`') => TokenKind::QuotedIdent(self.delimited('`', "identifier")?),
+            Some('[') => TokenKind::QuotedIdent(self.delimited(']', "identifier")?),
+            Some(':') if self.peek(1).is_some_and(|c| c.is_alphabetic() || c == '_') => {
+                self.bump();
+                TokenKind::Bind(self.word())
+            }
+            Some(c) if c.is_ascii_digit() => TokenKind::Number(self.number()),
+            Some(c) if c.is_alphabetic() || c == '_' => TokenKind::Word(self.word()),
+            Some(c) => {
+                let symbol = SYMBOLS.iter().find(|symbol| {
+                    symbol
+                        .chars()
+                        .enumerate()
+                        .all(|(i, s)| self.peek(i) == Some(s))
+                });
+                match symbol {
+                    Some(symbol) => {
+                        symbol.chars().for_each(|_| {
+                            self.bump();
+                        });
+                        TokenKind::Symbol(symbol)
+                    }
+                    None => {
+                        return Err(self.error(line, column, format!("unexpected character `{c}`")))
+                    }
+                }
+            }
+        };
+        Ok(Token { kind, line, column })
+    }
+}
+
+/// Split a SQL text into tokens, the last one is always `TokenKind::Eof`.
+pub(crate) fn tokenize(
+    text: &str,
+    backslash_escapes: bool,
+) -> error_stack::Result<Vec<Token>, ParseError> {
+    let mut lexer = Lexer {
+        chars: text.chars().collect(),
+        backslash_escapes,
+        pos: 0,
+        line: 1,
+        column: 1,
+    };
+    let mut tokens = Vec::new();
+    loop {
+        let token = lexer.token()?;
+        let eof = token.kind == TokenKind::Eof;
+        tokens.push(token);
+        if eof {
+            return Ok(tokens);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn kinds(text: &str) -> Vec<TokenKind> {
+        tokenize(text, false)
+            .unwrap()
+            .into_iter()
+            .map(|t| t.kind)
+            .collect()
+    }
+
+    #[test]
+    fn test_tokenize() {
+        assert_eq!(
+            kinds(r#""T"."A""B" >= 1.5e3 -- comment"#),
+            vec![
+                TokenKind::QuotedIdent("T".to_string()),
+                TokenKind::Symbol("."),
+                TokenKind::QuotedIdent(r#"A"B"#.to_string()),
+                TokenKind::Symbol(">="),
+                TokenKind::Number("1.5e3".to_string()),
+                TokenKind::Eof,
+            ]
+        );
+        assert_eq!(
+            kinds("name /* c */ <> 'It''s' AND :id"),
+            vec![
+                TokenKind::Word("name".to_string()),
+                TokenKind::Symbol("<>"),
+                TokenKind::String("It's".to_string()),
+                TokenKind::Word("AND".to_string()),
+                TokenKind::Bind("id".to_string()),
+                TokenKind::Eof,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tokenize_backslash_escapes() {
+        let tokens = tokenize(r"'It\'s' '\\\%'", true).unwrap();
+        assert_eq!(tokens[0].kind, TokenKind::String("It's".to_string()));
+        assert_eq!(tokens[1].kind, TokenKind::String(r"\\%".to_string()));
+        let tokens = tokenize(r"'C:\'", false).unwrap();
+        assert_eq!(tokens[0].kind, TokenKind::String(r"C:\".to_string()));
+    }
+
+    #[test]
+    fn test_tokenize_error() {
+        let error = tokenize("A = 1\n  AND B = 'x", false).unwrap_err();
+        assert_eq!(
+            error.current_context(),
+            &ParseError::new(2, 11, "unterminated string")
+        );
+        let error = tokenize("A # 1", false).unwrap_err();
+        assert_eq!(
+            error.current_context(),
+            &ParseError::new(1, 3, "unexpected character `#`")
+        );
+    }
+}
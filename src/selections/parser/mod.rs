@@ -0,0 +1,195 @@
+pub(crate) mod expression;
+pub(crate) mod lexer;
+pub(crate) mod select;
+
+use self::lexer::{tokenize, Token, TokenKind};
+use crate::{dialects::Dialect, ParseError};
+use error_stack::Report;
+use std::collections::HashSet;
+
+/// Recursive descent parser over the tokens of a SQL text.
+pub(crate) struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+    /// Positions of `(` already known to enclose a value instead of a logical expression.
+    value_groups: HashSet<usize>,
+}
+
+impl Parser {
+    pub(crate) fn new(text: &str) -> error_stack::Result<Self, ParseError> {
+        Self::build(text, false)
+    }
+
+    /// Create the parser for a text written with the syntax of `dialect`.
+    pub(crate) fn with_dialect(
+        text: &str,
+        dialect: &dyn Dialect,
+    ) -> error_stack::Result<Self, ParseError> {
+        Self::build(text, dialect.backslash_escapes())
+    }
+
+    fn build(text: &str, backslash_escapes: bool) -> error_stack::Result<Self, ParseError> {
+        Ok(Self {
+            tokens: tokenize(text, backslash_escapes)?,
+            pos: 0,
+            value_groups: HashSet::new(),
+        })
+    }
+
+    pub(crate) fn peek(&self) -> &Token {
+        self.peek_at(0)
+    }
+
+    pub(crate) fn peek_at(&self, offset: usize) -> &Token {
+        let last = self.tokens.len() - 1;
+        &self.tokens[(self.pos + offset).min(last)]
+    }
+
+    pub(crate) fn next(&mut self) -> Token {
+        let token = self.peek().clone();
+        if token.kind != TokenKind::Eof {
+            self.pos += 1;
+        }
+        token
+    }
+
+    pub(crate) fn error_at(&self, token: &Token, message: impl Into<String>) -> Report<ParseError> {
+        Report::new(ParseError::new(token.line, token.column, message))
+    }
+
+    /// Error for the current token, that is not the `expected` one.
+    pub(crate) fn expected(&self, expected: &str) -> Report<ParseError> {
+        let token = self.peek();
+        self.error_at(token, format!("expected {expected}, found {}", token.kind))
+    }
+
+    pub(crate) fn is_keyword_at(&self, offset: usize, keyword: &str) -> bool {
+        matches!(&self.peek_at(offset).kind, TokenKind::Word(word) if word.eq_ignore_ascii_case(keyword))
+    }
+
+    pub(crate) fn is_keyword(&self, keyword: &str) -> bool {
+        self.is_keyword_at(0, keyword)
+    }
+
+    pub(crate) fn eat_keyword(&mut self, keyword: &str) -> bool {
+        let found = self.is_keyword(keyword);
+        if found {
+            self.next();
+        }
+        found
+    }
+
+    pub(crate) fn expect_keyword(&mut self, keyword: &str) -> error_stack::Result<(), ParseError> {
+        if self.eat_keyword(keyword) {
+            Ok(())
+        } else {
+            Err(self.expected(&format!("`{keyword}`")))
+        }
+    }
+
+    pub(crate) fn is_symbol_at(&self, offset: usize, symbol: &str) -> bool {
+        matches!(&self.peek_at(offset).kind, TokenKind::Symbol(s) if *s == symbol)
+    }
+
+    pub(crate) fn is_symbol(&self, symbol: &str) -> bool {
+        self.is_symbol_at(0, symbol)
+    }
+
+    pub(crate) fn eat_symbol(&mut self, symbol: &str) -> bool {
+        let found = self.is_symbol(symbol);
+        if found {
+            self.next();
+        }
+        found
+    }
+
+    pub(crate) fn expect_symbol(&mut self, symbol: &str) -> error_stack::Result<(), ParseError> {
+        if self.eat_symbol(symbol) {
+            Ok(())
+        } else {
+            Err(self.expected(&format!("`{symbol}`")))
+        }
+    }
+
+    /// Check that the whole text was consumed, a final `;` is accepted.
+    pub(crate) fn expect_end(&mut self) -> error_stack::Result<(), ParseError> {
+        self.eat_symbol(";");
+        match self.peek().kind {
+            TokenKind::Eof => Ok(()),
+            _ => Err(self.expected("end of text")),
+        }
+    }
+
+    /// Read an identifier, quoted or not, keywords are accepted only when quoted.
+    /// Doubled closing quotes of a quoted identifier are already un-escaped by the lexer.
+    pub(crate) fn identifier(&mut self) -> error_stack::Result<String, ParseError> {
+        match &self.peek().kind {
+            TokenKind::QuotedIdent(ident) => {
+                let ident = ident.clone();
+                self.next();
+                Ok(ident)
+            }
+            TokenKind::Word(word) if !is_reserved(word) => {
+                let word = word.clone();
+                self.next();
+                Ok(word)
+            }
+            _ => Err(self.expected("identifier")),
+        }
+    }
+}
+
+/// Keywords that can't be used as unquoted identifiers.
+const RESERVED: &[&str] = &[
+    "ALL",
+    "AND",
+    "ANY",
+    "AS",
+    "BETWEEN",
+    "BY",
+    "CASE",
+    "CROSS",
+    "DISTINCT",
+    "ELSE",
+    "END",
+    "EXCEPT",
+    "EXISTS",
+    "FALSE",
+    "FETCH",
+    "FILTER",
+    "FOR",
+    "FROM",
+    "FULL",
+    "GROUP",
+    "HAVING",
+    "ILIKE",
+    "IN",
+    "INNER",
+    "INTERSECT",
+    "IS",
+    "JOIN",
+    "LEFT",
+    "LIKE",
+    "LIMIT",
+    "NOT",
+    "NULL",
+    "OFFSET",
+    "ON",
+    "OR",
+    "ORDER",
+    "OUTER",
+    "OVER",
+    "RIGHT",
+    "SELECT",
+    "THEN",
+    "TRUE",
+    "UNION",
+    "WHEN",
+    "WHERE",
+    "WINDOW",
+    "WITH",
+];
+
+pub(crate) fn is_reserved(word: &str) -> bool {
+    RESERVED.iter().any(|r| r.eq_ignore_ascii_case(word))
+}
@@ -0,0 +1,788 @@
+use super::{lexer::TokenKind, Parser};
+use crate::{
+    dialects::Dialect,
+    selections::{
+        orders::OrdersBy, Alias, Combination, CombinationType, Cte, FromSelect, FromType,
+        IntoGroupBy, Join, LimitOffset, NamedWindow, OrderBy, Select, TableField, TableName,
+        ValueSelect, ValueWhere, ValuesSelect,
+    },
+    FieldName, ParseError,
+};
+
+impl Select {
+    /// Parse a `SELECT` statement written with the syntax of `dialect`, like the one rendered by `to_sql`.
+    /// The syntax of all dialects is accepted (`LIMIT`, `TOP`, `OFFSET .. FETCH`, `||`, ..), `dialect`
+    /// only defines how string literals are escaped.
+    /// Qualified fields keep the qualifier as table name, even when it is an alias, and booleans
+    /// rendered as `1`/`0` are read as numbers. Other literals are read as described in
+    /// `LogicalExprWhere::parse`.
+    /// Syntax the `Select` can't represent, like expressions in `ORDER BY`, is reported as an error.
+    /// # Example
+    /// ```
+    /// # use voxi_core::selections::{QueryBuilder, Select, TableField, ConditionWhereOperation};
+    /// # use voxi_core::dialects::PostgresDialect;
+    /// let query = Select::parse(r#"SELECT "ID" FROM "TABLE" WHERE "PRICE" > 10"#, &PostgresDialect).unwrap();
+    /// let expected = QueryBuilder::new()
+    ///     .field("ID")
+    ///     .from("TABLE")
+    ///     .where_c(TableField::new("PRICE").greater(10))
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(query, expected);
+    /// let error = Select::parse("SELECT ID FROM T ORDER BY ID + 1", &PostgresDialect).unwrap_err();
+    /// assert_eq!(
+    ///     error.current_context().to_string(),
+    ///     "`ORDER BY` only supports fields at line 1, column 27"
+    /// );
+    /// ```
+    pub fn parse(sql: &str, dialect: &dyn Dialect) -> error_stack::Result<Select, ParseError> {
+        let mut parser = Parser::with_dialect(sql, dialect)?;
+        let select = parser.select()?;
+        parser.expect_end()?;
+        Ok(select)
+    }
+}
+
+/// Return `true` when `query` reads the table `name`, in `FROM` or in a join.
+fn reads_table(query: &Select, name: &TableName) -> bool {
+    query
+        .from
+        .iter()
+        .chain(query.joins.iter().map(|j| j.from()))
+        .any(|from| matches!(from.from_type(), FromType::Table(table) if table == name))
+}
+
+impl Parser {
+    /// Read a query enclosed by parentheses.
+    pub(crate) fn sub_query(&mut self) -> error_stack::Result<Select, ParseError> {
+        self.expect_symbol("(")?;
+        let query = self.select()?;
+        self.expect_symbol(")")?;
+        Ok(query)
+    }
+
+    pub(crate) fn select(&mut self) -> error_stack::Result<Select, ParseError> {
+        let ctes = match self.eat_keyword("WITH") {
+            true => self.ctes()?,
+            false => Vec::new(),
+        };
+        self.expect_keyword("SELECT")?;
+        let distinct = self.eat_keyword("DISTINCT");
+        let mut limit_offset = None;
+        if self.is_keyword("TOP") && matches!(self.peek_at(1).kind, TokenKind::Number(_)) {
+            self.next();
+            limit_offset = Some(LimitOffset::new(self.integer()?, 0));
+        }
+        let columns = self.columns()?;
+        self.expect_keyword("FROM")?;
+        let mut from = vec![self.table_reference()?];
+        while self.eat_symbol(",") {
+            from.push(self.table_reference()?);
+        }
+        let joins = self.joins()?;
+        let where_expr = match self.eat_keyword("WHERE") {
+            true => Some(self.logical_expr()?),
+            false => None,
+        };
+        let mut groups = Vec::new();
+        if self.eat_keyword("GROUP") {
+            self.expect_keyword("BY")?;
+            let fields = self.fields_list("GROUP BY")?;
+            groups = fields.into_iter().map(|f| f.into_group_by()).collect();
+        }
+        let having_expr = match self.eat_keyword("HAVING") {
+            true => Some(self.logical_expr()?),
+            false => None,
+        };
+        let mut windows = Vec::new();
+        if self.eat_keyword("WINDOW") {
+            loop {
+                let name = self.alias()?;
+                self.expect_keyword("AS")?;
+                self.expect_symbol("(")?;
+                windows.push(NamedWindow::new(name, self.window_spec()?));
+                self.expect_symbol(")")?;
+                if !self.eat_symbol(",") {
+                    break;
+                }
+            }
+        }
+        let mut orders_by = OrdersBy::empty();
+        if self.eat_keyword("ORDER") {
+            self.expect_keyword("BY")?;
+            orders_by = self.orders_by()?;
+        }
+        if self.is_keyword("LIMIT") || self.is_keyword("OFFSET") {
+            if limit_offset.is_some() {
+                return Err(self.error_at(self.peek(), "pagination already defined by `TOP`"));
+            }
+            limit_offset = Some(self.limit_offset()?);
+        }
+        let combination = self.combination()?;
+        Ok(Select {
+            ctes,
+            columns,
+            from,
+            where_expr,
+            having_expr,
+            windows,
+            joins,
+            groups,
+            orders_by,
+            limit_offset,
+            distinct,
+            combination,
+            binds_values: Vec::new(),
+        })
+    }
+
+    fn ctes(&mut self) -> error_stack::Result<Vec<Cte>, ParseError> {
+        self.eat_keyword("RECURSIVE");
+        let mut ctes = Vec::new();
+        loop {
+            let name = TableName(self.identifier()?);
+            let mut columns = Vec::new();
+            if self.eat_symbol("(") {
+                loop {
+                    columns.push(FieldName(self.identifier()?));
+                    if !self.eat_symbol(",") {
+                        break;
+                    }
+                }
+                self.expect_symbol(")")?;
+            }
+            self.expect_keyword("AS")?;
+            let mut query = self.sub_query()?;
+            // The recursive part is the query combined by `UNION ALL` that reads the expression itself.
+            let recursive = matches!(
+                &query.combination,
+                Some(c) if *c.combination_type() == CombinationType::UnionAll
+                    && c.query().combination.is_none()
+                    && reads_table(c.query(), &name)
+            );
+            let cte = match query.combination.take() {
+                Some(combination) if recursive => {
+                    Cte::new_recursive(name, columns, query, combination.into_query())
+                }
+                combination => {
+                    query.combination = combination;
+                    Cte {
+                        columns,
+                        ..Cte::new(name, query)
+                    }
+                }
+            };
+            ctes.push(cte);
+            if !self.eat_symbol(",") {
+                return Ok(ctes);
+            }
+        }
+    }
+
+    fn columns(&mut self) -> error_stack::Result<ValuesSelect, ParseError> {
+        let mut columns = ValuesSelect::empty();
+        loop {
+            if self.eat_symbol("*") {
+                columns.push(TableField::new("*"));
+            } else {
+                let mut column = ValueSelect::new(self.value_expr()?);
+                if let Some(alias) = self.optional_alias()? {
+                    column = column.with_alias(alias);
+                }
+                columns.push(column);
+            }
+            if !self.eat_symbol(",") {
+                return Ok(columns);
+            }
+        }
+    }
+
+    fn alias(&mut self) -> error_stack::Result<Alias, ParseError> {
+        Ok(Alias {
+            alias: self.identifier()?,
+        })
+    }
+
+    /// Read an alias, `AS` is optional.
+    fn optional_alias(&mut self) -> error_stack::Result<Option<Alias>, ParseError> {
+        if self.eat_keyword("AS") {
+            return self.alias().map(Some);
+        }
+        match &self.peek().kind {
+            TokenKind::QuotedIdent(_) => self.alias().map(Some),
+            TokenKind::Word(word) if !super::is_reserved(word) => self.alias().map(Some),
+            _ => Ok(None),
+        }
+    }
+
+    /// Read a table or a sub-query, with optional alias.
+    fn table_reference(&mut self) -> error_stack::Result<FromSelect, ParseError> {
+        let from_type = if self.is_symbol("(") && self.is_sub_query_at(1) {
+            FromType::Query(Box::new(self.sub_query()?))
+        } else {
+            let name = self.identifier()?;
+            if self.is_symbol(".") {
+                return Err(
+                    self.error_at(self.peek(), "tables qualified by schema are not supported")
+                );
+            }
+            FromType::Table(TableName(name))
+        };
+        Ok(FromSelect::new(from_type, self.optional_alias()?))
+    }
+
+    fn joins(&mut self) -> error_stack::Result<Vec<Join>, ParseError> {
+        let mut joins = Vec::new();
+        loop {
+            if self.is_keyword("CROSS") || self.is_keyword("NATURAL") {
+                return Err(self.error_at(
+                    self.peek(),
+                    format!("{} JOIN is not supported", self.peek().kind),
+                ));
+            }
+            let kind = ["INNER", "LEFT", "RIGHT", "FULL"]
+                .into_iter()
+                .find(|k| self.is_keyword(k));
+            if let Some(kind) = kind {
+                self.next();
+                if kind != "INNER" {
+                    self.eat_keyword("OUTER");
+                }
+                self.expect_keyword("JOIN")?;
+            } else if !self.eat_keyword("JOIN") {
+                return Ok(joins);
+            }
+            let from = self.table_reference()?;
+            self.expect_keyword("ON")?;
+            let condition = self.logical_expr()?;
+            let join = match kind {
+                Some("LEFT") => Join::left(from, condition),
+                Some("RIGHT") => Join::right(from, condition),
+                Some("FULL") => Join::full(from, condition),
+                _ => Join::inner(from, condition),
+            };
+            joins.push(join);
+        }
+    }
+
+    /// Read fields separated by comma, for a clause that doesn't accept expressions.
+    pub(crate) fn fields_list(
+        &mut self,
+        clause: &str,
+    ) -> error_stack::Result<Vec<TableField>, ParseError> {
+        let mut fields = vec![self.clause_field(clause)?];
+        while self.eat_symbol(",") {
+            fields.push(self.clause_field(clause)?);
+        }
+        Ok(fields)
+    }
+
+    fn clause_field(&mut self, clause: &str) -> error_stack::Result<TableField, ParseError> {
+        let start = self.peek().clone();
+        match self.value_expr()? {
+            ValueWhere::TableField(field) => Ok(field),
+            _ => Err(self.error_at(&start, format!("`{clause}` only supports fields"))),
+        }
+    }
+
+    pub(crate) fn orders_by(&mut self) -> error_stack::Result<OrdersBy, ParseError> {
+        let mut orders_by = OrdersBy::empty();
+        loop {
+            // MSSQL only paginates ordered queries, `to_sql` orders by `(SELECT NULL)` when there is no order.
+            let no_order = self.is_symbol("(")
+                && self.is_keyword_at(1, "SELECT")
+                && self.is_keyword_at(2, "NULL")
+                && self.is_symbol_at(3, ")");
+            if no_order {
+                (0..4).for_each(|_| {
+                    self.next();
+                });
+            } else {
+                let field = self.clause_field("ORDER BY")?;
+                let order_by = match self.eat_keyword("DESC") {
+                    true => OrderBy::desc(field),
+                    false => {
+                        self.eat_keyword("ASC");
+                        OrderBy::asc(field)
+                    }
+                };
+                if self.is_keyword("NULLS") {
+                    return Err(
+                        self.error_at(self.peek(), "`NULLS FIRST`/`NULLS LAST` is not supported")
+                    );
+                }
+                orders_by.push(order_by);
+            }
+            if !self.eat_symbol(",") {
+                return Ok(orders_by);
+            }
+        }
+    }
+
+    /// Read `LIMIT <limit> [OFFSET <offset>]`, `LIMIT <offset>, <limit>` or
+    /// `OFFSET <offset> ROWS FETCH NEXT <limit> ROWS ONLY`.
+    fn limit_offset(&mut self) -> error_stack::Result<LimitOffset, ParseError> {
+        if self.eat_keyword("LIMIT") {
+            let limit = self.integer()?;
+            if self.eat_symbol(",") {
+                return Ok(LimitOffset::new(self.integer()?, limit));
+            }
+            let offset = match self.eat_keyword("OFFSET") {
+                true => self.integer()?,
+                false => 0,
+            };
+            return Ok(LimitOffset::new(limit, offset));
+        }
+        let offset_token = self.next();
+        let offset = self.integer()?;
+        let _ = self.eat_keyword("ROWS") || self.eat_keyword("ROW");
+        if !self.eat_keyword("FETCH") {
+            return Err(self.error_at(&offset_token, "`OFFSET` without `FETCH` is not supported"));
+        }
+        if !self.eat_keyword("NEXT") {
+            self.expect_keyword("FIRST")?;
+        }
+        let limit = self.integer()?;
+        let _ = self.eat_keyword("ROWS") || self.eat_keyword("ROW");
+        self.expect_keyword("ONLY")?;
+        Ok(LimitOffset::new(limit, offset))
+    }
+
+    fn combination(&mut self) -> error_stack::Result<Option<Box<Combination>>, ParseError> {
+        let combination_type = if self.eat_keyword("UNION") {
+            match self.eat_keyword("ALL") {
+                true => CombinationType::UnionAll,
+                false => CombinationType::Union,
+            }
+        } else if self.eat_keyword("INTERSECT") {
+            CombinationType::Intersect
+        } else if self.eat_keyword("EXCEPT") {
+            CombinationType::Except
+        } else {
+            return Ok(None);
+        };
+        if self.is_keyword("ALL") {
+            return Err(self.error_at(self.peek(), "`ALL` is only supported by `UNION`"));
+        }
+        let query = self.select()?;
+        Ok(Some(Box::new(Combination::new(query, combination_type))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        dialects::{Dialect, MsSqlDialect, MySqlDialect, PostgresDialect, SqliteDialect},
+        resolvers::args_resolver_string::{args_to_str, args_to_str_with_dialect},
+        selections::{
+            AggFunction, Alias, ArithmeticExprWhere, CaseConditionBuilder, CompareOperator,
+            ConditionWhere, ConditionWhereOperation, FrameBound, FromSelect, IntoValueWhere, Join,
+            LogicalExprWhere, LogicalExprWhereOps, OrderBy, QueryAlias, QueryBuilder, Select,
+            SingleSelectBuilder, StringFunction, Table, TableField, TableName, ValueSelect,
+            ValueWhere, WhenCondition, WindowFunction, WindowSpec,
+        },
+        FieldName, ParseError,
+    };
+    use chrono::NaiveDate;
+    use pretty_assertions::assert_eq;
+    use rust_decimal_macros::dec;
+
+    fn field(name: &str) -> TableField {
+        TableField::new(name)
+    }
+
+    fn round_trip_with(query: &Select, dialect: impl Dialect + Copy + 'static) {
+        let sql = args_to_str_with_dialect(query, dialect).unwrap();
+        let parsed = Select::parse(&sql, &dialect)
+            .unwrap_or_else(|e| panic!("{}: {sql}: {e:?}", dialect.name()));
+        assert_eq!(&parsed, query, "{}: {sql}", dialect.name());
+    }
+
+    /// Parse the SQL rendered for every dialect, it must result in the same query.
+    fn round_trip(query: Select) {
+        round_trip_with(&query, PostgresDialect);
+        round_trip_with(&query, MySqlDialect);
+        round_trip_with(&query, SqliteDialect);
+        round_trip_with(&query, MsSqlDialect);
+    }
+
+    #[test]
+    fn test_round_trip_clauses() {
+        round_trip(QueryBuilder::new().all().from("TABLE").build().unwrap());
+        round_trip(
+            QueryBuilder::new()
+                .distinct()
+                .field("ID")
+                .select((field("NAME"), "N"))
+                .select((field("T.PRICE"), "It's \\ quoted"))
+                .select(field("T.*"))
+                .from(FromSelect::from_table("TABLE").with_alias("T"))
+                .where_c(
+                    field("NAME")
+                        .like("%O'Brien\\_%")
+                        .and(field("ID").greater(-3)),
+                )
+                .order(OrderBy::asc("ID"))
+                .order(OrderBy::desc("T.NAME"))
+                .limit_offset(10, 20)
+                .build()
+                .unwrap(),
+        );
+        round_trip(
+            QueryBuilder::new()
+                .field("ID")
+                .from("TABLE")
+                .limit_offset(5, 0)
+                .build()
+                .unwrap(),
+        );
+        round_trip(
+            QueryBuilder::new()
+                .field("ID")
+                .from("TABLE")
+                .limit_offset(5, 10)
+                .build()
+                .unwrap(),
+        );
+        round_trip(
+            QueryBuilder::new()
+                .field("C.NAME")
+                .select((AggFunction::sum("O.TOTAL"), "TOTAL"))
+                .from(FromSelect::from_table("CUSTOMER").with_alias("C"))
+                .from("REGION")
+                .join(Join::inner(
+                    FromSelect::from_table("ORDERS").with_alias("O"),
+                    field("O.CUSTOMER").equal(field("C.ID")),
+                ))
+                .join(Join::left("NOTE", field("NOTE.ID").equal(field("O.NOTE"))))
+                .join(Join::right("R", field("R.ID").equal(field("C.R"))))
+                .join(Join::full("F", field("F.ID").diff(field("C.F"))))
+                .join(Join::inner(
+                    "ITEM",
+                    field("ITEM.ORDER_ID")
+                        .equal(field("O.ID"))
+                        .and(field("ITEM.REGION").equal(field("REGION.ID"))),
+                ))
+                .where_c(field("C.ID").between(1, 100))
+                .group("C.NAME")
+                .having_c(AggFunction::sum("O.TOTAL").greater(dec!(1000.50)))
+                .build()
+                .unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_round_trip_quoted_identifiers() {
+        let table = TableName(r#"T"X"#.to_string());
+        let quoted = TableField {
+            table: Some(Table {
+                name: table.clone(),
+                alias: None,
+            }),
+            field_name: FieldName(r#"A"B"#.to_string()),
+        };
+        let query = QueryBuilder::new()
+            .select(ValueSelect::from(quoted.clone()).with_alias(Alias {
+                alias: r#"N"1"#.to_string(),
+            }))
+            .from(table)
+            .where_c(quoted.equal(1))
+            .build()
+            .unwrap();
+        assert_eq!(
+            args_to_str(&query).unwrap(),
+            r#"SELECT "T""X"."A""B" AS "N""1" FROM "T""X" WHERE "T""X"."A""B" = 1"#
+        );
+        round_trip(query);
+    }
+
+    #[test]
+    fn test_round_trip_sub_queries() {
+        let single = SingleSelectBuilder::max("PRICE")
+            .from("ITEM")
+            .where_c(field("ITEM.ORDER_ID").equal(field("O.ID")))
+            .build();
+        let ids = QueryBuilder::new()
+            .field("ID")
+            .from("BLOCKED")
+            .build()
+            .unwrap();
+        let sub = QueryBuilder::new()
+            .field("ID")
+            .count("*")
+            .from("ORDERS")
+            .group("ID")
+            .build()
+            .unwrap();
+        round_trip(
+            QueryBuilder::new()
+                .field("S.ID")
+                .select((single.clone(), "MAX_PRICE"))
+                .from(QueryAlias::new(sub, "S"))
+                .where_c(
+                    ConditionWhere::exists(ids.clone())
+                        .not()
+                        .and(field("S.ID").not_include(ids.clone()))
+                        .and(field("S.ID").include(vec![1, 2, 3]))
+                        .or(field("S.ID").all(CompareOperator::Ge, ids.clone()))
+                        .or(field("S.ID").any(CompareOperator::Df, ids))
+                        .and(ConditionWhere::ls(single, 10)),
+                )
+                .build()
+                .unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_round_trip_functions() {
+        let case = CaseConditionBuilder::new()
+            .when_value(WhenCondition::new(field("QTY").greater(10), "BIG"))
+            .else_case("SMALL")
+            .build();
+        round_trip(
+            QueryBuilder::new()
+                .select((ValueWhere::CaseCondition(Box::new(case)), "SIZE"))
+                .select(StringFunction::upper(field("NAME")))
+                .select(StringFunction::substring(field("NAME"), 2, 3))
+                .select(StringFunction::replace(field("NAME"), "a", "b"))
+                .select(StringFunction::concat(vec![
+                    field("NAME").into_value_where(),
+                    "-".into_value_where(),
+                    field("CODE").into_value_where(),
+                ]))
+                .select(AggFunction::count("*"))
+                .select(AggFunction::avg(ArithmeticExprWhere::multiply(
+                    field("PRICE"),
+                    field("QTY"),
+                )))
+                .select(AggFunction::count(field("CODE")).distinct())
+                .select(AggFunction::string_agg(field("NAME"), ", "))
+                .from("ITEM")
+                .where_c(
+                    field("A")
+                        .not_distinct_from(field("B"))
+                        .and(field("NAME").null().or(field("CODE").not_null())),
+                )
+                .build()
+                .unwrap(),
+        );
+        // SQLite has no STDDEV
+        let stddev = QueryBuilder::new()
+            .select(AggFunction::stddev(field("PRICE")))
+            .from("ITEM")
+            .build()
+            .unwrap();
+        round_trip_with(&stddev, PostgresDialect);
+        round_trip_with(&stddev, MySqlDialect);
+        round_trip_with(&stddev, MsSqlDialect);
+    }
+
+    #[test]
+    fn test_round_trip_windows() {
+        let by_dept = WindowSpec::new()
+            .partition_by("DEPT")
+            .order(OrderBy::desc("SALARY"));
+        round_trip(
+            QueryBuilder::new()
+                .select(WindowFunction::row_number().over(by_dept.clone()))
+                .select(WindowFunction::ntile(4).over_named("W"))
+                .select(WindowFunction::lag_or(field("SALARY"), 2, 0).over_named("W"))
+                .select(WindowFunction::lead(field("SALARY"), 1).over(WindowSpec::new()))
+                .select(
+                    WindowFunction::aggregate(AggFunction::sum("SALARY")).over(
+                        by_dept
+                            .clone()
+                            .rows_between(FrameBound::UnboundedPreceding, FrameBound::CurrentRow),
+                    ),
+                )
+                .select(
+                    WindowFunction::first_value(field("NAME")).over(
+                        by_dept
+                            .clone()
+                            .range_between(FrameBound::Preceding(2), FrameBound::Following(3)),
+                    ),
+                )
+                .from("EMPLOYEE")
+                .window("W", by_dept)
+                .build()
+                .unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_round_trip_combination_and_ctes() {
+        let archived = QueryBuilder::new()
+            .field("ID")
+            .from("ARCHIVE")
+            .build()
+            .unwrap();
+        let deleted = QueryBuilder::new()
+            .field("ID")
+            .from("DELETED")
+            .intersect(archived.clone())
+            .build()
+            .unwrap();
+        round_trip(
+            QueryBuilder::new()
+                .field("ID")
+                .from("ACTIVE")
+                .union_all(
+                    QueryBuilder::from_query(archived.clone())
+                        .except(deleted)
+                        .build()
+                        .unwrap(),
+                )
+                .build()
+                .unwrap(),
+        );
+        let anchor = QueryBuilder::new()
+            .field("ID")
+            .field("PARENT_ID")
+            .from("CATEGORY")
+            .where_c(field("PARENT_ID").null())
+            .build()
+            .unwrap();
+        let recursive = QueryBuilder::new()
+            .field("C.ID")
+            .field("C.PARENT_ID")
+            .from(FromSelect::from_table("CATEGORY").with_alias("C"))
+            .join(Join::inner(
+                "TREE",
+                field("C.PARENT_ID").equal(field("TREE.ID")),
+            ))
+            .build()
+            .unwrap();
+        round_trip(
+            QueryBuilder::new()
+                .with("OLD", archived.clone())
+                .with_recursive("TREE", vec!["ID", "PARENT_ID"], anchor, recursive)
+                .field("ID")
+                .from("TREE")
+                .from("OLD")
+                .union(archived)
+                .build()
+                .unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_round_trip_postgres() {
+        let query = QueryBuilder::new()
+            .select(
+                AggFunction::sum(field("TOTAL"))
+                    .filter(field("PAID").equal(true))
+                    .into_value_where(),
+            )
+            .select(AggFunction::bool_and(field("PAID")))
+            .from("ORDERS")
+            .where_c(
+                field("NAME")
+                    .ilike("a%")
+                    .and(field("A").distinct_from(field("B")))
+                    .and(field("BIRTH").less(NaiveDate::from_ymd_opt(2000, 1, 31).unwrap())),
+            )
+            .build()
+            .unwrap();
+        round_trip_with(&query, PostgresDialect);
+    }
+
+    #[test]
+    fn test_parse_legacy_sql() {
+        let sql = "
+            select top 10 c.name, count(*) total
+            from customer c
+            left outer join orders o on o.customer = c.id
+            where c.active = 1 and o.created >= CAST('2024-01-01 10:30:00' AS DATETIME)
+            group by c.name
+            order by c.name";
+        let query = Select::parse(sql, &MsSqlDialect).unwrap();
+        let expected = QueryBuilder::new()
+            .field("c.name")
+            .select((AggFunction::count("*"), "total"))
+            .from(FromSelect::from_table("customer").with_alias("c"))
+            .join(Join::left(
+                FromSelect::from_table("orders").with_alias("o"),
+                field("o.customer").equal(field("c.id")),
+            ))
+            .where_c(
+                field("c.active").equal(1).and(
+                    field("o.created").greater_or_equal(
+                        NaiveDate::from_ymd_opt(2024, 1, 1)
+                            .unwrap()
+                            .and_hms_opt(10, 30, 0)
+                            .unwrap(),
+                    ),
+                ),
+            )
+            .group("c.name")
+            .order(OrderBy::asc("c.name"))
+            .limit_offset(10, 0)
+            .build()
+            .unwrap();
+        assert_eq!(query, expected);
+
+        let query = Select::parse(
+            r"SELECT * FROM T WHERE A = 'x\'y' LIMIT 20, 10",
+            &MySqlDialect,
+        )
+        .unwrap();
+        let expected = QueryBuilder::new()
+            .all()
+            .from("T")
+            .where_c(field("A").equal("x'y"))
+            .limit_offset(10, 20)
+            .build()
+            .unwrap();
+        assert_eq!(query, expected);
+
+        let query = Select::parse("SELECT A FROM T WHERE A IS NOT :a", &SqliteDialect).unwrap();
+        let expr = LogicalExprWhere::parse("A IS DISTINCT FROM :a").unwrap();
+        assert_eq!(query.where_expr(), Some(&expr));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |sql: &str| {
+            Select::parse(sql, &PostgresDialect)
+                .unwrap_err()
+                .current_context()
+                .clone()
+        };
+        assert_eq!(
+            error("SELECT 1"),
+            ParseError::new(1, 9, "expected `FROM`, found end of text")
+        );
+        assert_eq!(
+            error("SELECT A FROM T\nGROUP BY UPPER(A)"),
+            ParseError::new(2, 10, "`GROUP BY` only supports fields")
+        );
+        assert_eq!(
+            error("SELECT A FROM T CROSS JOIN U"),
+            ParseError::new(1, 17, "`CROSS` JOIN is not supported")
+        );
+        assert_eq!(
+            error("SELECT A FROM S.T"),
+            ParseError::new(1, 16, "tables qualified by schema are not supported")
+        );
+        assert_eq!(
+            error("SELECT A FROM T OFFSET 10"),
+            ParseError::new(1, 17, "`OFFSET` without `FETCH` is not supported")
+        );
+        assert_eq!(
+            error("SELECT A FROM T ORDER BY A NULLS LAST"),
+            ParseError::new(1, 28, "`NULLS FIRST`/`NULLS LAST` is not supported")
+        );
+        assert_eq!(
+            error("SELECT (SELECT A, B FROM U) FROM T"),
+            ParseError::new(1, 8, "sub-query used as value must have one column")
+        );
+        assert_eq!(
+            error("SELECT A FROM T UNION SELECT B FROM U EXCEPT ALL SELECT C FROM V"),
+            ParseError::new(1, 46, "`ALL` is only supported by `UNION`")
+        );
+        assert_eq!(
+            error("SELECT RANK() FROM T"),
+            ParseError::new(1, 15, "expected `OVER`, found `FROM`")
+        );
+    }
+}
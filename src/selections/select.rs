@@ -1,7 +1,9 @@
 use super::{
-    agg_functions::AggFunction,
+    agg_functions::{AggFunction, IntoAggArgument},
+    alias::IntoAlias,
     bind_name::{BindName, IntoBindName},
     combination::Combination,
+    cte::{ctes_to_sql, Cte},
     from::{FromSelect, IntoFrom},
     group_by::{GroupBy, IntoGroupBy},
     join::{IntoJoins, Join},
@@ -10,23 +12,28 @@ use super::{
     orders::{IntoOrdersBy, OrdersBy},
     query::Query,
     single_select::SingleQuery,
-    table_field::{IntoTableField, IntoTablesField},
-    table_name::TableName,
-    tables_names::TablesNames,
+    table_field::IntoTablesField,
+    table_name::{IntoTableName, TableName},
+    tables_names::TablesNamesVisitor,
     to_sql::ToSQL,
+    type_inference::{InferredType, SelectTypes, TableTypes},
     value_select::{IntoValueSelect, ValueSelect},
     value_where::IntoValueWhere,
     values_select::{IntoValuesSelect, ValuesSelect},
+    visitor::Visitor,
+    window_function::{NamedWindow, WindowSpec},
     LimitOffset, LogicalExprWhereOps, ValueWhere,
 };
 use crate::{
     resolvers::{
-        args_resolver::ArgsResolver, args_resolver_binds::ArgsResolverBindsDecorator,
+        args_resolver::ArgsResolver,
+        args_resolver_binds::ArgsResolverBindsDecorator,
         args_resolver_string::ArgsResolverString,
+        sql_format::{indented, line_break},
     },
     SQLError,
 };
-use crate::{FieldName, IntoNullableValue, NullableValue};
+use crate::{FieldName, IntoFieldName, IntoNullableValue, NullableValue};
 use serde::{Deserialize, Serialize};
 use std::{
     collections::{HashMap, HashSet},
@@ -44,6 +51,9 @@ use std::{
 /// ```
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct QueryBuilder {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    ctes: Vec<Cte>,
     distinct: bool,
     columns: ValuesSelect,
     from: Vec<FromSelect>,
@@ -53,6 +63,9 @@ pub struct QueryBuilder {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     having_expr: Option<LogicalExprWhere>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    windows: Vec<NamedWindow>,
     orders_by: OrdersBy,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
@@ -68,6 +81,7 @@ impl QueryBuilder {
     pub fn from_query(query: impl IntoSelect) -> Self {
         let query = query.into_select();
         Self {
+            ctes: query.ctes,
             distinct: query.distinct,
             columns: query.columns,
             from: query.from,
@@ -75,6 +89,7 @@ impl QueryBuilder {
             where_expr: query.where_expr,
             groups: query.groups,
             having_expr: query.having_expr,
+            windows: query.windows,
             orders_by: query.orders_by,
             combination: query.combination,
             limit_offset: query.limit_offset,
@@ -85,6 +100,7 @@ impl QueryBuilder {
     /// Create the `SubQueryBuilder`.
     pub fn new() -> Self {
         Self {
+            ctes: Vec::new(),
             distinct: false,
             columns: ValuesSelect::empty(),
             from: Vec::new(),
@@ -92,6 +108,7 @@ impl QueryBuilder {
             where_expr: None,
             groups: Vec::new(),
             having_expr: None,
+            windows: Vec::new(),
             orders_by: OrdersBy::empty(),
             combination: None,
             limit_offset: None,
@@ -99,6 +116,42 @@ impl QueryBuilder {
         }
     }
 
+    /// Add a common table expression (`WITH <name> AS (<query>)`), it can be referenced as a table.
+    /// ```
+    /// # use voxi_core::selections::QueryBuilder;
+    /// # use voxi_core::resolvers::args_resolver_string::args_to_str;
+    /// let active = QueryBuilder::new().field("ID").from("USERS").build().unwrap();
+    /// let query = QueryBuilder::new().with("ACTIVE", active).field("ID").from("ACTIVE").build().unwrap();
+    /// assert_eq!(
+    ///     args_to_str(&query).unwrap(),
+    ///     r#"WITH "ACTIVE" AS (SELECT "ID" FROM "USERS") SELECT "ID" FROM "ACTIVE""#
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with(mut self, name: impl IntoTableName, query: impl IntoSelect) -> Self {
+        self.ctes.push(Cte::new(name, query.into_select()));
+        self
+    }
+
+    /// Add a recursive common table expression (`WITH RECURSIVE <name> (<columns>) AS (<anchor> UNION ALL <recursive>)`).
+    #[must_use]
+    pub fn with_recursive<F: IntoFieldName>(
+        mut self,
+        name: impl IntoTableName,
+        columns: Vec<F>,
+        anchor: impl IntoSelect,
+        recursive: impl IntoSelect,
+    ) -> Self {
+        let columns = columns.into_iter().map(|c| c.into_field_name()).collect();
+        self.ctes.push(Cte::new_recursive(
+            name,
+            columns,
+            anchor.into_select(),
+            recursive.into_select(),
+        ));
+        self
+    }
+
     /// Add column to select from query.
     /// If informed string will be considered static value.
     /// If you want select field use the method `field` instead.
@@ -161,32 +214,32 @@ impl QueryBuilder {
 
     /// Add column defining SQL function for COUNT(field).
     #[must_use]
-    pub fn count(self, column: impl IntoTableField) -> Self {
-        self.select(AggFunction::count(column.into_table_field()))
+    pub fn count(self, column: impl IntoAggArgument) -> Self {
+        self.select(AggFunction::count(column))
     }
 
     /// Add column defining SQL function for SUM(field).
     #[must_use]
-    pub fn sum(self, column: impl IntoTableField) -> Self {
-        self.select(AggFunction::sum(column.into_table_field()))
+    pub fn sum(self, column: impl IntoAggArgument) -> Self {
+        self.select(AggFunction::sum(column))
     }
 
     /// Add column defining SQL function for MAX(field).
     #[must_use]
-    pub fn max(self, column: impl IntoTableField) -> Self {
-        self.select(AggFunction::max(column.into_table_field()))
+    pub fn max(self, column: impl IntoAggArgument) -> Self {
+        self.select(AggFunction::max(column))
     }
 
     /// Add column defining SQL function for MAX(field).
     #[must_use]
-    pub fn min(self, column: impl IntoTableField) -> Self {
-        self.select(AggFunction::min(column.into_table_field()))
+    pub fn min(self, column: impl IntoAggArgument) -> Self {
+        self.select(AggFunction::min(column))
     }
 
     /// Add column defining SQL function for AVG(field).
     #[must_use]
-    pub fn avg(self, column: impl IntoTableField) -> Self {
-        self.select(AggFunction::avg(column.into_table_field()))
+    pub fn avg(self, column: impl IntoAggArgument) -> Self {
+        self.select(AggFunction::avg(column))
     }
 
     /// Select all columns (SELECT *) from query.
@@ -218,6 +271,15 @@ impl QueryBuilder {
         self
     }
 
+    /// Add joins, using `from` as the source of the query when none has been defined yet.
+    #[must_use]
+    pub(crate) fn join_from(mut self, from: impl IntoFrom, join: impl IntoJoins) -> Self {
+        if self.from.is_empty() {
+            self.from.push(from.into_from());
+        }
+        self.join(join)
+    }
+
     /// Define where condition. If already specified then the new condition will added with `AND` operator.
     #[must_use]
     pub fn where_c(self, expression: impl IntoLogicalExprWhere) -> Self {
@@ -241,6 +303,13 @@ impl QueryBuilder {
         }
     }
 
+    /// Declare a named window (`WINDOW <name> AS (<window>)`), it can be referenced by window functions.
+    #[must_use]
+    pub fn window(mut self, name: impl IntoAlias, spec: WindowSpec) -> Self {
+        self.windows.push(NamedWindow::new(name, spec));
+        self
+    }
+
     /// Define that SELECT must use DISTINCT clause, to returns only distinct record, without duplicates.
     #[must_use]
     pub fn distinct(mut self) -> Self {
@@ -334,6 +403,7 @@ impl QueryBuilder {
             ));
         }
         let query = Select {
+            ctes: self.ctes,
             distinct: self.distinct,
             columns: self.columns,
             from: self.from,
@@ -341,6 +411,7 @@ impl QueryBuilder {
             where_expr: self.where_expr,
             groups: self.groups,
             having_expr: self.having_expr,
+            windows: self.windows,
             orders_by: self.orders_by,
             combination: self.combination,
             limit_offset: self.limit_offset,
@@ -358,6 +429,10 @@ impl Default for QueryBuilder {
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Select {
+    #[serde(rename = "with")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub ctes: Vec<Cte>,
     pub columns: ValuesSelect,
     #[serde(rename = "from")]
     pub from: Vec<FromSelect>,
@@ -365,6 +440,10 @@ pub struct Select {
     pub where_expr: Option<LogicalExprWhere>,
     #[serde(rename = "having")]
     pub having_expr: Option<LogicalExprWhere>,
+    #[serde(rename = "window")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub windows: Vec<NamedWindow>,
     #[serde(rename = "join")]
     pub joins: Vec<Join>,
     #[serde(rename = "group_by")]
@@ -396,38 +475,52 @@ impl ToSQL for Select {
 
         let args_resolver = &mut ArgsResolverBindsDecorator::new(args_resolver, &hash);
 
-        let select_field = self.columns.to_sql(args_resolver)?;
-
-        let from = self
-            .from
-            .iter()
-            .map(|t| t.to_sql(args_resolver))
-            .collect::<Result<Vec<_>, _>>()?
-            .join(", ");
-        let mut full_sql = "SELECT ".to_string();
+        let mut full_sql = ctes_to_sql(&self.ctes, args_resolver)?;
 
+        let top = self.limit_offset.as_ref().and_then(|limit_offset| {
+            args_resolver
+                .dialect()
+                .top(limit_offset.limit, limit_offset.offset)
+        });
+        let select_start = full_sql.len();
+        full_sql.push_str("SELECT ");
         if self.distinct {
             full_sql.push_str("DISTINCT ");
         }
+        if let Some(top) = &top {
+            full_sql.push_str(top);
+            full_sql.push(' ');
+        }
+
+        // Columns and tables are aligned after the clause keyword.
+        let select_width = full_sql.len() - select_start;
+        let select_field = indented(args_resolver, select_width, |args_resolver| {
+            self.columns.to_sql(args_resolver)
+        })?;
+
+        let from = indented(args_resolver, "FROM ".len(), |args_resolver| {
+            let separator = format!(",{}", line_break(args_resolver, " "));
+            Ok(self
+                .from
+                .iter()
+                .map(|t| t.to_sql(args_resolver))
+                .collect::<Result<Vec<_>, _>>()?
+                .join(&separator))
+        })?;
 
         full_sql.push_str(&select_field);
 
+        let clause_break = line_break(args_resolver, " ");
         use std::fmt::Write;
-        write!(full_sql, " FROM {from}").unwrap();
+        write!(full_sql, "{clause_break}FROM {from}").unwrap();
 
-        let joins = self
-            .joins
-            .iter()
-            .map(|j| j.to_sql(args_resolver))
-            .collect::<Result<Vec<_>, _>>()?
-            .join(" ");
-        if !joins.is_empty() {
-            full_sql.push(' ');
-            full_sql.push_str(&joins);
+        for join in self.joins.iter() {
+            full_sql.push_str(&clause_break);
+            full_sql.push_str(&join.to_sql(args_resolver)?);
         }
 
         if let Some(w) = self.where_expr.as_ref() {
-            write!(full_sql, " WHERE {}", w.to_sql(args_resolver)?).unwrap();
+            write!(full_sql, "{clause_break}WHERE {}", w.to_sql(args_resolver)?).unwrap();
         }
 
         if !self.groups.is_empty() {
@@ -437,29 +530,47 @@ impl ToSQL for Select {
                 .map(|t| t.to_sql(args_resolver))
                 .collect::<Result<Vec<_>, _>>()?
                 .join(", ");
-            write!(full_sql, " GROUP BY {groups}").unwrap();
+            write!(full_sql, "{clause_break}GROUP BY {groups}").unwrap();
         }
 
         if let Some(h) = self.having_expr.as_ref() {
-            write!(full_sql, " HAVING {}", h.to_sql(args_resolver)?).unwrap();
+            write!(
+                full_sql,
+                "{clause_break}HAVING {}",
+                h.to_sql(args_resolver)?
+            )
+            .unwrap();
+        }
+
+        if !self.windows.is_empty() {
+            let windows = self
+                .windows
+                .iter()
+                .map(|w| w.to_sql(args_resolver))
+                .collect::<Result<Vec<_>, _>>()?
+                .join(", ");
+            write!(full_sql, "{clause_break}WINDOW {windows}").unwrap();
         }
 
         if !self.orders_by.is_empty() {
             write!(
                 full_sql,
-                " ORDER BY {}",
+                "{clause_break}ORDER BY {}",
                 self.orders_by.to_sql(args_resolver)?
             )
             .unwrap();
         }
 
-        if let Some(limit_offset) = &self.limit_offset {
-            full_sql.push(' ');
+        if let (Some(limit_offset), None) = (&self.limit_offset, &top) {
+            if self.orders_by.is_empty() && args_resolver.dialect().limit_requires_order_by() {
+                write!(full_sql, "{clause_break}ORDER BY (SELECT NULL)").unwrap();
+            }
+            full_sql.push_str(&clause_break);
             full_sql.push_str(&limit_offset.to_sql(args_resolver)?);
         }
 
         if let Some(combination) = &self.combination {
-            full_sql.push(' ');
+            full_sql.push_str(&clause_break);
             full_sql.push_str(&combination.to_sql(args_resolver)?);
         }
 
@@ -484,11 +595,13 @@ impl Select {
         binds_values: Vec<(BindName, NullableValue)>,
     ) -> Self {
         Self {
+            ctes: Vec::new(),
             distinct,
             columns: columns.into_values_select(),
             from: tables,
             where_expr,
             having_expr,
+            windows: Vec::new(),
             joins,
             groups,
             orders_by: orders_by.into_orders_by(),
@@ -498,32 +611,38 @@ impl Select {
         }
     }
 
+    /// Tables qualifying the fields of columns and `WHERE`, except the tables joined.
     pub fn tables_names(&self) -> HashSet<&TableName> {
-        let tables = match self.where_expr.as_ref() {
-            Some(c) => {
-                let e: HashSet<&TableName> = self
-                    .columns
-                    .tables_names()
-                    .into_iter()
-                    .chain(c.tables_names())
-                    .collect();
-                e
+        let tables = TablesNamesVisitor::collect(|visitor| {
+            for column in self.columns.values_select.iter() {
+                visitor.visit_value_select(column);
             }
-            None => self.columns.tables_names(),
-        };
+            if let Some(where_expr) = &self.where_expr {
+                visitor.visit_logical_expr(where_expr);
+            }
+        });
 
         // Ignore tables defined in join
         let tables_join = self
             .joins
             .iter()
-            .flat_map(|j| j.from().tables_names().into_iter().collect::<Vec<_>>())
-            .collect::<Vec<_>>();
+            .flat_map(|j| j.from().tables_names())
+            .collect::<HashSet<_>>();
         tables
             .into_iter()
-            .filter(|rt| !tables_join.iter().any(|jt| jt == rt))
+            .filter(|rt| !tables_join.contains(rt))
             .collect()
     }
 
+    /// Columns with the type inferred from the columns of the tables, `*` is expanded to the
+    /// columns of its tables.
+    pub fn infer_columns(
+        &self,
+        tables: &dyn TableTypes,
+    ) -> Vec<(ValueSelect, Option<InferredType>)> {
+        SelectTypes::new(self, tables, None).columns(self)
+    }
+
     /// Where expression.
     pub fn where_expr(&self) -> Option<&LogicalExprWhere> {
         self.where_expr.as_ref()
@@ -572,7 +691,11 @@ impl IntoSelect for SingleQuery {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::dialects::{MsSqlDialect, MySqlDialect, PostgresDialect, SqliteDialect};
+    use crate::resolvers::args_resolver_string::{args_to_str, args_to_str_with_dialect};
+    use crate::selections::{ArithmeticExprWhere, Join, LogicalExprWhereOps, OrderBy, TableField};
     use crate::{selections::ConditionWhereOperation, IntoFieldName};
+    use chrono::NaiveDate;
 
     #[test]
     fn test_agg_fun() {
@@ -696,6 +819,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_sum_expression() {
+        let query = QueryBuilder::new()
+            .sum(ArithmeticExprWhere::multiply(
+                TableField::new("PRICE"),
+                TableField::new("QTY"),
+            ))
+            .from("ITEM")
+            .build()
+            .unwrap();
+        assert_eq!(
+            args_to_str(&query).unwrap(),
+            r#"SELECT SUM("PRICE" * "QTY") FROM "ITEM""#
+        );
+    }
+
     #[test]
     fn test_avg() {
         let mut args_resolver_string = ArgsResolverString::new();
@@ -736,4 +875,142 @@ mod tests {
             r#"SELECT AVG("ID") FROM "TABLE" WHERE "ID" IS NULL AND "PRICE" IS NULL"#
         );
     }
+
+    fn dialect_query() -> Select {
+        QueryBuilder::new()
+            .field("ID")
+            .select((TableField::new("NAME"), "N".to_string()))
+            .from(FromSelect::from_table("TABLE").with_alias("T"))
+            .where_c(TableField::new("ACTIVE").equal(true).and(
+                TableField::new("BIRTH").greater(NaiveDate::from_ymd_opt(2000, 1, 31).unwrap()),
+            ))
+            .order(OrderBy::asc("ID"))
+            .limit_offset(10, 20)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_dialect_postgres() {
+        let query = dialect_query();
+        assert_eq!(
+            args_to_str_with_dialect(&query, PostgresDialect).unwrap(),
+            r#"SELECT "ID","NAME" AS "N" FROM "TABLE" "T" WHERE "ACTIVE" = true AND "BIRTH" > DATE '2000-01-31' ORDER BY "ID" ASC LIMIT 10 OFFSET 20"#
+        );
+    }
+
+    #[test]
+    fn test_dialect_mysql() {
+        let query = dialect_query();
+        assert_eq!(
+            args_to_str_with_dialect(&query, MySqlDialect).unwrap(),
+            "SELECT `ID`,`NAME` AS `N` FROM `TABLE` `T` WHERE `ACTIVE` = TRUE AND `BIRTH` > DATE '2000-01-31' ORDER BY `ID` ASC LIMIT 10 OFFSET 20"
+        );
+    }
+
+    #[test]
+    fn test_dialect_sqlite() {
+        let query = dialect_query();
+        assert_eq!(
+            args_to_str_with_dialect(&query, SqliteDialect).unwrap(),
+            r#"SELECT "ID","NAME" AS "N" FROM "TABLE" "T" WHERE "ACTIVE" = 1 AND "BIRTH" > '2000-01-31' ORDER BY "ID" ASC LIMIT 10 OFFSET 20"#
+        );
+    }
+
+    #[test]
+    fn test_dialect_mssql() {
+        let query = dialect_query();
+        assert_eq!(
+            args_to_str_with_dialect(&query, MsSqlDialect).unwrap(),
+            "SELECT [ID],[NAME] AS [N] FROM [TABLE] [T] WHERE [ACTIVE] = 1 AND [BIRTH] > CAST('2000-01-31' AS DATE) ORDER BY [ID] ASC OFFSET 20 ROWS FETCH NEXT 10 ROWS ONLY"
+        );
+    }
+
+    #[test]
+    fn test_dialect_mssql_top() {
+        let query = QueryBuilder::new()
+            .field("ID")
+            .from("TABLE")
+            .limit_offset(5, 0)
+            .build()
+            .unwrap();
+        assert_eq!(
+            args_to_str_with_dialect(&query, MsSqlDialect).unwrap(),
+            "SELECT TOP 5 [ID] FROM [TABLE]"
+        );
+        let query = QueryBuilder::new()
+            .field("ID")
+            .from("TABLE")
+            .limit_offset(5, 10)
+            .build()
+            .unwrap();
+        assert_eq!(
+            args_to_str_with_dialect(&query, MsSqlDialect).unwrap(),
+            "SELECT [ID] FROM [TABLE] ORDER BY (SELECT NULL) OFFSET 10 ROWS FETCH NEXT 5 ROWS ONLY"
+        );
+    }
+
+    #[test]
+    fn test_with_join() {
+        let totals = QueryBuilder::new()
+            .field("CUSTOMER_ID")
+            .select(AggFunction::sum("TOTAL"))
+            .from("ORDERS")
+            .group("CUSTOMER_ID")
+            .build()
+            .unwrap();
+        let query = QueryBuilder::new()
+            .with("TOTALS", totals)
+            .field(("C", "NAME"))
+            .from(FromSelect::from_table("CUSTOMER").with_alias("C"))
+            .join(Join::inner(
+                "TOTALS",
+                TableField::new(("TOTALS", "CUSTOMER_ID")).equal(TableField::new(("C", "ID"))),
+            ))
+            .build()
+            .unwrap();
+        assert_eq!(
+            args_to_str(&query).unwrap(),
+            r#"WITH "TOTALS" AS (SELECT "CUSTOMER_ID",SUM("TOTAL") FROM "ORDERS" GROUP BY "CUSTOMER_ID") SELECT "C"."NAME" FROM "CUSTOMER" "C" INNER JOIN "TOTALS" ON "TOTALS"."CUSTOMER_ID" = "C"."ID""#
+        );
+        let json = serde_json::to_string(&query).unwrap();
+        let query_de: Select = serde_json::from_str(&json).unwrap();
+        assert_eq!(query, query_de);
+    }
+
+    #[test]
+    fn test_with_recursive() {
+        let anchor = QueryBuilder::new()
+            .field("ID")
+            .field("PARENT_ID")
+            .from("CATEGORY")
+            .where_c(TableField::new("PARENT_ID").null())
+            .build()
+            .unwrap();
+        let recursive = QueryBuilder::new()
+            .field(("C", "ID"))
+            .field(("C", "PARENT_ID"))
+            .from(FromSelect::from_table("CATEGORY").with_alias("C"))
+            .join(Join::inner(
+                "TREE",
+                TableField::new(("C", "PARENT_ID")).equal(TableField::new(("TREE", "ID"))),
+            ))
+            .build()
+            .unwrap();
+        let query = QueryBuilder::new()
+            .with_recursive("TREE", vec!["ID", "PARENT_ID"], anchor, recursive)
+            .field("ID")
+            .from("TREE")
+            .build()
+            .unwrap();
+        assert_eq!(
+            args_to_str(&query).unwrap(),
+            r#"WITH RECURSIVE "TREE" ("ID", "PARENT_ID") AS (SELECT "ID","PARENT_ID" FROM "CATEGORY" WHERE "PARENT_ID" IS NULL UNION ALL SELECT "C"."ID","C"."PARENT_ID" FROM "CATEGORY" "C" INNER JOIN "TREE" ON "C"."PARENT_ID" = "TREE"."ID") SELECT "ID" FROM "TREE""#
+        );
+        let sql = args_to_str_with_dialect(&query, MsSqlDialect).unwrap();
+        assert!(sql.starts_with("WITH [TREE] ([ID], [PARENT_ID]) AS (SELECT"));
+        let json = serde_json::to_string(&query).unwrap();
+        let query_de: Select = serde_json::from_str(&json).unwrap();
+        assert_eq!(query, query_de);
+    }
 }
@@ -0,0 +1,400 @@
+use super::{
+    condition_where::ConditionWhere,
+    evaluator::{Evaluate, FieldValues},
+    logical_expr_where::{LogicalExprWhere, LogicalExprWhereOps},
+    table_field::TableField,
+    value_where::ValueWhere,
+    values_where::{IntoValuesWhere, ValuesListWhere},
+};
+use crate::NullableValue;
+
+/// Normalised form of a logical expression, with n-ary `AND`/`OR` and `NOT` already pushed
+/// into the conditions.
+#[derive(Clone, PartialEq)]
+enum Node {
+    Const(Option<bool>),
+    Condition(ConditionWhere),
+    /// Condition without negated form, like `EXISTS`.
+    Not(ConditionWhere),
+    And(Vec<Node>),
+    Or(Vec<Node>),
+}
+
+impl LogicalExprWhere {
+    /// Return an equivalent expression in canonical form, so that equivalent filters compare
+    /// equal and hash the same.
+    /// - nested `AND`/`OR` are flattened and duplicated predicates removed
+    /// - `NOT` is pushed inward (De Morgan), `NOT "A" = 1` becomes `"A" <> 1`
+    /// - conditions with only literal values are folded to `TRUE` or `FALSE`, except string
+    ///   comparisons which depend on the database collation
+    /// - `"A" = 1 OR "A" = 2` becomes `"A" IN (1,2)` and `"A" <> 1 AND "A" <> 2` becomes
+    ///   `"A" NOT IN (1,2)`
+    /// # Example
+    /// ```
+    /// # use voxi_core::selections::{ConditionWhereOperation, LogicalExprWhereOps, TableField};
+    /// # use voxi_core::resolvers::args_resolver_string::args_to_str;
+    /// let a = &TableField::new("A");
+    /// let expr = a.equal(1).or(a.equal(2)).or(a.equal(1)).not().not();
+    /// assert_eq!(args_to_str(&expr.simplify()).unwrap(), r#""A" IN (1,2)"#);
+    /// ```
+    pub fn simplify(&self) -> LogicalExprWhere {
+        to_logical_expr(normalize(self, false))
+    }
+}
+
+fn normalize(expr: &LogicalExprWhere, negate: bool) -> Node {
+    match expr {
+        LogicalExprWhere::Condition(condition) => condition_node(condition, negate),
+        LogicalExprWhere::Not(e) => normalize(e, !negate),
+        LogicalExprWhere::Expression(e) => normalize(e, negate),
+        LogicalExprWhere::And(e1, e2) => {
+            let nodes = vec![normalize(e1, negate), normalize(e2, negate)];
+            if negate {
+                or(nodes)
+            } else {
+                and(nodes)
+            }
+        }
+        LogicalExprWhere::Or(e1, e2) => {
+            let nodes = vec![normalize(e1, negate), normalize(e2, negate)];
+            if negate {
+                and(nodes)
+            } else {
+                or(nodes)
+            }
+        }
+    }
+}
+
+fn condition_node(condition: &ConditionWhere, negate: bool) -> Node {
+    // Conditions without fields are evaluated, any failure keeps the condition as is.
+    // String comparisons depend on the database collation, they are left to the database.
+    let no_fields = |_: &TableField| None;
+    if !has_string_operand(condition, &no_fields) {
+        if let Ok(value) = condition.evaluate_bool(&no_fields) {
+            return Node::Const(if negate { value.map(|v| !v) } else { value });
+        }
+    }
+    let condition = oriented(condition.clone());
+    if !negate {
+        return Node::Condition(condition);
+    }
+    negated(condition)
+}
+
+/// Return `true` when an operand of the condition evaluates to a string.
+fn has_string_operand(condition: &ConditionWhere, values: &dyn FieldValues) -> bool {
+    use ConditionWhere::*;
+    let operands = match condition {
+        ConditionEq(a, b)
+        | ConditionDf(a, b)
+        | ConditionGt(a, b)
+        | ConditionLs(a, b)
+        | ConditionGe(a, b)
+        | ConditionLe(a, b)
+        | ConditionLk(a, b)
+        | ConditionNotLk(a, b)
+        | ConditionILk(a, b)
+        | ConditionDistinct(a, b)
+        | ConditionNotDistinct(a, b) => vec![a, b],
+        ConditionBetween(a, low, high) | ConditionNotBetween(a, low, high) => vec![a, low, high],
+        ConditionIn(a, list) | ConditionNotIn(a, list) => {
+            let mut operands = vec![a];
+            if let ValuesListWhere::List(list) = list {
+                operands.extend(list.values_where());
+            }
+            operands
+        }
+        _ => vec![],
+    };
+    operands
+        .into_iter()
+        .any(|value| matches!(value.evaluate(values), Ok(NullableValue::String(_))))
+}
+
+fn is_literal(value: &ValueWhere) -> bool {
+    matches!(value, ValueWhere::LiteralValue(_))
+}
+
+/// Put literal values on the right side of comparisons.
+fn oriented(condition: ConditionWhere) -> ConditionWhere {
+    use ConditionWhere::*;
+    match condition {
+        ConditionEq(a, b) if is_literal(&a) && !is_literal(&b) => ConditionEq(b, a),
+        ConditionDf(a, b) if is_literal(&a) && !is_literal(&b) => ConditionDf(b, a),
+        ConditionGt(a, b) if is_literal(&a) && !is_literal(&b) => ConditionLs(b, a),
+        ConditionLs(a, b) if is_literal(&a) && !is_literal(&b) => ConditionGt(b, a),
+        ConditionGe(a, b) if is_literal(&a) && !is_literal(&b) => ConditionLe(b, a),
+        ConditionLe(a, b) if is_literal(&a) && !is_literal(&b) => ConditionGe(b, a),
+        ConditionDistinct(a, b) if is_literal(&a) && !is_literal(&b) => ConditionDistinct(b, a),
+        ConditionNotDistinct(a, b) if is_literal(&a) && !is_literal(&b) => {
+            ConditionNotDistinct(b, a)
+        }
+        condition => condition,
+    }
+}
+
+/// Negated form of a condition, or `NOT` when there is no one.
+fn negated(condition: ConditionWhere) -> Node {
+    use ConditionWhere::*;
+    let negated = match condition {
+        ConditionEq(a, b) => ConditionDf(a, b),
+        ConditionDf(a, b) => ConditionEq(a, b),
+        ConditionGt(a, b) => ConditionLe(a, b),
+        ConditionLs(a, b) => ConditionGe(a, b),
+        ConditionGe(a, b) => ConditionLs(a, b),
+        ConditionLe(a, b) => ConditionGt(a, b),
+        ConditionNull(a) => ConditionNotNull(a),
+        ConditionNotNull(a) => ConditionNull(a),
+        ConditionLk(a, b) => ConditionNotLk(a, b),
+        ConditionNotLk(a, b) => ConditionLk(a, b),
+        ConditionIn(a, list) => ConditionNotIn(a, list),
+        ConditionNotIn(a, list) => ConditionIn(a, list),
+        ConditionBetween(a, low, high) => ConditionNotBetween(a, low, high),
+        ConditionNotBetween(a, low, high) => ConditionBetween(a, low, high),
+        ConditionDistinct(a, b) => ConditionNotDistinct(a, b),
+        ConditionNotDistinct(a, b) => ConditionDistinct(a, b),
+        Exists(query) => NotExists(query),
+        NotExists(query) => Exists(query),
+        condition => return Node::Not(condition),
+    };
+    Node::Condition(negated)
+}
+
+/// Key used to sort the operands, so that the result does not depend on the original order.
+fn sort_key(node: &Node) -> String {
+    match node {
+        Node::Const(value) => format!("0{value:?}"),
+        Node::Condition(condition) => format!("1{}", serde_json::to_string(condition).unwrap()),
+        Node::Not(condition) => format!("2{}", serde_json::to_string(condition).unwrap()),
+        Node::And(nodes) => format!("3{}", nodes.iter().map(sort_key).collect::<String>()),
+        Node::Or(nodes) => format!("4{}", nodes.iter().map(sort_key).collect::<String>()),
+    }
+}
+
+fn canonical(mut nodes: Vec<Node>) -> Vec<Node> {
+    nodes.sort_by_cached_key(sort_key);
+    nodes.dedup();
+    nodes
+}
+
+fn and(nodes: Vec<Node>) -> Node {
+    let mut flat = Vec::new();
+    for node in nodes {
+        match node {
+            Node::And(children) => flat.extend(children),
+            Node::Const(Some(false)) => return Node::Const(Some(false)),
+            Node::Const(Some(true)) => {}
+            node => flat.push(node),
+        }
+    }
+    let flat = merge_lists(flat, false);
+    match flat.len() {
+        0 => Node::Const(Some(true)),
+        1 => flat.into_iter().next().unwrap(),
+        _ => Node::And(canonical(flat)),
+    }
+}
+
+fn or(nodes: Vec<Node>) -> Node {
+    let mut flat = Vec::new();
+    for node in nodes {
+        match node {
+            Node::Or(children) => flat.extend(children),
+            Node::Const(Some(true)) => return Node::Const(Some(true)),
+            Node::Const(Some(false)) => {}
+            node => flat.push(node),
+        }
+    }
+    let flat = merge_lists(flat, true);
+    match flat.len() {
+        0 => Node::Const(Some(false)),
+        1 => flat.into_iter().next().unwrap(),
+        _ => Node::Or(canonical(flat)),
+    }
+}
+
+/// Literal values compared with a value, by `=` and `IN` in `OR`, or `<>` and `NOT IN` in `AND`.
+fn list_values(node: &Node, include: bool) -> Option<(&ValueWhere, Vec<ValueWhere>)> {
+    let condition = match node {
+        Node::Condition(condition) => condition,
+        _ => return None,
+    };
+    let (value, values) = match (condition, include) {
+        (ConditionWhere::ConditionEq(a, b), true) | (ConditionWhere::ConditionDf(a, b), false) => {
+            (a, vec![b.clone()])
+        }
+        (ConditionWhere::ConditionIn(a, ValuesListWhere::List(list)), true)
+        | (ConditionWhere::ConditionNotIn(a, ValuesListWhere::List(list)), false) => {
+            (a, list.values_where().to_vec())
+        }
+        _ => return None,
+    };
+    (!is_literal(value) && values.iter().all(is_literal)).then_some((value, values))
+}
+
+/// Merge the comparisons of a same value against literals into a single `IN` or `NOT IN`.
+fn merge_lists(nodes: Vec<Node>, include: bool) -> Vec<Node> {
+    let mut groups: Vec<(ValueWhere, Vec<ValueWhere>)> = Vec::new();
+    let mut others = Vec::new();
+    for node in nodes {
+        let Some((value, values)) = list_values(&node, include) else {
+            others.push(node);
+            continue;
+        };
+        match groups.iter_mut().find(|(v, _)| v == value) {
+            Some((_, group)) => group.extend(values),
+            None => groups.push((value.clone(), values)),
+        }
+    }
+    for (value, mut values) in groups {
+        values.sort_by_cached_key(|v| serde_json::to_string(v).unwrap());
+        values.dedup();
+        let condition = match (values.len(), include) {
+            (1, true) => ConditionWhere::ConditionEq(value, values.remove(0)),
+            (1, false) => ConditionWhere::ConditionDf(value, values.remove(0)),
+            (_, true) => ConditionWhere::ConditionIn(value, list(values)),
+            (_, false) => ConditionWhere::ConditionNotIn(value, list(values)),
+        };
+        others.push(Node::Condition(condition));
+    }
+    others
+}
+
+fn list(values: Vec<ValueWhere>) -> ValuesListWhere {
+    ValuesListWhere::List(values.into_values_where())
+}
+
+fn to_logical_expr(node: Node) -> LogicalExprWhere {
+    match node {
+        Node::Const(value) => LogicalExprWhere::condition(ConditionWhere::expression(
+            ValueWhere::LiteralValue(NullableValue::Boolean(value)),
+        )),
+        Node::Condition(condition) => LogicalExprWhere::condition(condition),
+        Node::Not(condition) => LogicalExprWhere::condition(condition).not(),
+        Node::And(nodes) => nodes
+            .into_iter()
+            .map(to_logical_expr)
+            .reduce(|a, b| a.and(b))
+            .unwrap(),
+        Node::Or(nodes) => nodes
+            .into_iter()
+            .map(to_logical_expr)
+            .reduce(|a, b| a.or(b))
+            .unwrap(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        resolvers::args_resolver_string::args_to_str,
+        selections::{
+            ConditionWhere, ConditionWhereOperation, LogicalExprWhereOps, SingleSelectBuilder,
+            TableField,
+        },
+    };
+    use pretty_assertions::assert_eq;
+
+    fn field(name: &str) -> TableField {
+        TableField::new(name)
+    }
+
+    #[test]
+    fn test_flatten_and_dedupe() {
+        let expr = field("A")
+            .equal(1)
+            .and(field("B").equal(2).and(field("A").equal(1)).exp())
+            .and(field("C").null());
+        assert_eq!(
+            args_to_str(&expr.simplify()).unwrap(),
+            r#""A" = 1 AND "B" = 2 AND "C" IS NULL"#
+        );
+    }
+
+    #[test]
+    fn test_de_morgan() {
+        let expr = field("A").equal(1).or(field("B").greater(2)).not();
+        assert_eq!(
+            args_to_str(&expr.simplify()).unwrap(),
+            r#""A" <> 1 AND "B" <= 2"#
+        );
+        let expr = field("A")
+            .null()
+            .and(field("B").like("X%"))
+            .not()
+            .not()
+            .not();
+        assert_eq!(
+            args_to_str(&expr.simplify()).unwrap(),
+            r#""B" NOT LIKE 'X%' OR "A" IS NOT NULL"#
+        );
+        let query = SingleSelectBuilder::field("ID").from("T").build();
+        let expr = ConditionWhere::exists(query).not().and(field("A").equal(1));
+        assert_eq!(
+            args_to_str(&expr.simplify()).unwrap(),
+            r#""A" = 1 AND NOT EXISTS (SELECT "ID" FROM "T")"#
+        );
+    }
+
+    #[test]
+    fn test_constant_folding() {
+        let always = 1i32.greater(0);
+        let expr = field("A").equal(1).and(1i32.equal(1));
+        assert_eq!(args_to_str(&expr.simplify()).unwrap(), r#""A" = 1"#);
+        let expr = field("A").equal(1).and(1i32.equal(2));
+        assert_eq!(args_to_str(&expr.simplify()).unwrap(), "false");
+        let expr = field("A").equal(1).or(always.clone());
+        assert_eq!(args_to_str(&expr.simplify()).unwrap(), "true");
+        let expr = field("A").equal(1).and(always.not());
+        assert_eq!(args_to_str(&expr.simplify()).unwrap(), "false");
+    }
+
+    #[test]
+    fn test_string_constants_kept() {
+        // the result depends on the collation of the database
+        let expr = field("A").equal(1).and("abc".like("ABC"));
+        assert_eq!(
+            args_to_str(&expr.simplify()).unwrap(),
+            r#""A" = 1 AND 'abc' LIKE 'ABC'"#
+        );
+        let expr = field("A").equal(1).and("a ".equal("a"));
+        assert_eq!(
+            args_to_str(&expr.simplify()).unwrap(),
+            r#"'a ' = 'a' AND "A" = 1"#
+        );
+    }
+
+    #[test]
+    fn test_merge_in() {
+        let expr = field("A")
+            .equal(2)
+            .or(field("B").equal(1))
+            .or(field("A").equal(1))
+            .or(field("A").include(vec![3, 2]));
+        assert_eq!(
+            args_to_str(&expr.simplify()).unwrap(),
+            r#""B" = 1 OR "A" IN (1,2,3)"#
+        );
+        let expr = field("A").equal(1).or(field("A").equal(2)).not();
+        assert_eq!(
+            args_to_str(&expr.simplify()).unwrap(),
+            r#""A" NOT IN (1,2)"#
+        );
+    }
+
+    #[test]
+    fn test_canonical() {
+        let a = field("A")
+            .equal(1)
+            .and(field("B").equal(2).or(field("C").equal(3)));
+        let b = field("C")
+            .equal(3)
+            .or(field("B").equal(2))
+            .exp()
+            .and(1i32.equal(field("A")));
+        assert_ne!(a, b);
+        assert_eq!(a.simplify(), b.simplify());
+        assert_eq!(a.simplify(), a.simplify().simplify());
+    }
+}
@@ -1,5 +1,5 @@
 use super::{
-    agg_functions::AggFunction,
+    agg_functions::{AggFunction, IntoAggArgument},
     bind_name::{BindName, IntoBindName},
     combination::Combination,
     from::{FromSelect, IntoFrom},
@@ -123,28 +123,28 @@ impl SingleSelectBuilder {
     }
 
     /// Add column defining SQL function for COUNT(field).
-    pub fn count(column: impl IntoTableField) -> Self {
-        Self::select(AggFunction::count(column.into_table_field()))
+    pub fn count(column: impl IntoAggArgument) -> Self {
+        Self::select(AggFunction::count(column))
     }
 
     /// Add column defining SQL function for SUM(field).
-    pub fn sum(column: impl IntoTableField) -> Self {
-        Self::select(AggFunction::sum(column.into_table_field()))
+    pub fn sum(column: impl IntoAggArgument) -> Self {
+        Self::select(AggFunction::sum(column))
     }
 
     /// Add column defining SQL function for MAX(field).
-    pub fn max(column: impl IntoTableField) -> Self {
-        Self::select(AggFunction::max(column.into_table_field()))
+    pub fn max(column: impl IntoAggArgument) -> Self {
+        Self::select(AggFunction::max(column))
     }
 
     /// Add column defining SQL function for MAX(field).
-    pub fn min(column: impl IntoTableField) -> Self {
-        Self::select(AggFunction::min(column.into_table_field()))
+    pub fn min(column: impl IntoAggArgument) -> Self {
+        Self::select(AggFunction::min(column))
     }
 
     /// Add column defining SQL function for AVG(field).
-    pub fn avg(column: impl IntoTableField) -> Self {
-        Self::select(AggFunction::avg(column.into_table_field()))
+    pub fn avg(column: impl IntoAggArgument) -> Self {
+        Self::select(AggFunction::avg(column))
     }
 
     /// Add table to from part of select.
@@ -252,7 +252,7 @@ impl SingleSelectBuilder {
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct SingleQuery {
-    query: Select,
+    pub(crate) query: Select,
 }
 
 impl ToSQL for SingleQuery {
@@ -88,12 +88,10 @@ impl ToSQL for StringFunction {
             StringFunction::Lower(value_where) => {
                 format!("LOWER({})", value_where.to_sql(args_resolver)?)
             }
-            StringFunction::Substring(value_where, start, length) => format!(
-                "SUBSTRING({} FROM {} FOR {})",
-                value_where.to_sql(args_resolver)?,
-                start,
-                length
-            ),
+            StringFunction::Substring(value_where, start, length) => {
+                let value = value_where.to_sql(args_resolver)?;
+                args_resolver.dialect().substring(&value, *start, *length)
+            }
             StringFunction::Replace(value_where, old, new) => {
                 format!(
                     "REPLACE({}, {}, {})",
@@ -103,7 +101,12 @@ impl ToSQL for StringFunction {
                 )
             }
             StringFunction::Concat(values_where) => {
-                format!("CONCAT({})", values_where.to_sql(args_resolver)?)
+                let values = values_where
+                    .values_where()
+                    .iter()
+                    .map(|v| v.to_sql(args_resolver))
+                    .collect::<Result<Vec<_>, _>>()?;
+                args_resolver.dialect().concat(&values)
             }
         };
         Ok(sql)
@@ -113,6 +116,7 @@ impl ToSQL for StringFunction {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::dialects::{MsSqlDialect, SqliteDialect};
     use crate::{
         resolvers::args_resolver_string::ArgsResolverString,
         selections::table_field::IntoTableField,
@@ -208,4 +212,22 @@ mod tests {
             r#"CONCAT('OLD','NEW')"#
         );
     }
+
+    #[test]
+    fn test_dialect_functions() {
+        let substring = StringFunction::substring("TEXT", 1, 4);
+        let concat = StringFunction::concat(vec!["OLD", "NEW"]);
+        let mut sqlite = ArgsResolverString::with_dialect(SqliteDialect);
+        assert_eq!(
+            substring.to_sql(&mut sqlite).unwrap(),
+            "SUBSTR('TEXT', 1, 4)"
+        );
+        assert_eq!(concat.to_sql(&mut sqlite).unwrap(), "('OLD' || 'NEW')");
+        let mut mssql = ArgsResolverString::with_dialect(MsSqlDialect);
+        assert_eq!(
+            substring.to_sql(&mut mssql).unwrap(),
+            "SUBSTRING('TEXT', 1, 4)"
+        );
+        assert_eq!(concat.to_sql(&mut mssql).unwrap(), "CONCAT('OLD','NEW')");
+    }
 }
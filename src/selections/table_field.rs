@@ -51,6 +51,15 @@ impl TableField {
         self.table.as_ref()
     }
 
+    /// Alias or table name written before the field, as it is rendered in SQL.
+    pub fn qualifier(&self) -> Option<&str> {
+        let table = self.table.as_ref()?;
+        Some(match table.alias() {
+            Some(alias) => alias.alias(),
+            None => table.name().name(),
+        })
+    }
+
     /// Get a reference to the table field's name.
     pub fn name(&self) -> &FieldName {
         &self.field_name
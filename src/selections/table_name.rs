@@ -51,9 +51,9 @@ impl fmt::Display for TableName {
 impl ToSQL for TableName {
     fn to_sql(
         &self,
-        _args_resolver: &mut dyn ArgsResolver,
+        args_resolver: &mut dyn ArgsResolver,
     ) -> error_stack::Result<String, SQLError> {
-        Ok(format!(r#""{self}""#))
+        Ok(args_resolver.dialect().quote_identifier(&self.0))
     }
 }
 
@@ -1,90 +1,107 @@
 use super::{
     condition_where::ConditionWhere, logical_expr_where::LogicalExprWhere, table_name::TableName,
-    value_where::ValueWhere, ArithmeticExprWhere,
+    value_where::ValueWhere, visitor::Visitor, ArithmeticExprWhere, Select, TableField,
 };
 use std::collections::HashSet;
 
-// TODO: add comment
+/// Tables qualifying the fields referenced by an expression.
+/// Sub-queries don't report the tables they join.
 pub trait TablesNames {
     fn tables_names(&self) -> HashSet<&TableName>;
 }
 
+#[derive(Default)]
+pub(crate) struct TablesNamesVisitor<'ast> {
+    pub(crate) tables: HashSet<&'ast TableName>,
+}
+
+impl<'ast> TablesNamesVisitor<'ast> {
+    pub(crate) fn collect(visit: impl FnOnce(&mut Self)) -> HashSet<&'ast TableName> {
+        let mut visitor = Self::default();
+        visit(&mut visitor);
+        visitor.tables
+    }
+}
+
+impl<'ast> Visitor<'ast> for TablesNamesVisitor<'ast> {
+    fn visit_table_field(&mut self, table_field: &'ast TableField) {
+        if let Some(table) = &table_field.table {
+            self.tables.insert(table.name());
+        }
+    }
+
+    fn visit_select(&mut self, select: &'ast Select) {
+        self.tables.extend(select.tables_names());
+    }
+}
+
 impl TablesNames for ArithmeticExprWhere {
     fn tables_names(&self) -> HashSet<&TableName> {
-        match self {
-            ArithmeticExprWhere::ValueWhere(c) => c.tables_names().into_iter().collect(),
-            ArithmeticExprWhere::Expression(e) => e.tables_names(),
-            ArithmeticExprWhere::Subtract(e1, e2)
-            | ArithmeticExprWhere::Multiply(e1, e2)
-            | ArithmeticExprWhere::Divide(e1, e2)
-            | ArithmeticExprWhere::Add(e1, e2) => e1
-                .tables_names()
-                .into_iter()
-                .chain(e2.tables_names())
-                .collect(),
-        }
+        TablesNamesVisitor::collect(|visitor| visitor.visit_arithmetic_expr(self))
     }
 }
 
 impl TablesNames for ConditionWhere {
     fn tables_names(&self) -> HashSet<&TableName> {
-        match self {
-            ConditionWhere::ConditionEq(t, _)
-            | ConditionWhere::ConditionNull(t)
-            | ConditionWhere::ConditionDf(t, _)
-            | ConditionWhere::ConditionGt(t, _)
-            | ConditionWhere::ConditionLs(t, _)
-            | ConditionWhere::ConditionGe(t, _)
-            | ConditionWhere::ConditionLe(t, _)
-            | ConditionWhere::ConditionIn(t, _) => t.tables_names().into_iter().collect(),
-            ConditionWhere::ConditionBetween(t, _, _) => t.tables_names().into_iter().collect(),
-            ConditionWhere::Expression(e) => e.tables_names(),
-            ConditionWhere::Exists(_) => todo!(),
-            ConditionWhere::ConditionLk(_, _) => todo!(),
-        }
+        TablesNamesVisitor::collect(|visitor| visitor.visit_condition(self))
     }
 }
 
 impl TablesNames for LogicalExprWhere {
     fn tables_names(&self) -> HashSet<&TableName> {
-        match self {
-            LogicalExprWhere::Condition(c) => c.tables_names().into_iter().collect(),
-            LogicalExprWhere::Not(e) | LogicalExprWhere::Expression(e) => e.tables_names(),
-            LogicalExprWhere::And(e1, e2) | LogicalExprWhere::Or(e1, e2) => {
-                let e: HashSet<&TableName> = e1
-                    .tables_names()
-                    .into_iter()
-                    .chain(e2.tables_names())
-                    .collect();
-                e
-            }
-        }
+        TablesNamesVisitor::collect(|visitor| visitor.visit_logical_expr(self))
     }
 }
 
 impl TablesNames for ValueWhere {
-    /// FIXME: probably tables_names could be removed
-    /// Get a reference to the value condition's table name.
     fn tables_names(&self) -> HashSet<&TableName> {
-        match self {
-            ValueWhere::LiteralValue(_) => HashSet::default(),
-            ValueWhere::TableField(t) => t.table.iter().map(|f| f.name()).collect(),
-            // TODO: implement!
-            ValueWhere::Expression(_) => HashSet::default(),
-            ValueWhere::BindParameter(_) => HashSet::default(),
-            ValueWhere::SingleQuery(s) => s.tables_names(),
-            ValueWhere::CaseCondition(_) => todo!(),
-            ValueWhere::CaseValue(_) => todo!(),
-            ValueWhere::AggFunction(_) => todo!(),
-            ValueWhere::StringFunction(_) => todo!(),
-        }
+        TablesNamesVisitor::collect(|visitor| visitor.visit_value_where(self))
     }
 }
 
 impl TablesNames for Vec<ValueWhere> {
     fn tables_names(&self) -> HashSet<&TableName> {
-        self.iter()
-            .flat_map(|v| v.tables_names())
-            .collect::<HashSet<_>>()
+        TablesNamesVisitor::collect(|visitor| {
+            for value in self.iter() {
+                visitor.visit_value_where(value);
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::selections::{
+        AggFunction, ConditionWhereOperation, Join, LogicalExprWhereOps, QueryBuilder,
+        StringFunction, TableField, TableName,
+    };
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_select_tables_names() {
+        let query = QueryBuilder::new()
+            .select(AggFunction::sum("ITEM.TOTAL"))
+            .select(StringFunction::upper(TableField::new("CUSTOMER.NAME")))
+            .from("ORDERS")
+            .join(Join::inner(
+                "ITEM",
+                TableField::new("ITEM.ORDER_ID").equal(TableField::new("ORDERS.ID")),
+            ))
+            .where_c(
+                TableField::new("ORDERS.DATE")
+                    .greater(1)
+                    .and(TableField::new("NOTE.TEXT").like("%X%")),
+            )
+            .group("CUSTOMER.NAME")
+            .build()
+            .unwrap();
+        let expected = ["CUSTOMER", "ORDERS", "NOTE"]
+            .map(TableName::new)
+            .into_iter()
+            .collect::<HashSet<_>>();
+        assert_eq!(
+            query.tables_names(),
+            expected.iter().collect::<HashSet<_>>()
+        );
     }
 }
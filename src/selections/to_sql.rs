@@ -1,4 +1,8 @@
-use crate::{resolvers::args_resolver::ArgsResolver, SQLError};
+use crate::resolvers::{
+    args_resolver::ArgsResolver,
+    sql_format::{ArgsResolverFormatDecorator, SqlFormat},
+};
+use crate::SQLError;
 use crate::{FieldName, IntoNullableValue, NullableValue, TypedOptionValue, Value};
 
 /// Trait to generate SQL string.
@@ -6,18 +10,31 @@ use crate::{FieldName, IntoNullableValue, NullableValue, TypedOptionValue, Value
 pub trait ToSQL {
     fn to_sql(&self, args_resolver: &mut dyn ArgsResolver)
         -> error_stack::Result<String, SQLError>;
+
+    /// Generate SQL in multiple lines laid out by `format`, semantically identical to `to_sql`.
+    fn to_sql_formatted(
+        &self,
+        args_resolver: &mut dyn ArgsResolver,
+        format: &SqlFormat,
+    ) -> error_stack::Result<String, SQLError> {
+        let sql = self.to_sql(&mut ArgsResolverFormatDecorator::new(
+            args_resolver,
+            format.clone(),
+        ))?;
+        Ok(format.apply_keyword_case(&sql, args_resolver.dialect()))
+    }
 }
 
 impl ToSQL for FieldName {
     fn to_sql(
         &self,
-        _args_resolver: &mut dyn ArgsResolver,
+        args_resolver: &mut dyn ArgsResolver,
     ) -> error_stack::Result<String, SQLError> {
         let lit = self.to_string();
         if lit == "*" {
             return Ok(lit);
         }
-        Ok(format!(r#""{self}""#))
+        Ok(args_resolver.dialect().quote_identifier(&lit))
     }
 }
 
@@ -0,0 +1,616 @@
+use super::{
+    agg_functions::{AggFunction, AggFunctionType},
+    arithmetic_expr::arithmetic_expr_where::ArithmeticExprWhere,
+    case::{case_condition::CaseCondition, case_value::CaseValue},
+    from::{FromSelect, FromType},
+    join::JoinType,
+    select::Select,
+    string_functions::StringFunction,
+    table::Table,
+    table_field::TableField,
+    table_name::TableName,
+    value_select::ValueSelect,
+    value_where::ValueWhere,
+    window_function::{WindowFunction, WindowFunctionType},
+};
+use crate::{FieldName, IntoValueType, ValueType};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Result type of an expression, `nullable` when it can evaluate to `NULL`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct InferredType {
+    pub value_type: ValueType,
+    pub nullable: bool,
+}
+
+impl InferredType {
+    pub fn new(value_type: impl IntoValueType, nullable: bool) -> Self {
+        Self {
+            value_type: value_type.value_type(),
+            nullable,
+        }
+    }
+
+    pub fn not_null(value_type: impl IntoValueType) -> Self {
+        Self::new(value_type, false)
+    }
+
+    pub fn nullable(value_type: impl IntoValueType) -> Self {
+        Self::new(value_type, true)
+    }
+
+    fn or_nullable(mut self, nullable: bool) -> Self {
+        self.nullable |= nullable;
+        self
+    }
+}
+
+/// Source of the field types used to infer the type of expressions.
+pub trait FieldTypes {
+    fn field_type(&self, table_field: &TableField) -> Option<InferredType>;
+
+    /// Type of the single column returned by a sub-query, unknown by default.
+    fn query_type(&self, _query: &Select) -> Option<InferredType> {
+        None
+    }
+}
+
+impl<F> FieldTypes for F
+where
+    F: Fn(&TableField) -> Option<InferredType>,
+{
+    fn field_type(&self, table_field: &TableField) -> Option<InferredType> {
+        self(table_field)
+    }
+}
+
+impl FieldTypes for HashMap<FieldName, InferredType> {
+    fn field_type(&self, table_field: &TableField) -> Option<InferredType> {
+        self.get(&table_field.field_name).copied()
+    }
+}
+
+/// Columns of each table, used to infer the columns of a `SELECT`.
+pub trait TableTypes {
+    fn table_columns(&self, table: &TableName) -> Option<Vec<(FieldName, InferredType)>>;
+}
+
+impl TableTypes for HashMap<TableName, Vec<(FieldName, InferredType)>> {
+    fn table_columns(&self, table: &TableName) -> Option<Vec<(FieldName, InferredType)>> {
+        self.get(table).cloned()
+    }
+}
+
+/// Static inference of the type of an expression, `None` when it can't be known without
+/// running the query, like bind parameters or fields missing from `FieldTypes`.
+/// # Example
+/// ```
+/// # use voxi_core::selections::{AggFunction, ArithmeticExprWhere, InferType, InferredType, TableField};
+/// # use voxi_core::{FieldName, ValueType};
+/// # use std::collections::HashMap;
+/// let mut types = HashMap::new();
+/// types.insert(FieldName::from("QTY"), InferredType::not_null(ValueType::Int32));
+/// types.insert(FieldName::from("PRICE"), InferredType::nullable(ValueType::Decimal));
+/// let total = ArithmeticExprWhere::multiply(TableField::new("QTY"), TableField::new("PRICE"));
+/// assert_eq!(total.infer_type(&types), Some(InferredType::nullable(ValueType::Decimal)));
+/// let count = AggFunction::count("QTY");
+/// assert_eq!(count.infer_type(&types), Some(InferredType::not_null(ValueType::Int64)));
+/// ```
+pub trait InferType {
+    fn infer_type(&self, types: &dyn FieldTypes) -> Option<InferredType>;
+}
+
+impl InferType for ValueWhere {
+    fn infer_type(&self, types: &dyn FieldTypes) -> Option<InferredType> {
+        match self {
+            ValueWhere::TableField(table_field) => types.field_type(table_field),
+            ValueWhere::LiteralValue(value) => {
+                (!value.is_null()).then(|| InferredType::not_null(value.value_type()))
+            }
+            ValueWhere::Expression(expression) => expression.infer_type(types),
+            ValueWhere::BindParameter(_) => None,
+            // A sub-query without rows results in `NULL`.
+            ValueWhere::SingleQuery(single_query) => types
+                .query_type(&single_query.query)
+                .map(|t| t.or_nullable(true)),
+            ValueWhere::AggFunction(function) => function.infer_type(types),
+            ValueWhere::CaseCondition(case) => case.infer_type(types),
+            ValueWhere::CaseValue(case) => case.infer_type(types),
+            ValueWhere::StringFunction(function) => function.infer_type(types),
+            ValueWhere::WindowFunction(function) => function.infer_type(types),
+        }
+    }
+}
+
+impl InferType for ValueSelect {
+    fn infer_type(&self, types: &dyn FieldTypes) -> Option<InferredType> {
+        self.value_where.infer_type(types)
+    }
+}
+
+impl InferType for ArithmeticExprWhere {
+    fn infer_type(&self, types: &dyn FieldTypes) -> Option<InferredType> {
+        let operands = |a: &ArithmeticExprWhere, b: &ArithmeticExprWhere| {
+            Some((a.infer_type(types)?, b.infer_type(types)?))
+        };
+        let (a, b, value_type) = match self {
+            ArithmeticExprWhere::ValueWhere(value) => return value.infer_type(types),
+            ArithmeticExprWhere::Expression(expression) => return expression.infer_type(types),
+            ArithmeticExprWhere::Add(a, b) => {
+                let (a, b) = operands(a, b)?;
+                let value_type = numeric(a.value_type, b.value_type)
+                    .or_else(|| shifted(a.value_type, b.value_type))
+                    .or_else(|| shifted(b.value_type, a.value_type));
+                (a, b, value_type)
+            }
+            ArithmeticExprWhere::Subtract(a, b) => {
+                let (a, b) = operands(a, b)?;
+                let value_type = numeric(a.value_type, b.value_type)
+                    .or_else(|| shifted(a.value_type, b.value_type));
+                (a, b, value_type)
+            }
+            ArithmeticExprWhere::Multiply(a, b) | ArithmeticExprWhere::Divide(a, b) => {
+                let (a, b) = operands(a, b)?;
+                (a, b, numeric(a.value_type, b.value_type))
+            }
+        };
+        Some(InferredType::new(value_type?, a.nullable || b.nullable))
+    }
+}
+
+impl InferType for AggFunction {
+    fn infer_type(&self, types: &dyn FieldTypes) -> Option<InferredType> {
+        let value_type = match self.agg_type() {
+            AggFunctionType::Count => return Some(InferredType::not_null(ValueType::Int64)),
+            AggFunctionType::Sum => match self.value.infer_type(types)?.value_type {
+                ValueType::Int32 | ValueType::Int64 => ValueType::Int64,
+                ValueType::Decimal => ValueType::Decimal,
+                _ => return None,
+            },
+            AggFunctionType::Avg | AggFunctionType::StdDev | AggFunctionType::Variance => {
+                ValueType::Decimal
+            }
+            AggFunctionType::Min | AggFunctionType::Max => self.value.infer_type(types)?.value_type,
+            AggFunctionType::StringAgg(_) => ValueType::String,
+            AggFunctionType::BoolAnd | AggFunctionType::BoolOr => ValueType::Boolean,
+            AggFunctionType::ArrayAgg => return None,
+        };
+        // Every aggregate except `COUNT` results in `NULL` without rows.
+        Some(InferredType::nullable(value_type))
+    }
+}
+
+impl InferType for StringFunction {
+    fn infer_type(&self, types: &dyn FieldTypes) -> Option<InferredType> {
+        let values = match self {
+            StringFunction::Upper(value)
+            | StringFunction::Lower(value)
+            | StringFunction::Substring(value, _, _) => vec![value],
+            StringFunction::Replace(value, from, to) => vec![value, from, to],
+            StringFunction::Concat(values) => values.0.iter().collect(),
+        };
+        let nullable = values
+            .into_iter()
+            .any(|v| v.infer_type(types).is_none_or(|t| t.nullable));
+        Some(InferredType::new(ValueType::String, nullable))
+    }
+}
+
+impl InferType for CaseCondition {
+    fn infer_type(&self, types: &dyn FieldTypes) -> Option<InferredType> {
+        let thens = self.whens.iter().map(|w| &w.then_value_where);
+        branches(thens, self.else_case.as_ref(), types)
+    }
+}
+
+impl InferType for CaseValue {
+    fn infer_type(&self, types: &dyn FieldTypes) -> Option<InferredType> {
+        let thens = self.whens.iter().map(|w| &w.then_value_where);
+        branches(thens, self.else_case.as_ref(), types)
+    }
+}
+
+impl InferType for WindowFunction {
+    fn infer_type(&self, types: &dyn FieldTypes) -> Option<InferredType> {
+        match &self.function {
+            WindowFunctionType::RowNumber
+            | WindowFunctionType::Rank
+            | WindowFunctionType::DenseRank
+            | WindowFunctionType::Ntile(_) => Some(InferredType::not_null(ValueType::Int64)),
+            // Rows outside the partition take the default, `NULL` when it is not informed.
+            WindowFunctionType::Lag { value, default, .. }
+            | WindowFunctionType::Lead { value, default, .. } => {
+                let default_nullable = default
+                    .as_ref()
+                    .and_then(|d| d.infer_type(types))
+                    .is_none_or(|d| d.nullable);
+                Some(value.infer_type(types)?.or_nullable(default_nullable))
+            }
+            WindowFunctionType::FirstValue(value) | WindowFunctionType::LastValue(value) => {
+                value.infer_type(types)
+            }
+            WindowFunctionType::Aggregate(function) => function.infer_type(types),
+        }
+    }
+}
+
+/// Common type of the `CASE` results, `NULL` results only make it nullable.
+fn branches<'a>(
+    thens: impl Iterator<Item = &'a ValueWhere>,
+    else_case: Option<&'a ValueWhere>,
+    types: &dyn FieldTypes,
+) -> Option<InferredType> {
+    let mut result: Option<InferredType> = None;
+    let mut nullable = else_case.is_none();
+    for value in thens.chain(else_case) {
+        if matches!(value, ValueWhere::LiteralValue(v) if v.is_null()) {
+            nullable = true;
+            continue;
+        }
+        let branch = value.infer_type(types)?;
+        result = Some(match result {
+            Some(r) => InferredType::new(
+                promote(r.value_type, branch.value_type)?,
+                r.nullable || branch.nullable,
+            ),
+            None => branch,
+        });
+    }
+    result.map(|r| r.or_nullable(nullable))
+}
+
+/// Type holding values of both types, numbers are promoted up to `Decimal`.
+fn promote(a: ValueType, b: ValueType) -> Option<ValueType> {
+    if a == b {
+        return Some(a);
+    }
+    if a.is_temporal() && b.is_temporal() {
+        return Some(ValueType::DateTime);
+    }
+    numeric(a, b)
+}
+
+fn numeric(a: ValueType, b: ValueType) -> Option<ValueType> {
+    let rank = |t: ValueType| match t {
+        ValueType::Int32 => Some(0),
+        ValueType::Int64 => Some(1),
+        ValueType::Decimal => Some(2),
+        _ => None,
+    };
+    Some(if rank(a)? >= rank(b)? { a } else { b })
+}
+
+/// Date or timestamp added by a number of days.
+fn shifted(date: ValueType, days: ValueType) -> Option<ValueType> {
+    let days = matches!(days, ValueType::Int32 | ValueType::Int64);
+    (date.is_temporal() && days).then_some(date)
+}
+
+/// Columns of a source, the type is `None` when it can't be inferred.
+type Columns = Vec<(FieldName, Option<InferredType>)>;
+
+/// Types of the fields visible in a `SELECT`, coming from its tables, sub-queries and common
+/// table expressions, then from the outer queries.
+pub(crate) struct SelectTypes<'a> {
+    tables: &'a dyn TableTypes,
+    outer: Option<&'a SelectTypes<'a>>,
+    ctes: Vec<(String, Option<Columns>)>,
+    /// Alias or table name with its columns, `None` when they are unknown.
+    sources: Vec<(String, Option<Columns>)>,
+}
+
+impl<'a> SelectTypes<'a> {
+    pub(crate) fn new(
+        select: &Select,
+        tables: &'a dyn TableTypes,
+        outer: Option<&'a SelectTypes<'a>>,
+    ) -> Self {
+        let mut scope = Self {
+            tables,
+            outer,
+            ctes: Vec::new(),
+            sources: Vec::new(),
+        };
+        for cte in select.ctes.iter() {
+            let mut columns = SelectTypes::new(&cte.query, tables, Some(&scope)).output(&cte.query);
+            if let Some(columns) = columns.as_mut() {
+                for (column, name) in columns.iter_mut().zip(cte.columns.iter()) {
+                    column.0 = name.clone();
+                }
+            }
+            scope.ctes.push((cte.name.name().to_string(), columns));
+        }
+        for from in select.from.iter() {
+            let source = scope.source(from);
+            scope.sources.push(source);
+        }
+        for join in select.joins.iter() {
+            let mut source = scope.source(&join.from);
+            let (left, right) = match join.join_type() {
+                JoinType::Inner => (false, false),
+                JoinType::Left => (false, true),
+                JoinType::Right => (true, false),
+                JoinType::Full => (true, true),
+            };
+            if left {
+                for (_, columns) in scope.sources.iter_mut() {
+                    set_nullable(columns);
+                }
+            }
+            if right {
+                set_nullable(&mut source.1);
+            }
+            scope.sources.push(source);
+        }
+        scope
+    }
+
+    /// Columns of the select with their types, `*` is expanded when the columns of the sources
+    /// are known.
+    pub(crate) fn columns(&self, select: &Select) -> Vec<(ValueSelect, Option<InferredType>)> {
+        let mut columns = Vec::new();
+        for column in select.columns.values_select.iter() {
+            if let ValueWhere::TableField(table_field) = &column.value_where {
+                if table_field.field_name == "*" {
+                    if let Some(expanded) = self.expand(table_field.qualifier()) {
+                        columns.extend(expanded);
+                        continue;
+                    }
+                }
+            }
+            columns.push((column.clone(), column.infer_type(self)));
+        }
+        columns
+    }
+
+    fn expand(&self, qualifier: Option<&str>) -> Option<Vec<(ValueSelect, Option<InferredType>)>> {
+        let mut columns = Vec::new();
+        for (name, source) in self.sources.iter() {
+            if qualifier.is_some_and(|q| q != name) {
+                continue;
+            }
+            for (field_name, inferred) in source.as_ref()? {
+                let table = (!name.is_empty()).then(|| Table {
+                    name: TableName::from(name.as_str()),
+                    alias: None,
+                });
+                let table_field = TableField {
+                    table,
+                    field_name: field_name.clone(),
+                };
+                columns.push((
+                    ValueSelect::new(ValueWhere::TableField(table_field)),
+                    *inferred,
+                ));
+            }
+        }
+        Some(columns)
+    }
+
+    /// Columns as a source of an outer query, `None` when `*` can't be expanded.
+    fn output(&self, select: &Select) -> Option<Columns> {
+        self.columns(select)
+            .into_iter()
+            .map(|(column, inferred)| match &column.value_where {
+                ValueWhere::TableField(table_field) if table_field.field_name == "*" => None,
+                // Expressions without alias can't be referenced, their name is left empty.
+                _ => Some((column.try_field_name().unwrap_or_default(), inferred)),
+            })
+            .collect()
+    }
+
+    fn source(&self, from: &FromSelect) -> (String, Option<Columns>) {
+        let (name, columns) = match from.from_type() {
+            FromType::Table(table_name) => {
+                let columns = self.cte(table_name.name()).unwrap_or_else(|| {
+                    let columns = self.tables.table_columns(table_name)?;
+                    Some(columns.into_iter().map(|(n, t)| (n, Some(t))).collect())
+                });
+                (table_name.name().to_string(), columns)
+            }
+            FromType::Query(query) => (
+                String::new(),
+                SelectTypes::new(query, self.tables, Some(self)).output(query),
+            ),
+        };
+        let name = from.alias().map_or(name, |a| a.alias().to_string());
+        (name, columns)
+    }
+
+    fn cte(&self, name: &str) -> Option<Option<Columns>> {
+        match self.ctes.iter().find(|(cte, _)| cte == name) {
+            Some((_, columns)) => Some(columns.clone()),
+            None => self.outer?.cte(name),
+        }
+    }
+}
+
+fn set_nullable(columns: &mut Option<Columns>) {
+    for (_, inferred) in columns.iter_mut().flatten() {
+        if let Some(inferred) = inferred {
+            inferred.nullable = true;
+        }
+    }
+}
+
+impl FieldTypes for SelectTypes<'_> {
+    fn field_type(&self, table_field: &TableField) -> Option<InferredType> {
+        let name = &table_field.field_name;
+        let find = |columns: &Columns| columns.iter().find(|(c, _)| c == name).map(|(_, t)| *t);
+        if let Some(qualifier) = table_field.qualifier() {
+            return match self.sources.iter().find(|(s, _)| s == qualifier) {
+                Some((_, columns)) => find(columns.as_ref()?)?,
+                None => self.outer?.field_type(table_field),
+            };
+        }
+        let mut found = self
+            .sources
+            .iter()
+            .filter_map(|(_, columns)| find(columns.as_ref()?));
+        match (found.next(), found.next()) {
+            (Some(inferred), None) => inferred,
+            (Some(_), Some(_)) => None,
+            (None, _) if self.sources.iter().any(|(_, c)| c.is_none()) => None,
+            (None, _) => self.outer?.field_type(table_field),
+        }
+    }
+
+    fn query_type(&self, query: &Select) -> Option<InferredType> {
+        let scope = SelectTypes::new(query, self.tables, Some(self));
+        let (_, inferred) = scope.columns(query).into_iter().next()?;
+        inferred
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        dialects::PostgresDialect,
+        selections::{
+            AggFunction, CaseConditionBuilder, ConditionWhereOperation, IntoValueWhere,
+            WhenCondition,
+        },
+        NullableValue,
+    };
+    use pretty_assertions::assert_eq;
+
+    fn types() -> HashMap<FieldName, InferredType> {
+        let mut types = HashMap::new();
+        types.insert("QTY".into(), InferredType::not_null(ValueType::Int32));
+        types.insert("ID".into(), InferredType::not_null(ValueType::Int64));
+        types.insert("PRICE".into(), InferredType::nullable(ValueType::Decimal));
+        types.insert("NAME".into(), InferredType::not_null(ValueType::String));
+        types.insert("CREATED".into(), InferredType::not_null(ValueType::Date));
+        types
+    }
+
+    fn infer(value: impl IntoValueWhere) -> Option<InferredType> {
+        value.into_value_where().infer_type(&types())
+    }
+
+    #[test]
+    fn test_literals_and_expressions() {
+        assert_eq!(infer(10), Some(InferredType::not_null(ValueType::Int32)));
+        assert_eq!(infer(NullableValue::Int32(None)), None);
+        let expr = ArithmeticExprWhere::add(TableField::new("QTY"), TableField::new("ID"));
+        assert_eq!(infer(expr), Some(InferredType::not_null(ValueType::Int64)));
+        let expr = ArithmeticExprWhere::divide(TableField::new("ID"), TableField::new("PRICE"));
+        assert_eq!(
+            infer(expr),
+            Some(InferredType::nullable(ValueType::Decimal))
+        );
+        let expr = ArithmeticExprWhere::subtract(TableField::new("CREATED"), 7);
+        assert_eq!(infer(expr), Some(InferredType::not_null(ValueType::Date)));
+        let expr = ArithmeticExprWhere::add(TableField::new("NAME"), 1);
+        assert_eq!(infer(expr), None);
+        assert_eq!(
+            infer(StringFunction::upper(TableField::new("NAME"))),
+            Some(InferredType::not_null(ValueType::String))
+        );
+        assert_eq!(infer(TableField::new("MISSING")), None);
+    }
+
+    #[test]
+    fn test_aggregates() {
+        let cases = [
+            (
+                AggFunction::count("PRICE"),
+                InferredType::not_null(ValueType::Int64),
+            ),
+            (
+                AggFunction::sum("QTY"),
+                InferredType::nullable(ValueType::Int64),
+            ),
+            (
+                AggFunction::avg("QTY"),
+                InferredType::nullable(ValueType::Decimal),
+            ),
+            (
+                AggFunction::max("NAME"),
+                InferredType::nullable(ValueType::String),
+            ),
+        ];
+        for (function, expected) in cases {
+            assert_eq!(infer(function), Some(expected));
+        }
+        assert_eq!(infer(AggFunction::sum("NAME")), None);
+    }
+
+    #[test]
+    fn test_case() {
+        let case = CaseConditionBuilder::new()
+            .when_value(WhenCondition::new(
+                TableField::new("QTY").greater(10),
+                TableField::new("ID"),
+            ))
+            .when_value(WhenCondition::new(
+                TableField::new("QTY").greater(5),
+                TableField::new("QTY"),
+            ))
+            .else_case(NullableValue::Int32(None))
+            .build();
+        assert_eq!(
+            infer(ValueWhere::CaseCondition(Box::new(case))),
+            Some(InferredType::nullable(ValueType::Int64))
+        );
+        let case = CaseConditionBuilder::new()
+            .when_value(WhenCondition::new(
+                TableField::new("QTY").greater(10),
+                TableField::new("ID"),
+            ))
+            .else_case(TableField::new("NAME"))
+            .build();
+        assert_eq!(infer(ValueWhere::CaseCondition(Box::new(case))), None);
+    }
+
+    #[test]
+    fn test_select_columns() {
+        let mut tables = HashMap::new();
+        tables.insert(
+            TableName::from("CUSTOMER"),
+            vec![
+                ("ID".into(), InferredType::not_null(ValueType::Int64)),
+                ("NAME".into(), InferredType::not_null(ValueType::String)),
+            ],
+        );
+        tables.insert(
+            TableName::from("ORDERS"),
+            vec![
+                (
+                    "CUSTOMER_ID".into(),
+                    InferredType::not_null(ValueType::Int64),
+                ),
+                ("TOTAL".into(), InferredType::not_null(ValueType::Decimal)),
+            ],
+        );
+        let sql = r#"WITH "BIG" AS (SELECT "CUSTOMER_ID" AS "ID" FROM "ORDERS" WHERE "TOTAL" > 100)
+            SELECT "c".*,
+                (SELECT MAX("TOTAL") FROM "ORDERS" WHERE "CUSTOMER_ID" = "c"."ID") AS "LAST_TOTAL",
+                "o"."TOTAL" * 2 AS "DOUBLE",
+                ROW_NUMBER() OVER (ORDER BY "c"."NAME") AS "ROW",
+                "b"."ID" AS "BIG_ID"
+            FROM "CUSTOMER" "c"
+            LEFT JOIN "ORDERS" "o" ON "o"."CUSTOMER_ID" = "c"."ID"
+            INNER JOIN "BIG" "b" ON "b"."ID" = "c"."ID""#;
+        let query = Select::parse(sql, &PostgresDialect).unwrap();
+        let columns = query
+            .infer_columns(&tables)
+            .into_iter()
+            .map(|(c, t)| (c.try_field_name().unwrap().to_string(), t))
+            .collect::<Vec<_>>();
+        let column = |name: &str, inferred| (name.to_string(), Some(inferred));
+        assert_eq!(
+            columns,
+            vec![
+                column("ID", InferredType::not_null(ValueType::Int64)),
+                column("NAME", InferredType::not_null(ValueType::String)),
+                column("LAST_TOTAL", InferredType::nullable(ValueType::Decimal)),
+                column("DOUBLE", InferredType::nullable(ValueType::Decimal)),
+                column("ROW", InferredType::not_null(ValueType::Int64)),
+                column("BIG_ID", InferredType::not_null(ValueType::Int64)),
+            ]
+        );
+    }
+}
@@ -0,0 +1,339 @@
+use super::{
+    from::{FromSelect, IntoFrom},
+    insert::returning_to_sql,
+    logical_expr_where::{IntoLogicalExprWhere, LogicalExprWhere},
+    table::{IntoTable, Table},
+    table_field::IntoTablesField,
+    to_sql::ToSQL,
+    value_where::{IntoValueWhere, ValueWhere},
+    values_select::ValuesSelect,
+    LogicalExprWhereOps,
+};
+use crate::dialects::UpdateFromStyle;
+#[cfg(feature = "objects")]
+use crate::objects::{sub_set_values::SubsetValues, value_json::modified_fields_name};
+use crate::{resolvers::args_resolver::ArgsResolver, SQLError};
+use crate::{FieldName, IntoFieldName};
+use serde::{Deserialize, Serialize};
+
+/// Assignment `<field> = <value>` of a SQL `UPDATE`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UpdateSet {
+    pub field_name: FieldName,
+    pub value: ValueWhere,
+}
+
+impl UpdateSet {
+    pub fn new(field_name: impl IntoFieldName, value: impl IntoValueWhere) -> Self {
+        Self {
+            field_name: field_name.into_field_name(),
+            value: value.into_value_where(),
+        }
+    }
+}
+
+impl ToSQL for UpdateSet {
+    fn to_sql(
+        &self,
+        args_resolver: &mut dyn ArgsResolver,
+    ) -> error_stack::Result<String, SQLError> {
+        Ok(format!(
+            "{} = {}",
+            self.field_name.to_sql(args_resolver)?,
+            self.value.to_sql(args_resolver)?
+        ))
+    }
+}
+
+/// UpdateBuilder allow customize creating of a SQL `UPDATE`.
+/// # Example
+/// ```
+/// # use voxi_core::selections::{ArithmeticExprWhere, ConditionWhereOperation, TableField, UpdateBuilder};
+/// # use voxi_core::resolvers::args_resolver_string::args_to_str;
+/// let update = UpdateBuilder::new("PRODUCT")
+///     .set("PRICE", ArithmeticExprWhere::multiply(TableField::new("PRICE"), 2))
+///     .where_c(TableField::new("ID").equal(1))
+///     .build()
+///     .unwrap();
+/// assert_eq!(
+///     args_to_str(&update).unwrap(),
+///     r#"UPDATE "PRODUCT" SET "PRICE" = "PRICE" * 2 WHERE "ID" = 1"#
+/// );
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct UpdateBuilder {
+    table: Table,
+    sets: Vec<UpdateSet>,
+    from: Vec<FromSelect>,
+    where_expr: Option<LogicalExprWhere>,
+    returning: ValuesSelect,
+}
+
+impl UpdateBuilder {
+    /// Create the `UpdateBuilder` for the target table.
+    pub fn new(table: impl IntoTable) -> Self {
+        Self {
+            table: table.into_table(),
+            sets: Vec::new(),
+            from: Vec::new(),
+            where_expr: None,
+            returning: ValuesSelect::empty(),
+        }
+    }
+
+    /// Add assignment `<field> = <value>`.
+    #[must_use]
+    pub fn set(mut self, field_name: impl IntoFieldName, value: impl IntoValueWhere) -> Self {
+        self.sets.push(UpdateSet::new(field_name, value));
+        self
+    }
+
+    /// Add a table or sub-query to be read by the update (`UPDATE .. FROM`).
+    #[must_use]
+    pub fn from(mut self, from: impl IntoFrom) -> Self {
+        self.from.push(from.into_from());
+        self
+    }
+
+    /// Define where condition, if already exists a condition it will be combined with `AND`.
+    #[must_use]
+    pub fn where_c(mut self, expression: impl IntoLogicalExprWhere) -> Self {
+        let new_exp = expression.into_logical_expr_where();
+        self.where_expr = Some(match self.where_expr {
+            Some(expr) => expr.and(new_exp),
+            None => new_exp,
+        });
+        self
+    }
+
+    /// Add fields to be returned by the statement (`RETURNING`).
+    #[must_use]
+    pub fn returning(mut self, columns: impl IntoTablesField) -> Self {
+        for table_field in columns.into_tables_field() {
+            self.returning.push(table_field);
+        }
+        self
+    }
+
+    pub fn build(self) -> Result<Update, SQLError> {
+        if self.sets.is_empty() {
+            return Err(SQLError::InvalidQueryBuilderConfiguration(
+                "no assignment has been defined".to_string(),
+            ));
+        }
+        Ok(Update {
+            table: self.table,
+            sets: self.sets,
+            from: self.from,
+            where_expr: self.where_expr,
+            returning: self.returning,
+        })
+    }
+}
+
+/// SQL `UPDATE` statement.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Update {
+    pub table: Table,
+    pub sets: Vec<UpdateSet>,
+    pub from: Vec<FromSelect>,
+    #[serde(rename = "where")]
+    pub where_expr: Option<LogicalExprWhere>,
+    pub returning: ValuesSelect,
+}
+
+impl Update {
+    /// Create an update only of the fields modified from `old` to `new`.
+    /// Returns `None` when no field has been modified.
+    /// # Example
+    /// ```
+    /// # use voxi_core::objects::sub_set_values::SubsetValues;
+    /// # use voxi_core::selections::{ConditionWhereOperation, TableField, Update};
+    /// # use voxi_core::resolvers::args_resolver_string::args_to_str;
+    /// let mut old = SubsetValues::new();
+    /// old.set_value("ID", 1);
+    /// old.set_value("NAME", "A");
+    /// let mut new = old.clone();
+    /// new.set_value("NAME", "B");
+    /// let update = Update::from_subset_values_diff("TABLE", &old, &new, TableField::new("ID").equal(1));
+    /// assert_eq!(
+    ///     args_to_str(&update.unwrap()).unwrap(),
+    ///     r#"UPDATE "TABLE" SET "NAME" = 'B' WHERE "ID" = 1"#
+    /// );
+    /// ```
+    #[cfg(feature = "objects")]
+    pub fn from_subset_values_diff(
+        table: impl IntoTable,
+        old: &SubsetValues,
+        new: &SubsetValues,
+        where_expr: impl IntoLogicalExprWhere,
+    ) -> Option<Self> {
+        let builder = modified_fields_name(old.object_j(), new.object_j())
+            .into_iter()
+            .filter_map(|name| new.by_name(name.as_str()).map(|v| (name, v)))
+            .fold(UpdateBuilder::new(table), |builder, (name, value)| {
+                builder.set(name, value.opt_value.clone())
+            });
+        builder.where_c(where_expr).build().ok()
+    }
+
+    /// Target table.
+    pub fn table(&self) -> &Table {
+        &self.table
+    }
+
+    pub fn sets(&self) -> &[UpdateSet] {
+        self.sets.as_ref()
+    }
+
+    /// Where expression.
+    pub fn where_expr(&self) -> Option<&LogicalExprWhere> {
+        self.where_expr.as_ref()
+    }
+}
+
+impl ToSQL for Update {
+    fn to_sql(
+        &self,
+        args_resolver: &mut dyn ArgsResolver,
+    ) -> error_stack::Result<String, SQLError> {
+        // Arguments must be resolved in the same order they appear in the SQL.
+        let from_style = args_resolver.dialect().update_from_style();
+        let target = match (from_style, self.table.alias()) {
+            (UpdateFromStyle::FromWithTarget, Some(alias)) if !self.from.is_empty() => {
+                alias.to_sql(args_resolver)?
+            }
+            (UpdateFromStyle::FromWithTarget, None) if !self.from.is_empty() => {
+                self.table.name().to_sql(args_resolver)?
+            }
+            _ => self.table.to_sql(args_resolver)?,
+        };
+        let mut sql = format!("UPDATE {target}");
+        if from_style == UpdateFromStyle::TableList {
+            for from in self.from.iter() {
+                sql.push_str(&format!(", {}", from.to_sql(args_resolver)?));
+            }
+        }
+
+        let sets = self
+            .sets
+            .iter()
+            .map(|s| s.to_sql(args_resolver))
+            .collect::<Result<Vec<_>, _>>()?
+            .join(", ");
+        sql.push_str(&format!(" SET {sets}"));
+
+        if from_style != UpdateFromStyle::TableList && !self.from.is_empty() {
+            let mut from = self
+                .from
+                .iter()
+                .map(|f| f.to_sql(args_resolver))
+                .collect::<Result<Vec<_>, _>>()?;
+            if from_style == UpdateFromStyle::FromWithTarget {
+                from.insert(0, self.table.to_sql(args_resolver)?);
+            }
+            sql.push_str(&format!(" FROM {}", from.join(", ")));
+        }
+
+        if let Some(w) = self.where_expr.as_ref() {
+            sql.push_str(&format!(" WHERE {}", w.to_sql(args_resolver)?));
+        }
+
+        if !self.returning.is_empty() {
+            sql.push_str(&returning_to_sql(&self.returning, args_resolver)?);
+        }
+        Ok(sql)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dialects::{MsSqlDialect, MySqlDialect};
+    use crate::resolvers::args_resolver_string::{args_to_str, args_to_str_with_dialect};
+    use crate::selections::{ArithmeticExprWhere, ConditionWhereOperation, TableField};
+    use pretty_assertions::assert_eq;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_update_arithmetic() {
+        let update = UpdateBuilder::new("PRODUCT")
+            .set(
+                "PRICE",
+                ArithmeticExprWhere::multiply(
+                    TableField::new("PRICE"),
+                    dec!(1.1).into_value_where(),
+                ),
+            )
+            .set("NAME", "O'Brien")
+            .where_c(TableField::new("ID").equal(1))
+            .returning("ID")
+            .build()
+            .unwrap();
+        assert_eq!(
+            args_to_str(&update).unwrap(),
+            r#"UPDATE "PRODUCT" SET "PRICE" = "PRICE" * 1.1, "NAME" = 'O''Brien' WHERE "ID" = 1 RETURNING "ID""#
+        );
+    }
+
+    fn update_from() -> Update {
+        UpdateBuilder::new(Table::from("PRODUCT", "P"))
+            .set("PRICE", TableField::new("NEW_PRICE"))
+            .from(Table::from("PRICE_LIST", "L"))
+            .where_c(TableField::new(("P", "ID")).equal(TableField::new(("L", "PRODUCT_ID"))))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_update_from() {
+        assert_eq!(
+            args_to_str(&update_from()).unwrap(),
+            r#"UPDATE "PRODUCT" AS "P" SET "PRICE" = "NEW_PRICE" FROM "PRICE_LIST" "L" WHERE "P"."ID" = "L"."PRODUCT_ID""#
+        );
+        assert_eq!(
+            args_to_str_with_dialect(&update_from(), MySqlDialect).unwrap(),
+            "UPDATE `PRODUCT` AS `P`, `PRICE_LIST` `L` SET `PRICE` = `NEW_PRICE` WHERE `P`.`ID` = `L`.`PRODUCT_ID`"
+        );
+        assert_eq!(
+            args_to_str_with_dialect(&update_from(), MsSqlDialect).unwrap(),
+            "UPDATE [P] SET [PRICE] = [NEW_PRICE] FROM [PRODUCT] AS [P], [PRICE_LIST] [L] WHERE [P].[ID] = [L].[PRODUCT_ID]"
+        );
+    }
+
+    #[test]
+    fn test_update_without_set() {
+        assert!(UpdateBuilder::new("PRODUCT").build().is_err());
+    }
+
+    #[cfg(feature = "objects")]
+    #[test]
+    fn test_update_from_subset_values_diff() {
+        let mut old = SubsetValues::new();
+        old.set_value("ID", 1);
+        old.set_value("NAME", "A");
+        old.set_value("PRICE", dec!(10.5));
+        assert_eq!(
+            Update::from_subset_values_diff("TABLE", &old, &old, TableField::new("ID").equal(1)),
+            None
+        );
+        let mut new = old.clone();
+        new.set_value("NAME", "B");
+        new.set_value("PRICE", dec!(11));
+        let update =
+            Update::from_subset_values_diff("TABLE", &old, &new, TableField::new("ID").equal(1))
+                .unwrap();
+        assert_eq!(
+            args_to_str(&update).unwrap(),
+            r#"UPDATE "TABLE" SET "NAME" = 'B', "PRICE" = 11 WHERE "ID" = 1"#
+        );
+    }
+
+    #[test]
+    fn test_update_serialize() {
+        let update = update_from();
+        let json = serde_json::to_string(&update).unwrap();
+        let update_de: Update = serde_json::from_str(&json).unwrap();
+        assert_eq!(update, update_de);
+    }
+}
@@ -0,0 +1,349 @@
+use super::{
+    insert::{returning_to_sql, Insert},
+    to_sql::ToSQL,
+    value_where::{IntoValueWhere, ValueWhere},
+};
+use crate::dialects::UpsertStyle;
+use crate::{resolvers::args_resolver::ArgsResolver, SQLError};
+use crate::{FieldName, IntoFieldName};
+use serde::{Deserialize, Serialize};
+
+/// Value assigned to a column when the inserted row conflicts with an existing one.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum UpsertValue {
+    /// Value proposed for the column by the inserted row (`EXCLUDED.<field>`).
+    Excluded(FieldName),
+    /// Any expression, it can reference the current values of the row.
+    Value(ValueWhere),
+}
+
+impl ToSQL for UpsertValue {
+    fn to_sql(
+        &self,
+        args_resolver: &mut dyn ArgsResolver,
+    ) -> error_stack::Result<String, SQLError> {
+        let sql = match self {
+            UpsertValue::Excluded(field_name) => {
+                let field = field_name.to_sql(args_resolver)?;
+                match args_resolver.dialect().upsert_style() {
+                    UpsertStyle::OnDuplicateKey => format!("VALUES({field})"),
+                    _ => format!("EXCLUDED.{field}"),
+                }
+            }
+            UpsertValue::Value(value_where) => value_where.to_sql(args_resolver)?,
+        };
+        Ok(sql)
+    }
+}
+
+/// Assignment `<field> = <value>` applied to the existing row on conflict.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UpsertSet {
+    pub field_name: FieldName,
+    pub value: UpsertValue,
+}
+
+impl UpsertSet {
+    /// Assign the value proposed by the inserted row.
+    pub fn excluded(field_name: impl IntoFieldName) -> Self {
+        let field_name = field_name.into_field_name();
+        Self {
+            value: UpsertValue::Excluded(field_name.clone()),
+            field_name,
+        }
+    }
+
+    /// Assign an expression.
+    pub fn value(field_name: impl IntoFieldName, value: impl IntoValueWhere) -> Self {
+        Self {
+            field_name: field_name.into_field_name(),
+            value: UpsertValue::Value(value.into_value_where()),
+        }
+    }
+}
+
+impl ToSQL for UpsertSet {
+    fn to_sql(
+        &self,
+        args_resolver: &mut dyn ArgsResolver,
+    ) -> error_stack::Result<String, SQLError> {
+        Ok(format!(
+            "{} = {}",
+            self.field_name.to_sql(args_resolver)?,
+            self.value.to_sql(args_resolver)?
+        ))
+    }
+}
+
+/// Action executed when the inserted row conflicts with an existing one.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ConflictAction {
+    DoNothing,
+    DoUpdate(Vec<UpsertSet>),
+}
+
+/// Conflict clause of an `INSERT`, making it an upsert.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OnConflict {
+    /// Columns of the unique constraint used to detect the conflict.
+    pub target: Vec<FieldName>,
+    pub action: ConflictAction,
+}
+
+impl OnConflict {
+    pub fn new(target: Vec<FieldName>, action: ConflictAction) -> Self {
+        Self { target, action }
+    }
+
+    fn sets_to_sql(
+        sets: &[UpsertSet],
+        args_resolver: &mut dyn ArgsResolver,
+    ) -> error_stack::Result<String, SQLError> {
+        Ok(sets
+            .iter()
+            .map(|s| s.to_sql(args_resolver))
+            .collect::<Result<Vec<_>, _>>()?
+            .join(", "))
+    }
+
+    /// Render the clause placed after the inserted rows, for `ON CONFLICT` and `ON DUPLICATE KEY` styles.
+    pub(crate) fn to_sql_suffix(
+        &self,
+        columns: &[FieldName],
+        args_resolver: &mut dyn ArgsResolver,
+    ) -> error_stack::Result<String, SQLError> {
+        if args_resolver.dialect().upsert_style() == UpsertStyle::OnDuplicateKey {
+            let sets = match &self.action {
+                ConflictAction::DoUpdate(sets) => Self::sets_to_sql(sets, args_resolver)?,
+                // A no-op assignment, so the conflicting row is kept untouched.
+                ConflictAction::DoNothing => {
+                    let field = columns.first().ok_or_else(|| {
+                        SQLError::SQLResolver(
+                            "ON DUPLICATE KEY without update requires an inserted column"
+                                .to_string(),
+                        )
+                    })?;
+                    let field = field.to_sql(args_resolver)?;
+                    format!("{field} = {field}")
+                }
+            };
+            return Ok(format!(" ON DUPLICATE KEY UPDATE {sets}"));
+        }
+
+        let mut sql = " ON CONFLICT".to_string();
+        if !self.target.is_empty() {
+            let target = self
+                .target
+                .iter()
+                .map(|f| f.to_sql(args_resolver))
+                .collect::<Result<Vec<_>, _>>()?
+                .join(", ");
+            sql.push_str(&format!(" ({target})"));
+        }
+        match &self.action {
+            ConflictAction::DoNothing => sql.push_str(" DO NOTHING"),
+            ConflictAction::DoUpdate(sets) => sql.push_str(&format!(
+                " DO UPDATE SET {}",
+                Self::sets_to_sql(sets, args_resolver)?
+            )),
+        }
+        Ok(sql)
+    }
+}
+
+/// Render an upsert as a `MERGE` statement, the inserted rows are aliased as `EXCLUDED`.
+pub(crate) fn merge_to_sql(
+    insert: &Insert,
+    on_conflict: &OnConflict,
+    args_resolver: &mut dyn ArgsResolver,
+) -> error_stack::Result<String, SQLError> {
+    if on_conflict.target.is_empty() {
+        return Err(SQLError::SQLResolver(format!(
+            "conflict target columns are required by {}",
+            args_resolver.dialect().name()
+        ))
+        .into());
+    }
+    let table = insert.table.to_sql(args_resolver)?;
+    let columns = insert
+        .columns
+        .iter()
+        .map(|c| c.to_sql(args_resolver))
+        .collect::<Result<Vec<_>, _>>()?;
+    let source = insert.source.to_sql(args_resolver)?;
+    let matches = on_conflict
+        .target
+        .iter()
+        .map(|f| {
+            let field = f.to_sql(args_resolver)?;
+            Ok(format!("{table}.{field} = EXCLUDED.{field}"))
+        })
+        .collect::<error_stack::Result<Vec<_>, SQLError>>()?
+        .join(" AND ");
+
+    let mut sql = format!(
+        "MERGE INTO {table} USING ({source}) AS EXCLUDED ({}) ON {matches}",
+        columns.join(", ")
+    );
+    if let ConflictAction::DoUpdate(sets) = &on_conflict.action {
+        sql.push_str(&format!(
+            " WHEN MATCHED THEN UPDATE SET {}",
+            OnConflict::sets_to_sql(sets, args_resolver)?
+        ));
+    }
+    let excluded = columns
+        .iter()
+        .map(|c| format!("EXCLUDED.{c}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    sql.push_str(&format!(
+        " WHEN NOT MATCHED THEN INSERT ({}) VALUES ({excluded})",
+        columns.join(", ")
+    ));
+    if !insert.returning.is_empty() {
+        sql.push_str(&returning_to_sql(&insert.returning, args_resolver)?);
+    }
+    sql.push(';');
+    Ok(sql)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dialects::{MsSqlDialect, MySqlDialect, SqliteDialect};
+    use crate::resolvers::args_resolver_string::{args_to_str, args_to_str_with_dialect};
+    use crate::selections::{ArithmeticExprWhere, InsertBuilder, TableField};
+    use crate::IntoNullableValue;
+    use pretty_assertions::assert_eq;
+
+    fn upsert() -> Insert {
+        InsertBuilder::new("STOCK")
+            .columns(vec!["ID", "NAME", "QTY"])
+            .values(vec![
+                1.into_nullable_value(),
+                "A".into_nullable_value(),
+                5.into_nullable_value(),
+            ])
+            .on_conflict(vec!["ID"])
+            .update_excluded("NAME")
+            .update_set(
+                "QTY",
+                ArithmeticExprWhere::add(TableField::new(("STOCK", "QTY")), 5),
+            )
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_upsert_postgres() {
+        assert_eq!(
+            args_to_str(&upsert()).unwrap(),
+            r#"INSERT INTO "STOCK" ("ID", "NAME", "QTY") VALUES (1, 'A', 5) ON CONFLICT ("ID") DO UPDATE SET "NAME" = EXCLUDED."NAME", "QTY" = "STOCK"."QTY" + 5"#
+        );
+        assert_eq!(
+            args_to_str_with_dialect(&upsert(), SqliteDialect).unwrap(),
+            r#"INSERT INTO "STOCK" ("ID", "NAME", "QTY") VALUES (1, 'A', 5) ON CONFLICT ("ID") DO UPDATE SET "NAME" = EXCLUDED."NAME", "QTY" = "STOCK"."QTY" + 5"#
+        );
+    }
+
+    #[test]
+    fn test_upsert_mysql() {
+        assert_eq!(
+            args_to_str_with_dialect(&upsert(), MySqlDialect).unwrap(),
+            "INSERT INTO `STOCK` (`ID`, `NAME`, `QTY`) VALUES (1, 'A', 5) ON DUPLICATE KEY UPDATE `NAME` = VALUES(`NAME`), `QTY` = `STOCK`.`QTY` + 5"
+        );
+    }
+
+    #[test]
+    fn test_upsert_mssql() {
+        assert_eq!(
+            args_to_str_with_dialect(&upsert(), MsSqlDialect).unwrap(),
+            "MERGE INTO [STOCK] USING (VALUES (1, 'A', 5)) AS EXCLUDED ([ID], [NAME], [QTY]) ON [STOCK].[ID] = EXCLUDED.[ID] WHEN MATCHED THEN UPDATE SET [NAME] = EXCLUDED.[NAME], [QTY] = [STOCK].[QTY] + 5 WHEN NOT MATCHED THEN INSERT ([ID], [NAME], [QTY]) VALUES (EXCLUDED.[ID], EXCLUDED.[NAME], EXCLUDED.[QTY]);"
+        );
+    }
+
+    #[test]
+    fn test_upsert_do_nothing() {
+        let insert = InsertBuilder::new("STOCK")
+            .column("ID")
+            .values(vec![1])
+            .on_conflict(vec!["ID"])
+            .do_nothing()
+            .build()
+            .unwrap();
+        assert_eq!(
+            args_to_str(&insert).unwrap(),
+            r#"INSERT INTO "STOCK" ("ID") VALUES (1) ON CONFLICT ("ID") DO NOTHING"#
+        );
+        assert_eq!(
+            args_to_str_with_dialect(&insert, MySqlDialect).unwrap(),
+            "INSERT INTO `STOCK` (`ID`) VALUES (1) ON DUPLICATE KEY UPDATE `ID` = `ID`"
+        );
+        assert_eq!(
+            args_to_str_with_dialect(&insert, MsSqlDialect).unwrap(),
+            "MERGE INTO [STOCK] USING (VALUES (1)) AS EXCLUDED ([ID]) ON [STOCK].[ID] = EXCLUDED.[ID] WHEN NOT MATCHED THEN INSERT ([ID]) VALUES (EXCLUDED.[ID]);"
+        );
+    }
+
+    #[test]
+    fn test_upsert_do_nothing_without_columns() {
+        let mut insert = InsertBuilder::new("STOCK")
+            .column("ID")
+            .values(vec![1])
+            .on_conflict(vec!["ID"])
+            .do_nothing()
+            .build()
+            .unwrap();
+        insert.columns.clear();
+        assert!(args_to_str_with_dialect(&insert, MySqlDialect).is_err());
+    }
+
+    #[test]
+    fn test_upsert_target_last() {
+        let insert = InsertBuilder::new("STOCK")
+            .columns(vec!["ID", "NAME", "QTY"])
+            .values(vec![
+                1.into_nullable_value(),
+                "A".into_nullable_value(),
+                5.into_nullable_value(),
+            ])
+            .update_excluded("NAME")
+            .update_set(
+                "QTY",
+                ArithmeticExprWhere::add(TableField::new(("STOCK", "QTY")), 5),
+            )
+            .on_conflict(vec!["ID"])
+            .build()
+            .unwrap();
+        assert_eq!(insert, upsert());
+        let insert = InsertBuilder::new("STOCK")
+            .column("ID")
+            .values(vec![1])
+            .do_nothing()
+            .on_conflict(vec!["ID"])
+            .build()
+            .unwrap();
+        assert_eq!(
+            args_to_str(&insert).unwrap(),
+            r#"INSERT INTO "STOCK" ("ID") VALUES (1) ON CONFLICT ("ID") DO NOTHING"#
+        );
+    }
+
+    #[test]
+    fn test_upsert_without_sets() {
+        let result = InsertBuilder::new("STOCK")
+            .column("ID")
+            .values(vec![1])
+            .on_conflict(vec!["ID"])
+            .build();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_upsert_serialize() {
+        let insert = upsert();
+        let json = serde_json::to_string(&insert).unwrap();
+        let insert_de: Insert = serde_json::from_str(&json).unwrap();
+        assert_eq!(insert, insert_de);
+    }
+}
@@ -1,9 +1,10 @@
 use super::{
     alias::{Alias, IntoAlias},
     table_name::TableName,
-    tables_names::TablesNames,
+    tables_names::{TablesNames, TablesNamesVisitor},
     to_sql::ToSQL,
     value_where::ValueWhere,
+    visitor::Visitor,
     IntoValueWhere,
 };
 use crate::FieldName;
@@ -123,7 +124,10 @@ impl ToSQL for ValueSelect {
     ) -> error_stack::Result<String, SQLError> {
         let column = self.value_select_type().to_sql(args_resolver)?;
         let sql = match &self.alias {
-            Some(alias) => format!(r#"{column} AS "{alias}""#),
+            Some(alias) => format!(
+                "{column} AS {}",
+                args_resolver.dialect().quote_identifier(&alias.to_string())
+            ),
             None => column,
         };
         Ok(sql)
@@ -132,8 +136,7 @@ impl ToSQL for ValueSelect {
 
 impl TablesNames for ValueSelect {
     fn tables_names(&self) -> HashSet<&TableName> {
-        // TODO: implement
-        todo!()
+        TablesNamesVisitor::collect(|visitor| visitor.visit_value_select(self))
     }
 }
 
@@ -6,10 +6,14 @@ use super::{
     string_functions::StringFunction,
     table_field::TableField,
     to_sql::ToSQL,
+    window_function::WindowFunction,
     ArithmeticExprWhere,
 };
 use crate::IntoNullableValue;
-use crate::{resolvers::args_resolver::ArgsResolver, SQLError};
+use crate::{
+    resolvers::{args_resolver::ArgsResolver, sql_format::nested},
+    SQLError,
+};
 use crate::{FieldName, NullableValue};
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -34,6 +38,8 @@ pub enum ValueWhere {
     CaseValue(Box<CaseValue>),
     #[serde(rename = "sf")]
     StringFunction(Box<StringFunction>),
+    #[serde(rename = "wf")]
+    WindowFunction(Box<WindowFunction>),
 }
 
 impl fmt::Display for ValueWhere {
@@ -48,6 +54,7 @@ impl fmt::Display for ValueWhere {
             ValueWhere::CaseCondition(_) => write!(f, "CaseCondition"),
             ValueWhere::CaseValue(_) => write!(f, "CaseValue"),
             ValueWhere::StringFunction(_) => write!(f, "StringFunction"),
+            ValueWhere::WindowFunction(_) => write!(f, "WindowFunction"),
         }
     }
 }
@@ -71,7 +78,10 @@ impl ToSQL for ValueWhere {
             ValueWhere::CaseValue(c) => c.to_sql(args_resolver),
             ValueWhere::AggFunction(f) => f.to_sql(args_resolver),
             ValueWhere::StringFunction(f) => f.to_sql(args_resolver),
-            ValueWhere::SingleQuery(sq) => sq.to_sql(args_resolver).map(|s| format!("({s})")),
+            ValueWhere::WindowFunction(f) => f.to_sql(args_resolver),
+            ValueWhere::SingleQuery(sq) => {
+                nested(args_resolver, |r| sq.to_sql(r)).map(|s| format!("({s})"))
+            }
             ValueWhere::BindParameter(bn) => args_resolver
                 .add_bind(bn.clone())
                 .ok_or_else(|| SQLError::BindNameNotFound(bn.name().to_string()))?
@@ -154,6 +164,12 @@ impl IntoValueWhere for StringFunction {
     }
 }
 
+impl IntoValueWhere for WindowFunction {
+    fn into_value_where(self) -> ValueWhere {
+        ValueWhere::WindowFunction(Box::new(self))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{FieldName, IntoFieldName, IntoNullableValue};
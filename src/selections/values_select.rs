@@ -1,5 +1,11 @@
-use super::{IntoValueSelect, TableName, TablesNames, ToSQL, ValueSelect};
-use crate::{resolvers::args_resolver::ArgsResolver, SQLError};
+use super::{
+    tables_names::TablesNamesVisitor, visitor::Visitor, IntoValueSelect, TableName, TablesNames,
+    ToSQL, ValueSelect,
+};
+use crate::{
+    resolvers::{args_resolver::ArgsResolver, sql_format::line_break},
+    SQLError,
+};
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 
@@ -80,20 +86,23 @@ impl ToSQL for Vec<ValueSelect> {
         &self,
         args_resolver: &mut dyn ArgsResolver,
     ) -> error_stack::Result<String, SQLError> {
+        let separator = format!(",{}", line_break(args_resolver, ""));
         let sql = self
             .iter()
             .map(|v| v.to_sql(args_resolver))
             .collect::<Result<Vec<_>, _>>()?
-            .join(",");
+            .join(&separator);
         Ok(sql)
     }
 }
 
 impl TablesNames for Vec<ValueSelect> {
     fn tables_names(&self) -> HashSet<&TableName> {
-        self.iter()
-            .flat_map(|v| v.tables_names())
-            .collect::<HashSet<_>>()
+        TablesNamesVisitor::collect(|visitor| {
+            for value in self.iter() {
+                visitor.visit_value_select(value);
+            }
+        })
     }
 }
 
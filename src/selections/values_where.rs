@@ -1,14 +1,25 @@
 use super::{
+    select::Select,
     single_select::SingleQuery,
     to_sql::ToSQL,
     value_where::{IntoValueWhere, ValueWhere},
 };
-use crate::{resolvers::args_resolver::ArgsResolver, SQLError};
+use crate::{
+    resolvers::{args_resolver::ArgsResolver, sql_format::nested},
+    SQLError,
+};
 use serde::{Deserialize, Serialize};
 
 /// Represents a list of `ValueWhere`, to be used to represent a list `IN (n1, n2..)`.
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ValuesWhere(Vec<ValueWhere>);
+pub struct ValuesWhere(pub(crate) Vec<ValueWhere>);
+
+impl ValuesWhere {
+    /// Get a reference to the values list.
+    pub fn values_where(&self) -> &[ValueWhere] {
+        self.0.as_ref()
+    }
+}
 
 impl ToSQL for ValuesWhere {
     fn to_sql(
@@ -49,6 +60,7 @@ pub trait IntoValuesWhere {
 pub enum ValuesListWhere {
     List(ValuesWhere),
     SingleSelect(Box<SingleQuery>),
+    Select(Box<Select>),
 }
 
 impl ToSQL for ValuesListWhere {
@@ -58,7 +70,10 @@ impl ToSQL for ValuesListWhere {
     ) -> error_stack::Result<String, SQLError> {
         match self {
             ValuesListWhere::List(values) => values.to_sql(args_resolver),
-            ValuesListWhere::SingleSelect(single_select) => single_select.to_sql(args_resolver),
+            ValuesListWhere::SingleSelect(single_select) => {
+                nested(args_resolver, |r| single_select.to_sql(r))
+            }
+            ValuesListWhere::Select(select) => nested(args_resolver, |r| select.to_sql(r)),
         }
     }
 }
@@ -79,6 +94,12 @@ impl IntoValuesListWhere for SingleQuery {
     }
 }
 
+impl IntoValuesListWhere for Select {
+    fn into_values(self) -> ValuesListWhere {
+        ValuesListWhere::Select(Box::new(self))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::IntoNullableValue;
@@ -0,0 +1,1104 @@
+//! Generic traversal over the SQL AST.
+//! `Visitor` inspects the nodes by reference and `VisitorMut` can rewrite them in place, both
+//! reaching every node of `Select`, `Insert`, `Update` and `Delete`, including sub-queries.
+use super::{
+    agg_functions::AggFunction,
+    bind_name::BindName,
+    case::{
+        case_condition::CaseCondition, case_value::CaseValue, when_condition::WhenCondition,
+        when_value::WhenValue,
+    },
+    ArithmeticExprWhere, Combination, ConditionWhere, ConflictAction, Cte, Delete, FromSelect,
+    FromType, GroupBy, Insert, InsertSource, Join, LogicalExprWhere, NamedWindow, OnConflict,
+    OrderBy, Over, Select, SingleQuery, StringFunction, Table, TableField, TableName, Update,
+    UpsertValue, ValueSelect, ValueWhere, ValuesListWhere, WindowFunction, WindowFunctionType,
+    WindowSpec,
+};
+use crate::NullableValue;
+
+/// Read-only traversal over the AST.
+/// Each `visit_*` method walks into the children of the node by default, calling the matching
+/// `walk_*` function; override it to inspect the node and call `walk_*` to keep walking.
+pub trait Visitor<'ast> {
+    fn visit_select(&mut self, select: &'ast Select) {
+        walk_select(self, select);
+    }
+
+    fn visit_cte(&mut self, cte: &'ast Cte) {
+        walk_cte(self, cte);
+    }
+
+    fn visit_value_select(&mut self, value_select: &'ast ValueSelect) {
+        walk_value_select(self, value_select);
+    }
+
+    fn visit_from_select(&mut self, from_select: &'ast FromSelect) {
+        walk_from_select(self, from_select);
+    }
+
+    fn visit_join(&mut self, join: &'ast Join) {
+        walk_join(self, join);
+    }
+
+    fn visit_combination(&mut self, combination: &'ast Combination) {
+        walk_combination(self, combination);
+    }
+
+    fn visit_single_query(&mut self, single_query: &'ast SingleQuery) {
+        walk_single_query(self, single_query);
+    }
+
+    fn visit_logical_expr(&mut self, logical_expr: &'ast LogicalExprWhere) {
+        walk_logical_expr(self, logical_expr);
+    }
+
+    fn visit_condition(&mut self, condition: &'ast ConditionWhere) {
+        walk_condition(self, condition);
+    }
+
+    fn visit_value_where(&mut self, value_where: &'ast ValueWhere) {
+        walk_value_where(self, value_where);
+    }
+
+    fn visit_values_list(&mut self, values_list: &'ast ValuesListWhere) {
+        walk_values_list(self, values_list);
+    }
+
+    fn visit_arithmetic_expr(&mut self, arithmetic_expr: &'ast ArithmeticExprWhere) {
+        walk_arithmetic_expr(self, arithmetic_expr);
+    }
+
+    fn visit_case_condition(&mut self, case: &'ast CaseCondition) {
+        walk_case_condition(self, case);
+    }
+
+    fn visit_case_value(&mut self, case: &'ast CaseValue) {
+        walk_case_value(self, case);
+    }
+
+    fn visit_when_condition(&mut self, when: &'ast WhenCondition) {
+        walk_when_condition(self, when);
+    }
+
+    fn visit_when_value(&mut self, when: &'ast WhenValue) {
+        walk_when_value(self, when);
+    }
+
+    fn visit_agg_function(&mut self, function: &'ast AggFunction) {
+        walk_agg_function(self, function);
+    }
+
+    fn visit_string_function(&mut self, function: &'ast StringFunction) {
+        walk_string_function(self, function);
+    }
+
+    fn visit_window_function(&mut self, function: &'ast WindowFunction) {
+        walk_window_function(self, function);
+    }
+
+    fn visit_window_spec(&mut self, spec: &'ast WindowSpec) {
+        walk_window_spec(self, spec);
+    }
+
+    fn visit_named_window(&mut self, window: &'ast NamedWindow) {
+        walk_named_window(self, window);
+    }
+
+    fn visit_order_by(&mut self, order_by: &'ast OrderBy) {
+        walk_order_by(self, order_by);
+    }
+
+    fn visit_group_by(&mut self, group_by: &'ast GroupBy) {
+        walk_group_by(self, group_by);
+    }
+
+    fn visit_table_field(&mut self, table_field: &'ast TableField) {
+        walk_table_field(self, table_field);
+    }
+
+    fn visit_table(&mut self, table: &'ast Table) {
+        walk_table(self, table);
+    }
+
+    fn visit_insert(&mut self, insert: &'ast Insert) {
+        walk_insert(self, insert);
+    }
+
+    fn visit_update(&mut self, update: &'ast Update) {
+        walk_update(self, update);
+    }
+
+    fn visit_delete(&mut self, delete: &'ast Delete) {
+        walk_delete(self, delete);
+    }
+
+    fn visit_table_name(&mut self, _table_name: &'ast TableName) {}
+
+    fn visit_bind_name(&mut self, _bind_name: &'ast BindName) {}
+
+    fn visit_literal(&mut self, _value: &'ast NullableValue) {}
+}
+
+pub fn walk_select<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, select: &'ast Select) {
+    for cte in select.ctes.iter() {
+        visitor.visit_cte(cte);
+    }
+    for column in select.columns.values_select.iter() {
+        visitor.visit_value_select(column);
+    }
+    for from in select.from.iter() {
+        visitor.visit_from_select(from);
+    }
+    for join in select.joins.iter() {
+        visitor.visit_join(join);
+    }
+    if let Some(where_expr) = &select.where_expr {
+        visitor.visit_logical_expr(where_expr);
+    }
+    for group in select.groups.iter() {
+        visitor.visit_group_by(group);
+    }
+    if let Some(having_expr) = &select.having_expr {
+        visitor.visit_logical_expr(having_expr);
+    }
+    for window in select.windows.iter() {
+        visitor.visit_named_window(window);
+    }
+    for order_by in select.orders_by.orders_by.iter() {
+        visitor.visit_order_by(order_by);
+    }
+    if let Some(combination) = &select.combination {
+        visitor.visit_combination(combination);
+    }
+}
+
+pub fn walk_cte<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, cte: &'ast Cte) {
+    visitor.visit_table_name(&cte.name);
+    visitor.visit_select(&cte.query);
+    if let Some(recursive) = &cte.recursive {
+        visitor.visit_select(recursive);
+    }
+}
+
+pub fn walk_value_select<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    value_select: &'ast ValueSelect,
+) {
+    visitor.visit_value_where(&value_select.value_where);
+}
+
+pub fn walk_from_select<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    from_select: &'ast FromSelect,
+) {
+    match &from_select.from_type {
+        FromType::Table(table_name) => visitor.visit_table_name(table_name),
+        FromType::Query(query) => visitor.visit_select(query),
+    }
+}
+
+pub fn walk_join<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, join: &'ast Join) {
+    visitor.visit_from_select(&join.from);
+    visitor.visit_logical_expr(&join.condition);
+}
+
+pub fn walk_combination<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    combination: &'ast Combination,
+) {
+    visitor.visit_select(&combination.query);
+}
+
+pub fn walk_single_query<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    single_query: &'ast SingleQuery,
+) {
+    visitor.visit_select(&single_query.query);
+}
+
+pub fn walk_logical_expr<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    logical_expr: &'ast LogicalExprWhere,
+) {
+    match logical_expr {
+        LogicalExprWhere::Condition(condition) => visitor.visit_condition(condition),
+        LogicalExprWhere::Not(expr) | LogicalExprWhere::Expression(expr) => {
+            visitor.visit_logical_expr(expr)
+        }
+        LogicalExprWhere::And(left, right) | LogicalExprWhere::Or(left, right) => {
+            visitor.visit_logical_expr(left);
+            visitor.visit_logical_expr(right);
+        }
+    }
+}
+
+pub fn walk_condition<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    condition: &'ast ConditionWhere,
+) {
+    match condition {
+        ConditionWhere::Expression(expr) => visitor.visit_arithmetic_expr(expr),
+        ConditionWhere::ConditionNull(value) | ConditionWhere::ConditionNotNull(value) => {
+            visitor.visit_value_where(value)
+        }
+        ConditionWhere::ConditionEq(left, right)
+        | ConditionWhere::ConditionDf(left, right)
+        | ConditionWhere::ConditionGt(left, right)
+        | ConditionWhere::ConditionLs(left, right)
+        | ConditionWhere::ConditionGe(left, right)
+        | ConditionWhere::ConditionLe(left, right)
+        | ConditionWhere::ConditionLk(left, right)
+        | ConditionWhere::ConditionNotLk(left, right)
+        | ConditionWhere::ConditionILk(left, right)
+        | ConditionWhere::ConditionDistinct(left, right)
+        | ConditionWhere::ConditionNotDistinct(left, right) => {
+            visitor.visit_value_where(left);
+            visitor.visit_value_where(right);
+        }
+        ConditionWhere::ConditionIn(value, values)
+        | ConditionWhere::ConditionNotIn(value, values) => {
+            visitor.visit_value_where(value);
+            visitor.visit_values_list(values);
+        }
+        ConditionWhere::ConditionBetween(value, start, end)
+        | ConditionWhere::ConditionNotBetween(value, start, end) => {
+            visitor.visit_value_where(value);
+            visitor.visit_value_where(start);
+            visitor.visit_value_where(end);
+        }
+        ConditionWhere::Exists(query) | ConditionWhere::NotExists(query) => {
+            visitor.visit_select(query)
+        }
+        ConditionWhere::ConditionAny(value, _, query)
+        | ConditionWhere::ConditionAll(value, _, query) => {
+            visitor.visit_value_where(value);
+            visitor.visit_select(query);
+        }
+    }
+}
+
+pub fn walk_value_where<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    value_where: &'ast ValueWhere,
+) {
+    match value_where {
+        ValueWhere::TableField(table_field) => visitor.visit_table_field(table_field),
+        ValueWhere::LiteralValue(value) => visitor.visit_literal(value),
+        ValueWhere::Expression(expr) => visitor.visit_arithmetic_expr(expr),
+        ValueWhere::BindParameter(bind_name) => visitor.visit_bind_name(bind_name),
+        ValueWhere::SingleQuery(single_query) => visitor.visit_single_query(single_query),
+        ValueWhere::AggFunction(function) => visitor.visit_agg_function(function),
+        ValueWhere::CaseCondition(case) => visitor.visit_case_condition(case),
+        ValueWhere::CaseValue(case) => visitor.visit_case_value(case),
+        ValueWhere::StringFunction(function) => visitor.visit_string_function(function),
+        ValueWhere::WindowFunction(function) => visitor.visit_window_function(function),
+    }
+}
+
+pub fn walk_values_list<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    values_list: &'ast ValuesListWhere,
+) {
+    match values_list {
+        ValuesListWhere::List(values) => {
+            for value in values.0.iter() {
+                visitor.visit_value_where(value);
+            }
+        }
+        ValuesListWhere::SingleSelect(single_query) => visitor.visit_single_query(single_query),
+        ValuesListWhere::Select(query) => visitor.visit_select(query),
+    }
+}
+
+pub fn walk_arithmetic_expr<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    arithmetic_expr: &'ast ArithmeticExprWhere,
+) {
+    match arithmetic_expr {
+        ArithmeticExprWhere::ValueWhere(value) => visitor.visit_value_where(value),
+        ArithmeticExprWhere::Expression(expr) => visitor.visit_arithmetic_expr(expr),
+        ArithmeticExprWhere::Add(left, right)
+        | ArithmeticExprWhere::Subtract(left, right)
+        | ArithmeticExprWhere::Multiply(left, right)
+        | ArithmeticExprWhere::Divide(left, right) => {
+            visitor.visit_arithmetic_expr(left);
+            visitor.visit_arithmetic_expr(right);
+        }
+    }
+}
+
+pub fn walk_case_condition<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    case: &'ast CaseCondition,
+) {
+    for when in case.whens.iter() {
+        visitor.visit_when_condition(when);
+    }
+    if let Some(else_case) = &case.else_case {
+        visitor.visit_value_where(else_case);
+    }
+}
+
+pub fn walk_case_value<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, case: &'ast CaseValue) {
+    visitor.visit_value_where(&case.input);
+    for when in case.whens.iter() {
+        visitor.visit_when_value(when);
+    }
+    if let Some(else_case) = &case.else_case {
+        visitor.visit_value_where(else_case);
+    }
+}
+
+pub fn walk_when_condition<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    when: &'ast WhenCondition,
+) {
+    visitor.visit_logical_expr(&when.when_condition);
+    visitor.visit_value_where(&when.then_value_where);
+}
+
+pub fn walk_when_value<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, when: &'ast WhenValue) {
+    visitor.visit_value_where(&when.when_value_where);
+    visitor.visit_value_where(&when.then_value_where);
+}
+
+pub fn walk_agg_function<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    function: &'ast AggFunction,
+) {
+    visitor.visit_value_where(&function.value);
+    if let Some(filter) = &function.filter {
+        visitor.visit_logical_expr(filter);
+    }
+}
+
+pub fn walk_string_function<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    function: &'ast StringFunction,
+) {
+    match function {
+        StringFunction::Upper(value)
+        | StringFunction::Lower(value)
+        | StringFunction::Substring(value, _, _) => visitor.visit_value_where(value),
+        StringFunction::Replace(value, from, to) => {
+            visitor.visit_value_where(value);
+            visitor.visit_value_where(from);
+            visitor.visit_value_where(to);
+        }
+        StringFunction::Concat(values) => {
+            for value in values.0.iter() {
+                visitor.visit_value_where(value);
+            }
+        }
+    }
+}
+
+pub fn walk_window_function<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    function: &'ast WindowFunction,
+) {
+    match &function.function {
+        WindowFunctionType::Lag { value, default, .. }
+        | WindowFunctionType::Lead { value, default, .. } => {
+            visitor.visit_value_where(value);
+            if let Some(default) = default {
+                visitor.visit_value_where(default);
+            }
+        }
+        WindowFunctionType::FirstValue(value) | WindowFunctionType::LastValue(value) => {
+            visitor.visit_value_where(value)
+        }
+        WindowFunctionType::Aggregate(function) => visitor.visit_agg_function(function),
+        WindowFunctionType::RowNumber
+        | WindowFunctionType::Rank
+        | WindowFunctionType::DenseRank
+        | WindowFunctionType::Ntile(_) => {}
+    }
+    if let Over::Window(spec) = &function.over {
+        visitor.visit_window_spec(spec);
+    }
+}
+
+pub fn walk_window_spec<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, spec: &'ast WindowSpec) {
+    for table_field in spec.partition_by.iter() {
+        visitor.visit_table_field(table_field);
+    }
+    for order_by in spec.orders_by.orders_by.iter() {
+        visitor.visit_order_by(order_by);
+    }
+}
+
+pub fn walk_named_window<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    window: &'ast NamedWindow,
+) {
+    visitor.visit_window_spec(&window.spec);
+}
+
+pub fn walk_order_by<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, order_by: &'ast OrderBy) {
+    visitor.visit_table_field(&order_by.table_field);
+}
+
+pub fn walk_group_by<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, group_by: &'ast GroupBy) {
+    visitor.visit_table_field(&group_by.table_field);
+}
+
+pub fn walk_table_field<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    table_field: &'ast TableField,
+) {
+    if let Some(table) = &table_field.table {
+        visitor.visit_table(table);
+    }
+}
+
+pub fn walk_table<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, table: &'ast Table) {
+    visitor.visit_table_name(&table.name);
+}
+
+pub fn walk_insert<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, insert: &'ast Insert) {
+    visitor.visit_table_name(&insert.table);
+    match &insert.source {
+        InsertSource::Values(rows) => {
+            for value in rows.iter().flat_map(|row| row.0.iter()) {
+                visitor.visit_value_where(value);
+            }
+        }
+        InsertSource::Select(query) => visitor.visit_select(query),
+    }
+    if let Some(OnConflict {
+        action: ConflictAction::DoUpdate(sets),
+        ..
+    }) = &insert.on_conflict
+    {
+        for set in sets.iter() {
+            if let UpsertValue::Value(value) = &set.value {
+                visitor.visit_value_where(value);
+            }
+        }
+    }
+    for column in insert.returning.values_select.iter() {
+        visitor.visit_value_select(column);
+    }
+}
+
+pub fn walk_update<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, update: &'ast Update) {
+    visitor.visit_table(&update.table);
+    for set in update.sets.iter() {
+        visitor.visit_value_where(&set.value);
+    }
+    for from in update.from.iter() {
+        visitor.visit_from_select(from);
+    }
+    if let Some(where_expr) = &update.where_expr {
+        visitor.visit_logical_expr(where_expr);
+    }
+    for column in update.returning.values_select.iter() {
+        visitor.visit_value_select(column);
+    }
+}
+
+pub fn walk_delete<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, delete: &'ast Delete) {
+    visitor.visit_table(&delete.table);
+    for from in delete.using.iter() {
+        visitor.visit_from_select(from);
+    }
+    if let Some(where_expr) = &delete.where_expr {
+        visitor.visit_logical_expr(where_expr);
+    }
+    for column in delete.returning.values_select.iter() {
+        visitor.visit_value_select(column);
+    }
+}
+
+/// Traversal over the AST that can rewrite nodes in place.
+/// Each `visit_*_mut` method walks into the children of the node by default, calling the matching
+/// `walk_*_mut` function; override it to rewrite the node and call `walk_*_mut` to keep walking.
+pub trait VisitorMut {
+    fn visit_select_mut(&mut self, select: &mut Select) {
+        walk_select_mut(self, select);
+    }
+
+    fn visit_cte_mut(&mut self, cte: &mut Cte) {
+        walk_cte_mut(self, cte);
+    }
+
+    fn visit_value_select_mut(&mut self, value_select: &mut ValueSelect) {
+        walk_value_select_mut(self, value_select);
+    }
+
+    fn visit_from_select_mut(&mut self, from_select: &mut FromSelect) {
+        walk_from_select_mut(self, from_select);
+    }
+
+    fn visit_join_mut(&mut self, join: &mut Join) {
+        walk_join_mut(self, join);
+    }
+
+    fn visit_combination_mut(&mut self, combination: &mut Combination) {
+        walk_combination_mut(self, combination);
+    }
+
+    fn visit_single_query_mut(&mut self, single_query: &mut SingleQuery) {
+        walk_single_query_mut(self, single_query);
+    }
+
+    fn visit_logical_expr_mut(&mut self, logical_expr: &mut LogicalExprWhere) {
+        walk_logical_expr_mut(self, logical_expr);
+    }
+
+    fn visit_condition_mut(&mut self, condition: &mut ConditionWhere) {
+        walk_condition_mut(self, condition);
+    }
+
+    fn visit_value_where_mut(&mut self, value_where: &mut ValueWhere) {
+        walk_value_where_mut(self, value_where);
+    }
+
+    fn visit_values_list_mut(&mut self, values_list: &mut ValuesListWhere) {
+        walk_values_list_mut(self, values_list);
+    }
+
+    fn visit_arithmetic_expr_mut(&mut self, arithmetic_expr: &mut ArithmeticExprWhere) {
+        walk_arithmetic_expr_mut(self, arithmetic_expr);
+    }
+
+    fn visit_case_condition_mut(&mut self, case: &mut CaseCondition) {
+        walk_case_condition_mut(self, case);
+    }
+
+    fn visit_case_value_mut(&mut self, case: &mut CaseValue) {
+        walk_case_value_mut(self, case);
+    }
+
+    fn visit_when_condition_mut(&mut self, when: &mut WhenCondition) {
+        walk_when_condition_mut(self, when);
+    }
+
+    fn visit_when_value_mut(&mut self, when: &mut WhenValue) {
+        walk_when_value_mut(self, when);
+    }
+
+    fn visit_agg_function_mut(&mut self, function: &mut AggFunction) {
+        walk_agg_function_mut(self, function);
+    }
+
+    fn visit_string_function_mut(&mut self, function: &mut StringFunction) {
+        walk_string_function_mut(self, function);
+    }
+
+    fn visit_window_function_mut(&mut self, function: &mut WindowFunction) {
+        walk_window_function_mut(self, function);
+    }
+
+    fn visit_window_spec_mut(&mut self, spec: &mut WindowSpec) {
+        walk_window_spec_mut(self, spec);
+    }
+
+    fn visit_named_window_mut(&mut self, window: &mut NamedWindow) {
+        walk_named_window_mut(self, window);
+    }
+
+    fn visit_order_by_mut(&mut self, order_by: &mut OrderBy) {
+        walk_order_by_mut(self, order_by);
+    }
+
+    fn visit_group_by_mut(&mut self, group_by: &mut GroupBy) {
+        walk_group_by_mut(self, group_by);
+    }
+
+    fn visit_table_field_mut(&mut self, table_field: &mut TableField) {
+        walk_table_field_mut(self, table_field);
+    }
+
+    fn visit_table_mut(&mut self, table: &mut Table) {
+        walk_table_mut(self, table);
+    }
+
+    fn visit_insert_mut(&mut self, insert: &mut Insert) {
+        walk_insert_mut(self, insert);
+    }
+
+    fn visit_update_mut(&mut self, update: &mut Update) {
+        walk_update_mut(self, update);
+    }
+
+    fn visit_delete_mut(&mut self, delete: &mut Delete) {
+        walk_delete_mut(self, delete);
+    }
+
+    fn visit_table_name_mut(&mut self, _table_name: &mut TableName) {}
+
+    fn visit_bind_name_mut(&mut self, _bind_name: &mut BindName) {}
+
+    fn visit_literal_mut(&mut self, _value: &mut NullableValue) {}
+}
+
+pub fn walk_select_mut<V: VisitorMut + ?Sized>(visitor: &mut V, select: &mut Select) {
+    for cte in select.ctes.iter_mut() {
+        visitor.visit_cte_mut(cte);
+    }
+    for column in select.columns.values_select.iter_mut() {
+        visitor.visit_value_select_mut(column);
+    }
+    for from in select.from.iter_mut() {
+        visitor.visit_from_select_mut(from);
+    }
+    for join in select.joins.iter_mut() {
+        visitor.visit_join_mut(join);
+    }
+    if let Some(where_expr) = &mut select.where_expr {
+        visitor.visit_logical_expr_mut(where_expr);
+    }
+    for group in select.groups.iter_mut() {
+        visitor.visit_group_by_mut(group);
+    }
+    if let Some(having_expr) = &mut select.having_expr {
+        visitor.visit_logical_expr_mut(having_expr);
+    }
+    for window in select.windows.iter_mut() {
+        visitor.visit_named_window_mut(window);
+    }
+    for order_by in select.orders_by.orders_by.iter_mut() {
+        visitor.visit_order_by_mut(order_by);
+    }
+    if let Some(combination) = &mut select.combination {
+        visitor.visit_combination_mut(combination);
+    }
+}
+
+pub fn walk_cte_mut<V: VisitorMut + ?Sized>(visitor: &mut V, cte: &mut Cte) {
+    visitor.visit_table_name_mut(&mut cte.name);
+    visitor.visit_select_mut(&mut cte.query);
+    if let Some(recursive) = &mut cte.recursive {
+        visitor.visit_select_mut(recursive);
+    }
+}
+
+pub fn walk_value_select_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    value_select: &mut ValueSelect,
+) {
+    visitor.visit_value_where_mut(&mut value_select.value_where);
+}
+
+pub fn walk_from_select_mut<V: VisitorMut + ?Sized>(visitor: &mut V, from_select: &mut FromSelect) {
+    match &mut from_select.from_type {
+        FromType::Table(table_name) => visitor.visit_table_name_mut(table_name),
+        FromType::Query(query) => visitor.visit_select_mut(query),
+    }
+}
+
+pub fn walk_join_mut<V: VisitorMut + ?Sized>(visitor: &mut V, join: &mut Join) {
+    visitor.visit_from_select_mut(&mut join.from);
+    visitor.visit_logical_expr_mut(&mut join.condition);
+}
+
+pub fn walk_combination_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    combination: &mut Combination,
+) {
+    visitor.visit_select_mut(&mut combination.query);
+}
+
+pub fn walk_single_query_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    single_query: &mut SingleQuery,
+) {
+    visitor.visit_select_mut(&mut single_query.query);
+}
+
+pub fn walk_logical_expr_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    logical_expr: &mut LogicalExprWhere,
+) {
+    match logical_expr {
+        LogicalExprWhere::Condition(condition) => visitor.visit_condition_mut(condition),
+        LogicalExprWhere::Not(expr) | LogicalExprWhere::Expression(expr) => {
+            visitor.visit_logical_expr_mut(expr)
+        }
+        LogicalExprWhere::And(left, right) | LogicalExprWhere::Or(left, right) => {
+            visitor.visit_logical_expr_mut(left);
+            visitor.visit_logical_expr_mut(right);
+        }
+    }
+}
+
+pub fn walk_condition_mut<V: VisitorMut + ?Sized>(visitor: &mut V, condition: &mut ConditionWhere) {
+    match condition {
+        ConditionWhere::Expression(expr) => visitor.visit_arithmetic_expr_mut(expr),
+        ConditionWhere::ConditionNull(value) | ConditionWhere::ConditionNotNull(value) => {
+            visitor.visit_value_where_mut(value)
+        }
+        ConditionWhere::ConditionEq(left, right)
+        | ConditionWhere::ConditionDf(left, right)
+        | ConditionWhere::ConditionGt(left, right)
+        | ConditionWhere::ConditionLs(left, right)
+        | ConditionWhere::ConditionGe(left, right)
+        | ConditionWhere::ConditionLe(left, right)
+        | ConditionWhere::ConditionLk(left, right)
+        | ConditionWhere::ConditionNotLk(left, right)
+        | ConditionWhere::ConditionILk(left, right)
+        | ConditionWhere::ConditionDistinct(left, right)
+        | ConditionWhere::ConditionNotDistinct(left, right) => {
+            visitor.visit_value_where_mut(left);
+            visitor.visit_value_where_mut(right);
+        }
+        ConditionWhere::ConditionIn(value, values)
+        | ConditionWhere::ConditionNotIn(value, values) => {
+            visitor.visit_value_where_mut(value);
+            visitor.visit_values_list_mut(values);
+        }
+        ConditionWhere::ConditionBetween(value, start, end)
+        | ConditionWhere::ConditionNotBetween(value, start, end) => {
+            visitor.visit_value_where_mut(value);
+            visitor.visit_value_where_mut(start);
+            visitor.visit_value_where_mut(end);
+        }
+        ConditionWhere::Exists(query) | ConditionWhere::NotExists(query) => {
+            visitor.visit_select_mut(query)
+        }
+        ConditionWhere::ConditionAny(value, _, query)
+        | ConditionWhere::ConditionAll(value, _, query) => {
+            visitor.visit_value_where_mut(value);
+            visitor.visit_select_mut(query);
+        }
+    }
+}
+
+pub fn walk_value_where_mut<V: VisitorMut + ?Sized>(visitor: &mut V, value_where: &mut ValueWhere) {
+    match value_where {
+        ValueWhere::TableField(table_field) => visitor.visit_table_field_mut(table_field),
+        ValueWhere::LiteralValue(value) => visitor.visit_literal_mut(value),
+        ValueWhere::Expression(expr) => visitor.visit_arithmetic_expr_mut(expr),
+        ValueWhere::BindParameter(bind_name) => visitor.visit_bind_name_mut(bind_name),
+        ValueWhere::SingleQuery(single_query) => visitor.visit_single_query_mut(single_query),
+        ValueWhere::AggFunction(function) => visitor.visit_agg_function_mut(function),
+        ValueWhere::CaseCondition(case) => visitor.visit_case_condition_mut(case),
+        ValueWhere::CaseValue(case) => visitor.visit_case_value_mut(case),
+        ValueWhere::StringFunction(function) => visitor.visit_string_function_mut(function),
+        ValueWhere::WindowFunction(function) => visitor.visit_window_function_mut(function),
+    }
+}
+
+pub fn walk_values_list_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    values_list: &mut ValuesListWhere,
+) {
+    match values_list {
+        ValuesListWhere::List(values) => {
+            for value in values.0.iter_mut() {
+                visitor.visit_value_where_mut(value);
+            }
+        }
+        ValuesListWhere::SingleSelect(single_query) => visitor.visit_single_query_mut(single_query),
+        ValuesListWhere::Select(query) => visitor.visit_select_mut(query),
+    }
+}
+
+pub fn walk_arithmetic_expr_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    arithmetic_expr: &mut ArithmeticExprWhere,
+) {
+    match arithmetic_expr {
+        ArithmeticExprWhere::ValueWhere(value) => visitor.visit_value_where_mut(value),
+        ArithmeticExprWhere::Expression(expr) => visitor.visit_arithmetic_expr_mut(expr),
+        ArithmeticExprWhere::Add(left, right)
+        | ArithmeticExprWhere::Subtract(left, right)
+        | ArithmeticExprWhere::Multiply(left, right)
+        | ArithmeticExprWhere::Divide(left, right) => {
+            visitor.visit_arithmetic_expr_mut(left);
+            visitor.visit_arithmetic_expr_mut(right);
+        }
+    }
+}
+
+pub fn walk_case_condition_mut<V: VisitorMut + ?Sized>(visitor: &mut V, case: &mut CaseCondition) {
+    for when in case.whens.iter_mut() {
+        visitor.visit_when_condition_mut(when);
+    }
+    if let Some(else_case) = &mut case.else_case {
+        visitor.visit_value_where_mut(else_case);
+    }
+}
+
+pub fn walk_case_value_mut<V: VisitorMut + ?Sized>(visitor: &mut V, case: &mut CaseValue) {
+    visitor.visit_value_where_mut(&mut case.input);
+    for when in case.whens.iter_mut() {
+        visitor.visit_when_value_mut(when);
+    }
+    if let Some(else_case) = &mut case.else_case {
+        visitor.visit_value_where_mut(else_case);
+    }
+}
+
+pub fn walk_when_condition_mut<V: VisitorMut + ?Sized>(visitor: &mut V, when: &mut WhenCondition) {
+    visitor.visit_logical_expr_mut(&mut when.when_condition);
+    visitor.visit_value_where_mut(&mut when.then_value_where);
+}
+
+pub fn walk_when_value_mut<V: VisitorMut + ?Sized>(visitor: &mut V, when: &mut WhenValue) {
+    visitor.visit_value_where_mut(&mut when.when_value_where);
+    visitor.visit_value_where_mut(&mut when.then_value_where);
+}
+
+pub fn walk_agg_function_mut<V: VisitorMut + ?Sized>(visitor: &mut V, function: &mut AggFunction) {
+    visitor.visit_value_where_mut(&mut function.value);
+    if let Some(filter) = &mut function.filter {
+        visitor.visit_logical_expr_mut(filter);
+    }
+}
+
+pub fn walk_string_function_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    function: &mut StringFunction,
+) {
+    match function {
+        StringFunction::Upper(value)
+        | StringFunction::Lower(value)
+        | StringFunction::Substring(value, _, _) => visitor.visit_value_where_mut(value),
+        StringFunction::Replace(value, from, to) => {
+            visitor.visit_value_where_mut(value);
+            visitor.visit_value_where_mut(from);
+            visitor.visit_value_where_mut(to);
+        }
+        StringFunction::Concat(values) => {
+            for value in values.0.iter_mut() {
+                visitor.visit_value_where_mut(value);
+            }
+        }
+    }
+}
+
+pub fn walk_window_function_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    function: &mut WindowFunction,
+) {
+    match &mut function.function {
+        WindowFunctionType::Lag { value, default, .. }
+        | WindowFunctionType::Lead { value, default, .. } => {
+            visitor.visit_value_where_mut(value);
+            if let Some(default) = default {
+                visitor.visit_value_where_mut(default);
+            }
+        }
+        WindowFunctionType::FirstValue(value) | WindowFunctionType::LastValue(value) => {
+            visitor.visit_value_where_mut(value)
+        }
+        WindowFunctionType::Aggregate(function) => visitor.visit_agg_function_mut(function),
+        WindowFunctionType::RowNumber
+        | WindowFunctionType::Rank
+        | WindowFunctionType::DenseRank
+        | WindowFunctionType::Ntile(_) => {}
+    }
+    if let Over::Window(spec) = &mut function.over {
+        visitor.visit_window_spec_mut(spec);
+    }
+}
+
+pub fn walk_window_spec_mut<V: VisitorMut + ?Sized>(visitor: &mut V, spec: &mut WindowSpec) {
+    for table_field in spec.partition_by.iter_mut() {
+        visitor.visit_table_field_mut(table_field);
+    }
+    for order_by in spec.orders_by.orders_by.iter_mut() {
+        visitor.visit_order_by_mut(order_by);
+    }
+}
+
+pub fn walk_named_window_mut<V: VisitorMut + ?Sized>(visitor: &mut V, window: &mut NamedWindow) {
+    visitor.visit_window_spec_mut(&mut window.spec);
+}
+
+pub fn walk_order_by_mut<V: VisitorMut + ?Sized>(visitor: &mut V, order_by: &mut OrderBy) {
+    visitor.visit_table_field_mut(&mut order_by.table_field);
+}
+
+pub fn walk_group_by_mut<V: VisitorMut + ?Sized>(visitor: &mut V, group_by: &mut GroupBy) {
+    visitor.visit_table_field_mut(&mut group_by.table_field);
+}
+
+pub fn walk_table_field_mut<V: VisitorMut + ?Sized>(visitor: &mut V, table_field: &mut TableField) {
+    if let Some(table) = &mut table_field.table {
+        visitor.visit_table_mut(table);
+    }
+}
+
+pub fn walk_table_mut<V: VisitorMut + ?Sized>(visitor: &mut V, table: &mut Table) {
+    visitor.visit_table_name_mut(&mut table.name);
+}
+
+pub fn walk_insert_mut<V: VisitorMut + ?Sized>(visitor: &mut V, insert: &mut Insert) {
+    visitor.visit_table_name_mut(&mut insert.table);
+    match &mut insert.source {
+        InsertSource::Values(rows) => {
+            for value in rows.iter_mut().flat_map(|row| row.0.iter_mut()) {
+                visitor.visit_value_where_mut(value);
+            }
+        }
+        InsertSource::Select(query) => visitor.visit_select_mut(query),
+    }
+    if let Some(OnConflict {
+        action: ConflictAction::DoUpdate(sets),
+        ..
+    }) = &mut insert.on_conflict
+    {
+        for set in sets.iter_mut() {
+            if let UpsertValue::Value(value) = &mut set.value {
+                visitor.visit_value_where_mut(value);
+            }
+        }
+    }
+    for column in insert.returning.values_select.iter_mut() {
+        visitor.visit_value_select_mut(column);
+    }
+}
+
+pub fn walk_update_mut<V: VisitorMut + ?Sized>(visitor: &mut V, update: &mut Update) {
+    visitor.visit_table_mut(&mut update.table);
+    for set in update.sets.iter_mut() {
+        visitor.visit_value_where_mut(&mut set.value);
+    }
+    for from in update.from.iter_mut() {
+        visitor.visit_from_select_mut(from);
+    }
+    if let Some(where_expr) = &mut update.where_expr {
+        visitor.visit_logical_expr_mut(where_expr);
+    }
+    for column in update.returning.values_select.iter_mut() {
+        visitor.visit_value_select_mut(column);
+    }
+}
+
+pub fn walk_delete_mut<V: VisitorMut + ?Sized>(visitor: &mut V, delete: &mut Delete) {
+    visitor.visit_table_mut(&mut delete.table);
+    for from in delete.using.iter_mut() {
+        visitor.visit_from_select_mut(from);
+    }
+    if let Some(where_expr) = &mut delete.where_expr {
+        visitor.visit_logical_expr_mut(where_expr);
+    }
+    for column in delete.returning.values_select.iter_mut() {
+        visitor.visit_value_select_mut(column);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::selections::{
+        CaseConditionBuilder, ConditionWhereOperation, IntoValueWhere, LogicalExprWhereOps,
+        OrderBy, QueryBuilder, SingleSelectBuilder, WhenCondition,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[derive(Default)]
+    struct Names {
+        fields: Vec<String>,
+        binds: Vec<String>,
+        tables: Vec<String>,
+    }
+
+    impl<'ast> Visitor<'ast> for Names {
+        fn visit_table_field(&mut self, table_field: &'ast TableField) {
+            self.fields.push(table_field.to_string());
+            walk_table_field(self, table_field);
+        }
+
+        fn visit_bind_name(&mut self, bind_name: &'ast BindName) {
+            self.binds.push(bind_name.name().to_string());
+        }
+
+        fn visit_table_name(&mut self, table_name: &'ast TableName) {
+            self.tables.push(table_name.name().to_string());
+        }
+    }
+
+    struct RenameTable<'a> {
+        from: &'a str,
+        to: &'a str,
+    }
+
+    impl VisitorMut for RenameTable<'_> {
+        fn visit_table_name_mut(&mut self, table_name: &mut TableName) {
+            if table_name.name() == self.from {
+                *table_name = TableName::new(self.to);
+            }
+        }
+    }
+
+    fn query() -> Select {
+        let last_order = SingleSelectBuilder::max("ORDERS.DATE")
+            .from("ORDERS")
+            .where_c(TableField::new("ORDERS.CUSTOMER_ID").equal(TableField::new("C.ID")))
+            .build();
+        let status = CaseConditionBuilder::new()
+            .when_value(WhenCondition::new(
+                TableField::new("C.ACTIVE").equal(ValueWhere::bind("active")),
+                "ACTIVE",
+            ))
+            .else_case(TableField::new("C.STATUS"))
+            .build();
+        let big_orders = QueryBuilder::new()
+            .field("ID")
+            .from("ORDERS")
+            .where_c(TableField::new("ORDERS.TOTAL").greater(ValueWhere::bind("total")))
+            .build()
+            .unwrap();
+        QueryBuilder::new()
+            .field("C.NAME")
+            .select((last_order, "LAST_ORDER"))
+            .select((ValueWhere::CaseCondition(Box::new(status)), "STATUS"))
+            .from(crate::selections::FromSelect::from_table("CUSTOMER").with_alias("C"))
+            .where_c(
+                ConditionWhere::exists(big_orders)
+                    .and(TableField::new("C.REGION").include(vec!["N".into_value_where()])),
+            )
+            .order(OrderBy::asc("C.NAME"))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_visitor() {
+        let query = query();
+        let mut names = Names::default();
+        names.visit_select(&query);
+        assert_eq!(
+            names.fields,
+            vec![
+                "C.NAME",
+                "ORDERS.DATE",
+                "ORDERS.CUSTOMER_ID",
+                "C.ID",
+                "C.ACTIVE",
+                "C.STATUS",
+                "ID",
+                "ORDERS.TOTAL",
+                "C.REGION",
+                "C.NAME",
+            ]
+        );
+        assert_eq!(names.binds, vec!["active", "total"]);
+        assert_eq!(
+            names.tables,
+            vec![
+                "C", "ORDERS", "ORDERS", "ORDERS", "C", "C", "C", "CUSTOMER", "ORDERS", "ORDERS",
+                "C", "C"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_visitor_mut() {
+        let mut query = query();
+        RenameTable {
+            from: "ORDERS",
+            to: "SALES",
+        }
+        .visit_select_mut(&mut query);
+        let mut names = Names::default();
+        names.visit_select(&query);
+        assert!(!names.tables.iter().any(|t| t == "ORDERS"));
+        assert_eq!(names.tables.iter().filter(|t| *t == "SALES").count(), 5);
+    }
+}
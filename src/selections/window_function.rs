@@ -0,0 +1,537 @@
+use super::{
+    agg_functions::AggFunction,
+    alias::{Alias, IntoAlias},
+    order_by::IntoOrderBy,
+    orders::OrdersBy,
+    table_field::{IntoTableField, TableField},
+    to_sql::ToSQL,
+    value_where::{IntoValueWhere, ValueWhere},
+};
+use crate::{resolvers::args_resolver::ArgsResolver, SQLError};
+use serde::{Deserialize, Serialize};
+
+/// Units of a window frame.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FrameUnits {
+    Rows,
+    Range,
+}
+
+/// Start or end bound of a window frame.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FrameBound {
+    UnboundedPreceding,
+    Preceding(u32),
+    CurrentRow,
+    Following(u32),
+    UnboundedFollowing,
+}
+
+impl ToSQL for FrameBound {
+    fn to_sql(
+        &self,
+        _args_resolver: &mut dyn ArgsResolver,
+    ) -> error_stack::Result<String, SQLError> {
+        let sql = match self {
+            FrameBound::UnboundedPreceding => "UNBOUNDED PRECEDING".to_string(),
+            FrameBound::Preceding(n) => format!("{n} PRECEDING"),
+            FrameBound::CurrentRow => "CURRENT ROW".to_string(),
+            FrameBound::Following(n) => format!("{n} FOLLOWING"),
+            FrameBound::UnboundedFollowing => "UNBOUNDED FOLLOWING".to_string(),
+        };
+        Ok(sql)
+    }
+}
+
+/// Frame of a window (`ROWS`/`RANGE`), when `end` is informed it's rendered as `BETWEEN <start> AND <end>`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WindowFrame {
+    pub units: FrameUnits,
+    pub start: FrameBound,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub end: Option<FrameBound>,
+}
+
+impl WindowFrame {
+    pub fn new(units: FrameUnits, start: FrameBound) -> Self {
+        Self {
+            units,
+            start,
+            end: None,
+        }
+    }
+
+    pub fn between(units: FrameUnits, start: FrameBound, end: FrameBound) -> Self {
+        Self {
+            units,
+            start,
+            end: Some(end),
+        }
+    }
+}
+
+impl ToSQL for WindowFrame {
+    fn to_sql(
+        &self,
+        args_resolver: &mut dyn ArgsResolver,
+    ) -> error_stack::Result<String, SQLError> {
+        let units = match self.units {
+            FrameUnits::Rows => "ROWS",
+            FrameUnits::Range => "RANGE",
+        };
+        let start = self.start.to_sql(args_resolver)?;
+        match &self.end {
+            Some(end) => Ok(format!(
+                "{units} BETWEEN {start} AND {}",
+                end.to_sql(args_resolver)?
+            )),
+            None => Ok(format!("{units} {start}")),
+        }
+    }
+}
+
+/// Definition of a window, the content between the parentheses of `OVER (...)`.
+/// # Example
+/// ```
+/// # use voxi_core::selections::{FrameBound, OrderBy, ToSQL, WindowSpec};
+/// # use voxi_core::resolvers::args_resolver_string::args_to_str;
+/// let window = WindowSpec::new()
+///     .partition_by("DEPT")
+///     .order(OrderBy::asc("HIRED"))
+///     .rows_between(FrameBound::UnboundedPreceding, FrameBound::CurrentRow);
+/// assert_eq!(
+///     args_to_str(&window).unwrap(),
+///     r#"PARTITION BY "DEPT" ORDER BY "HIRED" ASC ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW"#
+/// );
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WindowSpec {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub partition_by: Vec<TableField>,
+    #[serde(rename = "order_by")]
+    pub orders_by: OrdersBy,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub frame: Option<WindowFrame>,
+}
+
+impl WindowSpec {
+    /// Create an empty window, all rows of the result are a single partition.
+    pub fn new() -> Self {
+        Self {
+            partition_by: Vec::new(),
+            orders_by: OrdersBy::empty(),
+            frame: None,
+        }
+    }
+
+    /// Add partition column.
+    #[must_use]
+    pub fn partition_by(mut self, table_field: impl IntoTableField) -> Self {
+        self.partition_by.push(table_field.into_table_field());
+        self
+    }
+
+    /// Add order column.
+    #[must_use]
+    pub fn order(mut self, order_by: impl IntoOrderBy) -> Self {
+        self.orders_by.push(order_by.into_order_by());
+        self
+    }
+
+    /// Define the frame.
+    #[must_use]
+    pub fn frame(mut self, frame: WindowFrame) -> Self {
+        self.frame = Some(frame);
+        self
+    }
+
+    /// Define the frame `ROWS BETWEEN <start> AND <end>`.
+    #[must_use]
+    pub fn rows_between(self, start: FrameBound, end: FrameBound) -> Self {
+        self.frame(WindowFrame::between(FrameUnits::Rows, start, end))
+    }
+
+    /// Define the frame `RANGE BETWEEN <start> AND <end>`.
+    #[must_use]
+    pub fn range_between(self, start: FrameBound, end: FrameBound) -> Self {
+        self.frame(WindowFrame::between(FrameUnits::Range, start, end))
+    }
+}
+
+impl Default for WindowSpec {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ToSQL for WindowSpec {
+    fn to_sql(
+        &self,
+        args_resolver: &mut dyn ArgsResolver,
+    ) -> error_stack::Result<String, SQLError> {
+        let mut parts = Vec::new();
+        if !self.partition_by.is_empty() {
+            let partition = self
+                .partition_by
+                .iter()
+                .map(|t| t.to_sql(args_resolver))
+                .collect::<Result<Vec<_>, _>>()?
+                .join(", ");
+            parts.push(format!("PARTITION BY {partition}"));
+        }
+        if !self.orders_by.is_empty() {
+            parts.push(format!(
+                "ORDER BY {}",
+                self.orders_by.to_sql(args_resolver)?
+            ));
+        }
+        if let Some(frame) = &self.frame {
+            parts.push(frame.to_sql(args_resolver)?);
+        }
+        Ok(parts.join(" "))
+    }
+}
+
+/// Named window declared in the `WINDOW` clause of a query.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NamedWindow {
+    pub name: Alias,
+    pub spec: WindowSpec,
+}
+
+impl NamedWindow {
+    pub fn new(name: impl IntoAlias, spec: WindowSpec) -> Self {
+        Self {
+            name: name.into_alias(),
+            spec,
+        }
+    }
+}
+
+impl ToSQL for NamedWindow {
+    fn to_sql(
+        &self,
+        args_resolver: &mut dyn ArgsResolver,
+    ) -> error_stack::Result<String, SQLError> {
+        Ok(format!(
+            "{} AS ({})",
+            self.name.to_sql(args_resolver)?,
+            self.spec.to_sql(args_resolver)?
+        ))
+    }
+}
+
+/// Window where the function is evaluated.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Over {
+    Window(WindowSpec),
+    /// Reference to a window declared in the `WINDOW` clause.
+    Named(Alias),
+}
+
+impl ToSQL for Over {
+    fn to_sql(
+        &self,
+        args_resolver: &mut dyn ArgsResolver,
+    ) -> error_stack::Result<String, SQLError> {
+        match self {
+            Over::Window(spec) => Ok(format!("OVER ({})", spec.to_sql(args_resolver)?)),
+            Over::Named(name) => Ok(format!("OVER {}", name.to_sql(args_resolver)?)),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum WindowFunctionType {
+    RowNumber,
+    Rank,
+    DenseRank,
+    Ntile(u32),
+    Lag {
+        value: ValueWhere,
+        offset: u32,
+        default: Option<ValueWhere>,
+    },
+    Lead {
+        value: ValueWhere,
+        offset: u32,
+        default: Option<ValueWhere>,
+    },
+    FirstValue(ValueWhere),
+    LastValue(ValueWhere),
+    /// Any aggregate function evaluated over the window.
+    Aggregate(AggFunction),
+}
+
+impl ToSQL for WindowFunctionType {
+    fn to_sql(
+        &self,
+        args_resolver: &mut dyn ArgsResolver,
+    ) -> error_stack::Result<String, SQLError> {
+        let sql = match self {
+            WindowFunctionType::RowNumber => "ROW_NUMBER()".to_string(),
+            WindowFunctionType::Rank => "RANK()".to_string(),
+            WindowFunctionType::DenseRank => "DENSE_RANK()".to_string(),
+            WindowFunctionType::Ntile(buckets) => format!("NTILE({buckets})"),
+            WindowFunctionType::Lag {
+                value,
+                offset,
+                default,
+            } => format!(
+                "LAG({})",
+                offset_args_to_sql(value, *offset, default, args_resolver)?
+            ),
+            WindowFunctionType::Lead {
+                value,
+                offset,
+                default,
+            } => format!(
+                "LEAD({})",
+                offset_args_to_sql(value, *offset, default, args_resolver)?
+            ),
+            WindowFunctionType::FirstValue(value) => {
+                format!("FIRST_VALUE({})", value.to_sql(args_resolver)?)
+            }
+            WindowFunctionType::LastValue(value) => {
+                format!("LAST_VALUE({})", value.to_sql(args_resolver)?)
+            }
+            WindowFunctionType::Aggregate(agg) => agg.to_sql(args_resolver)?,
+        };
+        Ok(sql)
+    }
+}
+
+fn offset_args_to_sql(
+    value: &ValueWhere,
+    offset: u32,
+    default: &Option<ValueWhere>,
+    args_resolver: &mut dyn ArgsResolver,
+) -> error_stack::Result<String, SQLError> {
+    let mut sql = format!("{}, {offset}", value.to_sql(args_resolver)?);
+    if let Some(default) = default {
+        sql.push_str(&format!(", {}", default.to_sql(args_resolver)?));
+    }
+    Ok(sql)
+}
+
+/// Window function, `<function> OVER (<window>)`.
+/// # Example
+/// ```
+/// # use voxi_core::selections::{OrderBy, WindowFunction, WindowSpec};
+/// # use voxi_core::resolvers::args_resolver_string::args_to_str;
+/// let rank = WindowFunction::rank()
+///     .over(WindowSpec::new().partition_by("DEPT").order(OrderBy::desc("SALARY")));
+/// assert_eq!(
+///     args_to_str(&rank).unwrap(),
+///     r#"RANK() OVER (PARTITION BY "DEPT" ORDER BY "SALARY" DESC)"#
+/// );
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WindowFunction {
+    pub function: WindowFunctionType,
+    pub over: Over,
+}
+
+impl WindowFunction {
+    /// Create the function over an empty window (`OVER ()`).
+    pub fn new(function: WindowFunctionType) -> Self {
+        Self {
+            function,
+            over: Over::Window(WindowSpec::new()),
+        }
+    }
+
+    pub fn row_number() -> Self {
+        Self::new(WindowFunctionType::RowNumber)
+    }
+
+    pub fn rank() -> Self {
+        Self::new(WindowFunctionType::Rank)
+    }
+
+    pub fn dense_rank() -> Self {
+        Self::new(WindowFunctionType::DenseRank)
+    }
+
+    pub fn ntile(buckets: u32) -> Self {
+        Self::new(WindowFunctionType::Ntile(buckets))
+    }
+
+    /// Value of the row `offset` rows before the current one.
+    pub fn lag(value: impl IntoValueWhere, offset: u32) -> Self {
+        Self::new(WindowFunctionType::Lag {
+            value: value.into_value_where(),
+            offset,
+            default: None,
+        })
+    }
+
+    /// Same as `lag`, returning `default` when the row doesn't exist.
+    pub fn lag_or(value: impl IntoValueWhere, offset: u32, default: impl IntoValueWhere) -> Self {
+        Self::new(WindowFunctionType::Lag {
+            value: value.into_value_where(),
+            offset,
+            default: Some(default.into_value_where()),
+        })
+    }
+
+    /// Value of the row `offset` rows after the current one.
+    pub fn lead(value: impl IntoValueWhere, offset: u32) -> Self {
+        Self::new(WindowFunctionType::Lead {
+            value: value.into_value_where(),
+            offset,
+            default: None,
+        })
+    }
+
+    /// Same as `lead`, returning `default` when the row doesn't exist.
+    pub fn lead_or(value: impl IntoValueWhere, offset: u32, default: impl IntoValueWhere) -> Self {
+        Self::new(WindowFunctionType::Lead {
+            value: value.into_value_where(),
+            offset,
+            default: Some(default.into_value_where()),
+        })
+    }
+
+    pub fn first_value(value: impl IntoValueWhere) -> Self {
+        Self::new(WindowFunctionType::FirstValue(value.into_value_where()))
+    }
+
+    pub fn last_value(value: impl IntoValueWhere) -> Self {
+        Self::new(WindowFunctionType::LastValue(value.into_value_where()))
+    }
+
+    /// Evaluate an aggregate function over the window, like `SUM(<field>) OVER (...)`.
+    pub fn aggregate(agg_function: AggFunction) -> Self {
+        Self::new(WindowFunctionType::Aggregate(agg_function))
+    }
+
+    /// Define the window.
+    #[must_use]
+    pub fn over(mut self, spec: WindowSpec) -> Self {
+        self.over = Over::Window(spec);
+        self
+    }
+
+    /// Use a window declared in the `WINDOW` clause of the query.
+    #[must_use]
+    pub fn over_named(mut self, name: impl IntoAlias) -> Self {
+        self.over = Over::Named(name.into_alias());
+        self
+    }
+}
+
+impl ToSQL for WindowFunction {
+    fn to_sql(
+        &self,
+        args_resolver: &mut dyn ArgsResolver,
+    ) -> error_stack::Result<String, SQLError> {
+        Ok(format!(
+            "{} {}",
+            self.function.to_sql(args_resolver)?,
+            self.over.to_sql(args_resolver)?
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dialects::MsSqlDialect;
+    use crate::resolvers::args_resolver_string::{args_to_str, args_to_str_with_dialect};
+    use crate::selections::{OrderBy, QueryBuilder, Select};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_ranking_functions() {
+        let spec = WindowSpec::new().order(OrderBy::asc("ID"));
+        assert_eq!(
+            args_to_str(&WindowFunction::row_number().over(spec.clone())).unwrap(),
+            r#"ROW_NUMBER() OVER (ORDER BY "ID" ASC)"#
+        );
+        assert_eq!(
+            args_to_str(&WindowFunction::dense_rank().over(spec.clone())).unwrap(),
+            r#"DENSE_RANK() OVER (ORDER BY "ID" ASC)"#
+        );
+        assert_eq!(
+            args_to_str(&WindowFunction::ntile(4).over(spec)).unwrap(),
+            r#"NTILE(4) OVER (ORDER BY "ID" ASC)"#
+        );
+        assert_eq!(
+            args_to_str(&WindowFunction::row_number()).unwrap(),
+            "ROW_NUMBER() OVER ()"
+        );
+    }
+
+    #[test]
+    fn test_offset_functions() {
+        let spec = WindowSpec::new()
+            .partition_by(("P", "CUSTOMER_ID"))
+            .order(OrderBy::asc("DATE"));
+        assert_eq!(
+            args_to_str(&WindowFunction::lag(TableField::new("TOTAL"), 1).over(spec.clone()))
+                .unwrap(),
+            r#"LAG("TOTAL", 1) OVER (PARTITION BY "P"."CUSTOMER_ID" ORDER BY "DATE" ASC)"#
+        );
+        assert_eq!(
+            args_to_str(&WindowFunction::lead_or(TableField::new("TOTAL"), 2, 0).over(spec))
+                .unwrap(),
+            r#"LEAD("TOTAL", 2, 0) OVER (PARTITION BY "P"."CUSTOMER_ID" ORDER BY "DATE" ASC)"#
+        );
+    }
+
+    #[test]
+    fn test_aggregate_over_frame() {
+        let sum = WindowFunction::aggregate(AggFunction::sum("TOTAL")).over(
+            WindowSpec::new()
+                .order(OrderBy::asc("DATE"))
+                .range_between(FrameBound::Preceding(7), FrameBound::Following(1)),
+        );
+        assert_eq!(
+            args_to_str(&sum).unwrap(),
+            r#"SUM("TOTAL") OVER (ORDER BY "DATE" ASC RANGE BETWEEN 7 PRECEDING AND 1 FOLLOWING)"#
+        );
+        let last = WindowFunction::last_value(TableField::new("TOTAL")).over(
+            WindowSpec::new().frame(WindowFrame::new(FrameUnits::Rows, FrameBound::CurrentRow)),
+        );
+        assert_eq!(
+            args_to_str_with_dialect(&last, MsSqlDialect).unwrap(),
+            "LAST_VALUE([TOTAL]) OVER (ROWS CURRENT ROW)"
+        );
+    }
+
+    fn query_named_window() -> Select {
+        QueryBuilder::new()
+            .field("ID")
+            .select(WindowFunction::first_value(TableField::new("SALARY")).over_named("w"))
+            .from("EMPLOYEE")
+            .window(
+                "w",
+                WindowSpec::new()
+                    .partition_by("DEPT")
+                    .order(OrderBy::desc("SALARY")),
+            )
+            .order(OrderBy::asc("ID"))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_named_window() {
+        assert_eq!(
+            args_to_str(&query_named_window()).unwrap(),
+            r#"SELECT "ID",FIRST_VALUE("SALARY") OVER "w" FROM "EMPLOYEE" WINDOW "w" AS (PARTITION BY "DEPT" ORDER BY "SALARY" DESC) ORDER BY "ID" ASC"#
+        );
+    }
+
+    #[test]
+    fn test_window_serialize() {
+        let query = query_named_window();
+        let json = serde_json::to_string(&query).unwrap();
+        let query_de: Select = serde_json::from_str(&json).unwrap();
+        assert_eq!(query, query_de);
+    }
+}
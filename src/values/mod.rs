@@ -30,6 +30,7 @@ pub use null_value::NullValue;
 pub use nullable_value::IntoNullableValue;
 pub use nullable_value::NullableValue;
 pub use typed_option_value::TypedOptionValue;
+pub use util::quote_escaped;
 pub use util::validate_double_quotes;
 pub use value::Value;
 pub use value::ValueToSQL;
@@ -6,6 +6,27 @@ pub fn validate_double_quotes(content: &str) -> Result<(), String> {
     Ok(())
 }
 
+/// Enclose content between `open` and `close` delimiters, doubling any `close` inside it,
+/// so the content can never terminate the quoted text.
+/// # Example
+/// ```
+/// # use voxi_core::quote_escaped;
+/// assert_eq!(quote_escaped("O'Brien", '\'', '\''), "'O''Brien'");
+/// assert_eq!(quote_escaped("A]B", '[', ']'), "[A]]B]");
+/// ```
+pub fn quote_escaped(content: &str, open: char, close: char) -> String {
+    let mut quoted = String::with_capacity(content.len() + 2);
+    quoted.push(open);
+    for c in content.chars() {
+        if c == close {
+            quoted.push(close);
+        }
+        quoted.push(c);
+    }
+    quoted.push(close);
+    quoted
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -24,4 +45,12 @@ mod tests {
             Err(String::from("Content cannot contain double quotes!"))
         );
     }
+
+    #[test]
+    fn quote_escaped_test() {
+        assert_eq!(quote_escaped("TABLE", '"', '"'), r#""TABLE""#);
+        assert_eq!(quote_escaped(r#"A"B"#, '"', '"'), r#""A""B""#);
+        assert_eq!(quote_escaped("'", '\'', '\''), "''''");
+        assert_eq!(quote_escaped("", '`', '`'), "``");
+    }
 }
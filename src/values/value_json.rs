@@ -1,5 +1,6 @@
 use super::{
     into_value::IntoValue,
+    util::quote_escaped,
     value::Value,
     value::{ValueToSQL, ValueTyped},
     value_type::ValueType,
@@ -18,7 +19,7 @@ impl IntoValue for serde_json::Value {
 
 impl ValueToSQL for serde_json::Value {
     fn to_sql(&self) -> String {
-        self.to_string()
+        quote_escaped(&self.to_string(), '\'', '\'')
     }
 }
 
@@ -1,3 +1,4 @@
+use super::util::quote_escaped;
 use super::{
     into_value::IntoValue,
     value::Value,
@@ -29,7 +30,7 @@ impl IntoValue for &str {
 
 impl ValueToSQL for String {
     fn to_sql(&self) -> String {
-        format!("'{self}'")
+        quote_escaped(self, '\'', '\'')
     }
 }
 
@@ -33,6 +33,17 @@ impl ValueType {
     pub fn is_string(&self) -> bool {
         matches!(&self, ValueType::String)
     }
+
+    pub fn is_numeric(&self) -> bool {
+        matches!(
+            &self,
+            ValueType::Int32 | ValueType::Int64 | ValueType::Decimal
+        )
+    }
+
+    pub fn is_temporal(&self) -> bool {
+        matches!(&self, ValueType::Date | ValueType::DateTime)
+    }
 }
 
 impl fmt::Display for ValueType {